@@ -0,0 +1,522 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use boj_client::client::BojClient;
+use boj_client::error::BojError;
+use boj_client::query::{CodeQuery, Format, Frequency, Language, LayerQuery, MetadataQuery};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use crate::cache::{MetadataCache, default_cache_dir};
+use crate::help::{exit_code_help, parameter_help, parameter_long_help};
+use crate::output::OutputFormat;
+use crate::watch::{CheckTime, WatchSchedule};
+
+const DEFAULT_BASE_URL: &str = "https://www.stat-search.boj.or.jp";
+
+#[derive(Debug, Clone, Parser)]
+#[command(name = "boj", version)]
+#[command(about = "Command-line client for the BOJ time-series statistics API")]
+#[command(after_help = exit_code_help())]
+pub struct Cli {
+    /// BOJ API base URL
+    #[arg(long, global = true, env = "BOJ_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+
+    /// HTTP timeout per request in milliseconds
+    #[arg(long, global = true, env = "BOJ_TIMEOUT_MS", default_value_t = 30_000)]
+    pub timeout_ms: u64,
+
+    /// Output format
+    #[arg(short, long, global = true, value_enum, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Fetch series by code (getDataCode)
+    Code(CodeArgs),
+    /// Fetch series under a layer path (getDataLayer)
+    Layer(LayerArgs),
+    /// Fetch series metadata of a DB (getMetadata)
+    Metadata(MetadataArgs),
+    /// Search series names, categories, units and notes in cached metadata
+    Search(SearchArgs),
+    /// Print the layer hierarchy of a DB under a path from cached metadata
+    Browse(BrowseArgs),
+    /// Poll daily after the BOJ update and print changes as JSON lines
+    Watch(WatchArgs),
+    /// Explore DBs, layers and series interactively and export a basket
+    #[cfg(feature = "tui")]
+    Tui(TuiArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct CodeArgs {
+    #[arg(help = parameter_help("DB"), long_help = parameter_long_help("DB"))]
+    pub db: String,
+
+    #[arg(
+        required = true,
+        value_delimiter = ',',
+        help = parameter_help("CODE"),
+        long_help = parameter_long_help("CODE")
+    )]
+    pub codes: Vec<String>,
+
+    #[command(flatten)]
+    pub range: RangeArgs,
+
+    #[arg(long, value_enum, help = parameter_help("LANG"))]
+    pub lang: Option<LanguageArg>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct LayerArgs {
+    #[arg(help = parameter_help("DB"), long_help = parameter_long_help("DB"))]
+    pub db: String,
+
+    #[arg(
+        value_enum,
+        ignore_case = true,
+        help = parameter_help("FREQUENCY"),
+        long_help = parameter_long_help("FREQUENCY")
+    )]
+    pub frequency: FrequencyArg,
+
+    #[arg(
+        required = true,
+        value_delimiter = ',',
+        help = parameter_help("LAYER"),
+        long_help = parameter_long_help("LAYER")
+    )]
+    pub layers: Vec<String>,
+
+    #[command(flatten)]
+    pub range: RangeArgs,
+
+    #[arg(long, value_enum, help = parameter_help("LANG"))]
+    pub lang: Option<LanguageArg>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct MetadataArgs {
+    #[arg(help = parameter_help("DB"), long_help = parameter_long_help("DB"))]
+    pub db: String,
+
+    #[arg(long, value_enum, help = parameter_help("LANG"))]
+    pub lang: Option<LanguageArg>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct SearchArgs {
+    /// Words to find; every word must match
+    pub text: String,
+
+    /// Search only these DBs instead of every DB in the catalog
+    #[arg(long = "db", value_name = "DB", value_delimiter = ',')]
+    pub dbs: Vec<String>,
+
+    /// Keep only series of this frequency
+    #[arg(long, value_enum, ignore_case = true)]
+    pub frequency: Option<FrequencyArg>,
+
+    /// Maximum number of results
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+
+    /// Language of the printed names
+    #[arg(long, value_enum, default_value_t = LanguageArg::Jp)]
+    pub lang: LanguageArg,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BrowseArgs {
+    #[arg(help = parameter_help("DB"), long_help = parameter_long_help("DB"))]
+    pub db: String,
+
+    /// Layer path such as 1,1; the whole DB when omitted
+    #[arg(value_delimiter = ',')]
+    pub path: Vec<u32>,
+
+    /// Levels to print below the path; all when omitted
+    #[arg(long)]
+    pub depth: Option<usize>,
+
+    /// Language of the printed names
+    #[arg(long, value_enum, default_value_t = LanguageArg::Jp)]
+    pub lang: LanguageArg,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct WatchArgs {
+    #[arg(long, help = parameter_help("DB"), long_help = parameter_long_help("DB"))]
+    pub db: String,
+
+    #[arg(
+        long = "code",
+        required = true,
+        value_delimiter = ',',
+        help = parameter_help("CODE"),
+        long_help = parameter_long_help("CODE")
+    )]
+    pub codes: Vec<String>,
+
+    #[arg(long = "start", help = parameter_help("STARTDATE"), long_help = parameter_long_help("STARTDATE"))]
+    pub start_date: Option<String>,
+
+    /// Time of day (JST) when checking starts; BOJ updates around 08:50
+    #[arg(long, value_name = "HH:MM", default_value = "08:50")]
+    pub check_at: CheckTime,
+
+    /// Minutes to keep polling after --check-at until an update appears
+    #[arg(long, default_value_t = 180)]
+    pub window_minutes: u64,
+
+    /// Seconds between polls inside the window (at least 60)
+    #[arg(
+        long,
+        default_value_t = 300,
+        value_parser = clap::value_parser!(u64).range(60..)
+    )]
+    pub poll_interval_secs: u64,
+
+    /// Longest wait in seconds between retries while BOJ keeps failing
+    #[arg(long, default_value_t = 3600)]
+    pub max_backoff_secs: u64,
+
+    /// Minimum milliseconds between consecutive BOJ requests
+    #[arg(long, default_value_t = 1000)]
+    pub request_interval_ms: u64,
+
+    /// Shell command run per update with the JSON diff on stdin instead of
+    /// printing it
+    #[arg(long, value_name = "COMMAND")]
+    pub hook: Option<String>,
+}
+
+#[cfg(feature = "tui")]
+#[derive(Debug, Clone, Args)]
+pub struct TuiArgs {
+    /// Language of the shown names
+    #[arg(long, value_enum, default_value_t = LanguageArg::Jp)]
+    pub lang: LanguageArg,
+
+    #[command(flatten)]
+    pub cache: CacheArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct CacheArgs {
+    /// Directory of cached getMetadata responses [default: ~/.cache/boj]
+    #[arg(long, env = "BOJ_CACHE_DIR", value_name = "DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Hours before cached metadata is fetched again
+    #[arg(long, value_name = "HOURS", default_value_t = 24)]
+    pub max_age_hours: u64,
+
+    /// Fetch metadata again even when the cache is fresh
+    #[arg(long)]
+    pub refresh: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct RangeArgs {
+    #[arg(
+        long = "start",
+        value_name = "PERIOD",
+        help = parameter_help("STARTDATE"),
+        long_help = parameter_long_help("STARTDATE")
+    )]
+    pub start_date: Option<String>,
+
+    #[arg(
+        long = "end",
+        value_name = "PERIOD",
+        help = parameter_help("ENDDATE"),
+        long_help = parameter_long_help("ENDDATE")
+    )]
+    pub end_date: Option<String>,
+
+    #[arg(
+        long,
+        help = parameter_help("STARTPOSITION"),
+        long_help = parameter_long_help("STARTPOSITION")
+    )]
+    pub start_position: Option<u32>,
+
+    /// Fetch one page only instead of following NEXTPOSITION
+    #[arg(long)]
+    pub single_page: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LanguageArg {
+    Jp,
+    En,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FrequencyArg {
+    Cy,
+    Fy,
+    Ch,
+    Fh,
+    Q,
+    M,
+    W,
+    D,
+}
+
+impl From<LanguageArg> for Language {
+    fn from(value: LanguageArg) -> Self {
+        match value {
+            LanguageArg::Jp => Language::Jp,
+            LanguageArg::En => Language::En,
+        }
+    }
+}
+
+impl From<FrequencyArg> for Frequency {
+    fn from(value: FrequencyArg) -> Self {
+        match value {
+            FrequencyArg::Cy => Frequency::Cy,
+            FrequencyArg::Fy => Frequency::Fy,
+            FrequencyArg::Ch => Frequency::Ch,
+            FrequencyArg::Fh => Frequency::Fh,
+            FrequencyArg::Q => Frequency::Q,
+            FrequencyArg::M => Frequency::M,
+            FrequencyArg::W => Frequency::W,
+            FrequencyArg::D => Frequency::D,
+        }
+    }
+}
+
+impl Cli {
+    pub fn to_boj_client(&self) -> Result<BojClient, BojError> {
+        let reqwest_client = reqwest::blocking::Client::builder()
+            .timeout(Duration::from_millis(self.timeout_ms))
+            .build()
+            .map_err(|error| {
+                BojError::transport(format!("failed to build reqwest client for CLI: {error}"))
+            })?;
+
+        Ok(BojClient::with_reqwest_client(reqwest_client).with_base_url(self.base_url.clone()))
+    }
+}
+
+impl CodeArgs {
+    pub fn to_query(&self) -> Result<CodeQuery, BojError> {
+        let mut query =
+            CodeQuery::new(self.db.clone(), self.codes.clone())?.with_format(Format::Json);
+        if let Some(lang) = self.lang {
+            query = query.with_lang(lang.into());
+        }
+        if let Some(start_date) = &self.range.start_date {
+            query = query.with_start_date(start_date)?;
+        }
+        if let Some(end_date) = &self.range.end_date {
+            query = query.with_end_date(end_date)?;
+        }
+        if let Some(start_position) = self.range.start_position {
+            query = query.with_start_position(start_position)?;
+        }
+        Ok(query)
+    }
+}
+
+impl LayerArgs {
+    pub fn to_query(&self) -> Result<LayerQuery, BojError> {
+        let mut query =
+            LayerQuery::new(self.db.clone(), self.frequency.into(), self.layers.clone())?
+                .with_format(Format::Json);
+        if let Some(lang) = self.lang {
+            query = query.with_lang(lang.into());
+        }
+        if let Some(start_date) = &self.range.start_date {
+            query = query.with_start_date(start_date)?;
+        }
+        if let Some(end_date) = &self.range.end_date {
+            query = query.with_end_date(end_date)?;
+        }
+        if let Some(start_position) = self.range.start_position {
+            query = query.with_start_position(start_position)?;
+        }
+        Ok(query)
+    }
+}
+
+impl WatchArgs {
+    /// Query fetching `codes` of the watched DB.
+    pub fn code_query(&self, codes: Vec<String>) -> Result<CodeQuery, BojError> {
+        let mut query = CodeQuery::new(self.db.clone(), codes)?.with_format(Format::Json);
+        if let Some(start_date) = &self.start_date {
+            query = query.with_start_date(start_date)?;
+        }
+        Ok(query)
+    }
+
+    pub fn schedule(&self) -> WatchSchedule {
+        WatchSchedule {
+            check_at: self.check_at,
+            window: Duration::from_secs(self.window_minutes * 60),
+            poll_interval: Duration::from_secs(self.poll_interval_secs),
+            max_backoff: Duration::from_secs(self.max_backoff_secs),
+        }
+    }
+}
+
+impl CacheArgs {
+    pub fn to_cache(&self) -> MetadataCache {
+        let directory = self.cache_dir.clone().unwrap_or_else(default_cache_dir);
+        MetadataCache::new(directory, Duration::from_secs(self.max_age_hours * 3600))
+            .with_refresh(self.refresh)
+    }
+}
+
+impl MetadataArgs {
+    pub fn to_query(&self) -> Result<MetadataQuery, BojError> {
+        let mut query = MetadataQuery::new(self.db.clone())?.with_format(Format::Json);
+        if let Some(lang) = self.lang {
+            query = query.with_lang(lang.into());
+        }
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn parse(args: &[&str]) -> Cli {
+        Cli::try_parse_from(args).expect("arguments should parse")
+    }
+
+    #[test]
+    fn code_splits_comma_separated_codes() {
+        let cli = parse(&[
+            "boj",
+            "code",
+            "FM08",
+            "FXERD01,FXERD04",
+            "FXERD09",
+            "-o",
+            "csv",
+        ]);
+        assert_eq!(cli.output, OutputFormat::Csv);
+        let Command::Code(args) = cli.command else {
+            panic!("expected code subcommand");
+        };
+        assert_eq!(args.codes, ["FXERD01", "FXERD04", "FXERD09"]);
+        assert!(args.to_query().is_ok());
+    }
+
+    #[test]
+    fn layer_accepts_upper_case_frequency_and_wildcards() {
+        let cli = parse(&["boj", "layer", "MD10", "Q", "1,*", "--start", "202501"]);
+        let Command::Layer(args) = cli.command else {
+            panic!("expected layer subcommand");
+        };
+        assert_eq!(args.frequency, FrequencyArg::Q);
+        assert_eq!(args.layers, ["1", "*"]);
+        assert!(args.to_query().is_ok());
+    }
+
+    #[test]
+    fn invalid_date_shape_is_a_validation_error() {
+        let cli = parse(&["boj", "layer", "BP01", "q", "1", "--start", "2024"]);
+        let Command::Layer(args) = cli.command else {
+            panic!("expected layer subcommand");
+        };
+        assert!(matches!(args.to_query(), Err(BojError::ValidationError(_))));
+    }
+
+    #[test]
+    fn search_and_browse_parse_lists() {
+        let cli = parse(&[
+            "boj",
+            "search",
+            "無担保コール",
+            "--db",
+            "FM01,FM02",
+            "--limit",
+            "5",
+        ]);
+        let Command::Search(args) = cli.command else {
+            panic!("expected search subcommand");
+        };
+        assert_eq!(args.text, "無担保コール");
+        assert_eq!(args.dbs, ["FM01", "FM02"]);
+        assert_eq!(args.limit, 5);
+
+        let cli = parse(&["boj", "browse", "FF", "1,1", "--depth", "1"]);
+        let Command::Browse(args) = cli.command else {
+            panic!("expected browse subcommand");
+        };
+        assert_eq!(args.path, [1, 1]);
+        assert_eq!(args.depth, Some(1));
+    }
+
+    #[test]
+    fn watch_requires_codes_and_limits_poll_interval() {
+        let cli = parse(&[
+            "boj",
+            "watch",
+            "--db",
+            "FM01",
+            "--code",
+            "STRDCLUCON,STRDCLUCONH",
+            "--check-at",
+            "09:00",
+        ]);
+        let Command::Watch(args) = cli.command else {
+            panic!("expected watch subcommand");
+        };
+        assert_eq!(args.codes, ["STRDCLUCON", "STRDCLUCONH"]);
+        assert_eq!(args.schedule().check_at.to_string(), "09:00");
+        assert_eq!(args.schedule().poll_interval, Duration::from_secs(300));
+
+        assert!(Cli::try_parse_from(["boj", "watch", "--db", "FM01"]).is_err());
+        assert!(
+            Cli::try_parse_from([
+                "boj",
+                "watch",
+                "--db",
+                "FM01",
+                "--code",
+                "STRDCLUCON",
+                "--poll-interval-secs",
+                "10",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn help_comes_from_parameter_catalog() {
+        let mut command = Cli::command();
+        let help = command
+            .find_subcommand_mut("layer")
+            .unwrap()
+            .render_long_help()
+            .to_string();
+        assert!(help.contains("期種（CY, FY, CH, FH, Q, M, W, D）"));
+        assert!(help.contains("階層1は必須、階層2〜5は任意。"));
+        assert!(help.contains("開始期"));
+    }
+
+    #[test]
+    fn command_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+}
@@ -0,0 +1,219 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use boj_client::client::BojClient;
+use boj_client::model::MetadataResponse;
+use boj_client::query::{Format, MetadataQuery};
+use boj_client::search::SearchIndex;
+
+use crate::error::CliError;
+
+const SEARCH_INDEX_FILE: &str = "search-index.json";
+
+/// Directory of `getMetadata` responses and the search index built from
+/// them, reused until they are older than `max_age`.
+///
+/// Responses are fetched in both languages so Japanese and English names are
+/// searchable, and stored without their raw body.
+#[derive(Debug, Clone)]
+pub struct MetadataCache {
+    directory: PathBuf,
+    max_age: Duration,
+    refresh: bool,
+}
+
+impl MetadataCache {
+    pub fn new(directory: impl Into<PathBuf>, max_age: Duration) -> Self {
+        Self {
+            directory: directory.into(),
+            max_age,
+            refresh: false,
+        }
+    }
+
+    /// Ignores cached files and fetches again, rewriting the cache.
+    pub fn with_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the metadata of `db`, fetching it when the cached copy is
+    /// missing, stale or unreadable.
+    pub fn metadata(&self, client: &BojClient, db: &str) -> Result<MetadataResponse, CliError> {
+        let db = db.to_ascii_uppercase();
+        let path = self.directory.join("metadata").join(format!("{db}.json"));
+        if self.is_fresh(&path)
+            && let Some(response) = fs::read(&path)
+                .ok()
+                .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        {
+            return Ok(response);
+        }
+
+        let query = MetadataQuery::new(db)?.with_format(Format::Json);
+        let mut response = client.get_metadata_bilingual(&query)?.merged;
+        response.raw.clear();
+        let bytes = serde_json::to_vec(&response).map_err(io::Error::from);
+        self.write(&path, bytes)?;
+        Ok(response)
+    }
+
+    /// Returns a search index covering `dbs`.
+    ///
+    /// The saved index is reused when it is fresh and already holds every DB
+    /// in `dbs`; otherwise it is rebuilt from [`metadata`](Self::metadata)
+    /// and saved. With `skip_failed`, DBs BOJ rejects are reported on stderr
+    /// and left out instead of failing the search.
+    pub fn search_index(
+        &self,
+        client: &BojClient,
+        dbs: &[String],
+        skip_failed: bool,
+    ) -> Result<SearchIndex, CliError> {
+        let path = self.directory.join(SEARCH_INDEX_FILE);
+        if self.is_fresh(&path)
+            && let Ok(index) = SearchIndex::load(&path)
+            && dbs.iter().all(|db| index.dbs().contains(&db.as_str()))
+        {
+            return Ok(index);
+        }
+
+        let mut responses = Vec::with_capacity(dbs.len());
+        for db in dbs {
+            match self.metadata(client, db) {
+                Ok(response) => responses.push(response),
+                Err(CliError::Boj(error @ boj_client::error::BojError::ApiError { .. }))
+                    if skip_failed =>
+                {
+                    eprintln!("boj: skipping {db}: {error}");
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        let index = SearchIndex::from_responses(&responses);
+        let mut bytes = Vec::new();
+        let written = index.write_to(&mut bytes).map(|()| bytes);
+        self.write(&path, written)?;
+        Ok(index)
+    }
+
+    fn is_fresh(&self, path: &Path) -> bool {
+        if self.refresh {
+            return false;
+        }
+        fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+            .is_some_and(|age| age <= self.max_age)
+    }
+
+    /// Writes through a temporary file so readers never see a partial file.
+    fn write(&self, path: &Path, bytes: io::Result<Vec<u8>>) -> Result<(), CliError> {
+        let cache_error = |source| CliError::Cache {
+            path: path.to_path_buf(),
+            source,
+        };
+        let bytes = bytes.map_err(cache_error)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(cache_error)?;
+        }
+        let temporary = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&temporary, bytes)
+            .and_then(|()| fs::rename(&temporary, path))
+            .map_err(cache_error)
+    }
+}
+
+/// Returns `$XDG_CACHE_HOME/boj`, `~/.cache/boj` or `%LOCALAPPDATA%\boj`,
+/// falling back to the system temporary directory.
+pub fn default_cache_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+        .or_else(|| std::env::var_os("LOCALAPPDATA").map(PathBuf::from))
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("boj")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boj_client::model::ResponseMeta;
+
+    fn cache_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("boj-cli-cache-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn unreachable_client() -> BojClient {
+        BojClient::new()
+            .expect("default client should build")
+            .with_base_url("http://127.0.0.1:9")
+    }
+
+    fn response(db: &str) -> MetadataResponse {
+        MetadataResponse {
+            meta: ResponseMeta {
+                status: 200,
+                message_id: "M181000I".to_string(),
+                message: "ok".to_string(),
+                date: None,
+            },
+            db: db.to_string(),
+            entries: Vec::new(),
+            raw: String::new(),
+        }
+    }
+
+    #[test]
+    fn fresh_cached_metadata_is_served_without_network() {
+        let directory = cache_directory("fresh");
+        let cache = MetadataCache::new(&directory, Duration::from_secs(3600));
+        let path = directory.join("metadata").join("FM08.json");
+        cache
+            .write(
+                &path,
+                serde_json::to_vec(&response("FM08")).map_err(io::Error::from),
+            )
+            .unwrap();
+
+        let cached = cache.metadata(&unreachable_client(), "fm08").unwrap();
+        assert_eq!(cached.db, "FM08");
+
+        let refreshed = cache
+            .clone()
+            .with_refresh(true)
+            .metadata(&unreachable_client(), "FM08");
+        assert!(matches!(refreshed, Err(CliError::Boj(_))));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn stale_cached_metadata_is_fetched_again() {
+        let directory = cache_directory("stale");
+        let cache = MetadataCache::new(&directory, Duration::ZERO);
+        let path = directory.join("metadata").join("FM08.json");
+        cache
+            .write(
+                &path,
+                serde_json::to_vec(&response("FM08")).map_err(io::Error::from),
+            )
+            .unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+
+        let result = cache.metadata(&unreachable_client(), "FM08");
+        assert!(matches!(result, Err(CliError::Boj(_))));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
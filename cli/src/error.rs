@@ -0,0 +1,99 @@
+use std::io;
+use std::path::PathBuf;
+
+use boj_client::error::BojError;
+use boj_client::export::ExportError;
+use thiserror::Error;
+
+/// Output or the metadata cache could not be written.
+pub const EXIT_FAILURE: u8 = 1;
+/// Invalid arguments or query; clap also exits with 2 on usage errors.
+pub const EXIT_VALIDATION: u8 = 2;
+/// Network or HTTP failure.
+pub const EXIT_TRANSPORT: u8 = 3;
+/// BOJ response could not be decoded.
+pub const EXIT_DECODE: u8 = 4;
+/// BOJ rejected the request.
+pub const EXIT_API: u8 = 5;
+/// BOJ reported a server error or maintenance; retrying later may succeed.
+pub const EXIT_API_UNAVAILABLE: u8 = 6;
+
+#[derive(Debug, Error)]
+pub enum CliError {
+    #[error(transparent)]
+    Boj(#[from] BojError),
+
+    #[error(transparent)]
+    Export(#[from] ExportError),
+
+    #[error("failed to write output: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("failed to write metadata cache {}: {source}", path.display())]
+    Cache { path: PathBuf, source: io::Error },
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Self::Boj(BojError::ValidationError(_)) => EXIT_VALIDATION,
+            Self::Boj(BojError::TransportError(_)) => EXIT_TRANSPORT,
+            Self::Boj(BojError::DecodeError(_)) => EXIT_DECODE,
+            Self::Boj(BojError::ApiError { status, .. }) if *status == 500 || *status == 503 => {
+                EXIT_API_UNAVAILABLE
+            }
+            Self::Boj(BojError::ApiError { .. }) => EXIT_API,
+            Self::Export(ExportError::InvalidValue { .. }) => EXIT_DECODE,
+            Self::Export(_) | Self::Io(_) | Self::Cache { .. } => EXIT_FAILURE,
+        }
+    }
+
+    /// Returns `true` when stdout was closed early, as by `boj ... | head`.
+    pub fn is_broken_pipe(&self) -> bool {
+        let io_error = match self {
+            Self::Io(error) | Self::Export(ExportError::Io(error)) => error,
+            _ => return false,
+        };
+        io_error.kind() == io::ErrorKind::BrokenPipe
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_error(status: u16) -> CliError {
+        CliError::Boj(BojError::ApiError {
+            status,
+            message_id: "M181091S".to_string(),
+            message: "message".to_string(),
+        })
+    }
+
+    #[test]
+    fn exit_codes_follow_boj_error_variants() {
+        assert_eq!(
+            CliError::Boj(BojError::validation("bad")).exit_code(),
+            EXIT_VALIDATION
+        );
+        assert_eq!(
+            CliError::Boj(BojError::transport("down")).exit_code(),
+            EXIT_TRANSPORT
+        );
+        assert_eq!(
+            CliError::Boj(BojError::decode("garbled")).exit_code(),
+            EXIT_DECODE
+        );
+        assert_eq!(api_error(400).exit_code(), EXIT_API);
+        assert_eq!(api_error(500).exit_code(), EXIT_API_UNAVAILABLE);
+        assert_eq!(api_error(503).exit_code(), EXIT_API_UNAVAILABLE);
+    }
+
+    #[test]
+    fn broken_pipe_is_detected() {
+        let error = CliError::Io(io::Error::from(io::ErrorKind::BrokenPipe));
+        assert!(error.is_broken_pipe());
+        assert_eq!(error.exit_code(), EXIT_FAILURE);
+        assert!(!api_error(400).is_broken_pipe());
+    }
+}
@@ -0,0 +1,79 @@
+use boj_client::catalog::{self, ParameterSpec};
+
+use crate::error::{
+    EXIT_API, EXIT_API_UNAVAILABLE, EXIT_DECODE, EXIT_FAILURE, EXIT_TRANSPORT, EXIT_VALIDATION,
+};
+
+fn parameter_spec(name: &str) -> Option<&'static ParameterSpec> {
+    catalog::parameter_specs()
+        .iter()
+        .find(|spec| spec.name == name)
+}
+
+/// Short help for a BOJ parameter: its manual description and allowed values.
+pub fn parameter_help(name: &str) -> String {
+    match parameter_spec(name) {
+        Some(spec) => format!("{}（{}）", spec.description_ja, spec.allowed_values),
+        None => name.to_string(),
+    }
+}
+
+/// Long help for a BOJ parameter: the short help followed by manual notes.
+pub fn parameter_long_help(name: &str) -> String {
+    let mut help = parameter_help(name);
+    for note in parameter_spec(name).map_or(&[][..], |spec| spec.notes) {
+        help.push_str("\n- ");
+        help.push_str(note);
+    }
+    help
+}
+
+pub fn exit_code_help() -> String {
+    let snapshot = catalog::snapshot();
+    format!(
+        "Exit codes:\n  \
+         {EXIT_FAILURE}  output or metadata cache could not be written\n  \
+         {EXIT_VALIDATION}  invalid arguments or query (validation error)\n  \
+         {EXIT_TRANSPORT}  network or HTTP failure (transport error)\n  \
+         {EXIT_DECODE}  unreadable BOJ response (decode error)\n  \
+         {EXIT_API}  BOJ rejected the request (STATUS 400)\n  \
+         {EXIT_API_UNAVAILABLE}  BOJ server error or maintenance (STATUS 500/503)\n\n\
+         Parameter help follows {} ({}).",
+        snapshot.source_document, snapshot.source_date
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parameter_help_uses_catalog_description_and_notes() {
+        assert_eq!(
+            parameter_help("FREQUENCY"),
+            "期種（CY, FY, CH, FH, Q, M, W, D）"
+        );
+        assert_eq!(
+            parameter_long_help("CODE"),
+            "系列コード（系列コード（カンマ区切りで複数指定可、同じ期種のみ指定可））\n\
+             - データコード（先頭にDB名付き）は不可。\n\
+             - 上限は1250コード。"
+        );
+    }
+
+    #[test]
+    fn every_referenced_parameter_is_in_catalog() {
+        for name in [
+            "DB",
+            "CODE",
+            "LAYER",
+            "FREQUENCY",
+            "LANG",
+            "STARTDATE",
+            "ENDDATE",
+            "STARTPOSITION",
+        ] {
+            assert!(parameter_spec(name).is_some(), "{name} missing");
+        }
+    }
+}
@@ -0,0 +1,123 @@
+pub mod args;
+pub mod cache;
+pub mod error;
+pub mod help;
+pub mod output;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod watch;
+
+use std::io::Write;
+
+use args::{BrowseArgs, Cli, Command, SearchArgs};
+use boj_client::catalog;
+use boj_client::client::BojClient;
+use boj_client::error::BojError;
+use boj_client::model::MetadataTree;
+use boj_client::search::SearchQuery;
+use error::CliError;
+
+/// Runs one subcommand, writing its result to `out` and notes to stderr.
+pub fn run<W: Write>(cli: &Cli, mut out: W) -> Result<(), CliError> {
+    let client = cli.to_boj_client()?;
+    match &cli.command {
+        Command::Code(args) => {
+            let query = args.to_query()?;
+            let response = if args.range.single_page {
+                client.get_data_code(&query)?
+            } else {
+                client.get_data_code_all(&query)?
+            };
+            report_next_position(response.next_position);
+            output::write_series(&mut out, &response.series, cli.output)?;
+        }
+        Command::Layer(args) => {
+            let query = args.to_query()?;
+            let response = if args.range.single_page {
+                client.get_data_layer(&query)?
+            } else {
+                client.get_data_layer_all(&query)?
+            };
+            report_next_position(response.next_position);
+            output::write_series(&mut out, &response.series, cli.output)?;
+        }
+        Command::Metadata(args) => {
+            let response = client.get_metadata(&args.to_query()?)?;
+            output::write_metadata(&mut out, &response.entries, cli.output)?;
+        }
+        Command::Search(args) => search(&client, args, cli, &mut out)?,
+        Command::Browse(args) => browse(&client, args, cli, &mut out)?,
+        Command::Watch(args) => watch::watch(&client, args, &mut out)?,
+        #[cfg(feature = "tui")]
+        Command::Tui(args) => tui::run_tui(
+            &client,
+            &args.cache.to_cache(),
+            &cli.base_url,
+            args.lang.into(),
+            &mut out,
+        )?,
+    }
+    out.flush()?;
+    Ok(())
+}
+
+fn report_next_position(next_position: Option<u32>) {
+    if let Some(next_position) = next_position {
+        eprintln!("boj: more series remain; rerun with --start-position {next_position}");
+    }
+}
+
+fn search<W: Write>(
+    client: &BojClient,
+    args: &SearchArgs,
+    cli: &Cli,
+    out: W,
+) -> Result<(), CliError> {
+    let all_dbs = args.dbs.is_empty();
+    let dbs = if all_dbs {
+        catalog::databases()
+            .iter()
+            .map(|entry| entry.code.to_string())
+            .collect()
+    } else {
+        args.dbs
+            .iter()
+            .map(|db| db.to_ascii_uppercase())
+            .collect::<Vec<_>>()
+    };
+    let index = args.cache.to_cache().search_index(client, &dbs, all_dbs)?;
+
+    let mut query = SearchQuery::new(&args.text).with_limit(args.limit);
+    for db in &dbs {
+        query = query.with_db(db);
+    }
+    if let Some(frequency) = args.frequency {
+        query = query.with_frequency(frequency.into());
+    }
+    output::write_search_hits(out, &index.search(&query), args.lang.into(), cli.output)
+}
+
+fn browse<W: Write>(
+    client: &BojClient,
+    args: &BrowseArgs,
+    cli: &Cli,
+    out: W,
+) -> Result<(), CliError> {
+    let response = args.cache.to_cache().metadata(client, &args.db)?;
+    let tree = MetadataTree::from_response(&response);
+    let nodes = if args.path.is_empty() {
+        tree.roots.iter().collect::<Vec<_>>()
+    } else {
+        let node = tree.node(&args.path).ok_or_else(|| {
+            let path = args
+                .path
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            BojError::validation(format!("layer path {path} does not exist in {}", tree.db))
+        })?;
+        vec![node]
+    };
+    output::write_tree(out, &nodes, args.depth, args.lang.into(), cli.output)
+}
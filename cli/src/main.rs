@@ -0,0 +1,17 @@
+use std::io::{self, BufWriter};
+use std::process::ExitCode;
+
+use boj_cli::args::Cli;
+use clap::Parser;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match boj_cli::run(&cli, BufWriter::new(io::stdout().lock())) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) if error.is_broken_pipe() => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("boj: {error}");
+            ExitCode::from(error.exit_code())
+        }
+    }
+}
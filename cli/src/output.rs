@@ -0,0 +1,491 @@
+use std::io::{self, Write};
+
+use boj_client::export::{self, ExportFormat, ExportOptions};
+use boj_client::model::{MetadataEntry, MetadataTreeNode, TimeSeries};
+use boj_client::query::Language;
+use boj_client::search::SearchHit;
+use clap::ValueEnum;
+use serde::Serialize;
+use unicode_width::UnicodeWidthStr;
+
+use crate::error::CliError;
+
+const METADATA_COLUMNS: [&str; 7] = [
+    "series_code",
+    "name",
+    "unit",
+    "frequency",
+    "start",
+    "end",
+    "last_update",
+];
+
+const SEARCH_COLUMNS: [&str; 7] = [
+    "db",
+    "series_code",
+    "name",
+    "unit",
+    "frequency",
+    "start",
+    "end",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for reading in a terminal
+    Table,
+    /// Comma-separated values with a header row
+    Csv,
+    /// One pretty-printed JSON array
+    Json,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// Writes one row per observation: `series_code`, `period` and `value`.
+pub fn write_series<T, W>(out: W, series: &[T], format: OutputFormat) -> Result<(), CliError>
+where
+    T: TimeSeries + Serialize,
+    W: Write,
+{
+    match format {
+        OutputFormat::Table => {
+            let rows = series
+                .iter()
+                .flat_map(|series| {
+                    series.points().iter().map(|point| {
+                        vec![
+                            series.series_code().to_string(),
+                            point.survey_date.clone(),
+                            point.value.clone().unwrap_or_default(),
+                        ]
+                    })
+                })
+                .collect::<Vec<_>>();
+            write_table(out, &["series_code", "period", "value"], &rows)
+        }
+        OutputFormat::Csv => Ok(export::write_series(
+            out,
+            series,
+            &ExportOptions::new(ExportFormat::Csv),
+        )?),
+        OutputFormat::Json => write_json(out, series),
+        OutputFormat::Jsonl => Ok(export::write_series(
+            out,
+            series,
+            &ExportOptions::new(ExportFormat::JsonLines),
+        )?),
+    }
+}
+
+/// Writes one row per metadata entry, including layer heading rows without a
+/// series code.
+pub fn write_metadata<W: Write>(
+    mut out: W,
+    entries: &[MetadataEntry],
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    match format {
+        OutputFormat::Table => write_table(out, &METADATA_COLUMNS, &metadata_rows(entries)),
+        OutputFormat::Csv => {
+            write_csv(out, &METADATA_COLUMNS, &metadata_rows(entries)).map_err(io::Error::from)?;
+            Ok(())
+        }
+        OutputFormat::Json => write_json(out, entries),
+        OutputFormat::Jsonl => {
+            for entry in entries {
+                serde_json::to_writer(&mut out, entry).map_err(io::Error::from)?;
+                out.write_all(b"\n")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SearchRow<'a> {
+    db: &'a str,
+    series_code: &'a str,
+    name: Option<&'a str>,
+    unit: Option<&'a str>,
+    frequency: Option<&'a str>,
+    start: Option<&'a str>,
+    end: Option<&'a str>,
+    score: f64,
+}
+
+impl SearchRow<'_> {
+    fn cells(&self) -> Vec<String> {
+        [
+            Some(self.db),
+            Some(self.series_code),
+            self.name,
+            self.unit,
+            self.frequency,
+            self.start,
+            self.end,
+        ]
+        .map(|cell| cell.unwrap_or_default().to_string())
+        .to_vec()
+    }
+}
+
+/// Writes one row per search hit with its DB, code, labels and coverage.
+pub fn write_search_hits<W: Write>(
+    out: W,
+    hits: &[SearchHit<'_>],
+    language: Language,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let rows = hits
+        .iter()
+        .map(|hit| SearchRow {
+            db: hit.db,
+            series_code: hit.series_code,
+            name: label(
+                &hit.entry.name_of_time_series_j,
+                &hit.entry.name_of_time_series,
+                language,
+            ),
+            unit: label(&hit.entry.unit_j, &hit.entry.unit, language),
+            frequency: hit.entry.frequency.as_deref(),
+            start: hit.entry.start_of_the_time_series.as_deref(),
+            end: hit.entry.end_of_the_time_series.as_deref(),
+            score: hit.score,
+        })
+        .collect::<Vec<_>>();
+    write_rows(out, &SEARCH_COLUMNS, &rows, SearchRow::cells, format)
+}
+
+#[derive(Debug, Serialize)]
+struct TreeRow<'a> {
+    path: String,
+    depth: usize,
+    name: Option<&'a str>,
+    series_code: Option<&'a str>,
+    frequency: Option<&'a str>,
+}
+
+/// Writes `nodes` and their descendants depth-first, down to `max_depth`
+/// levels below `nodes`. The table indents names by depth.
+pub fn write_tree<W: Write>(
+    out: W,
+    nodes: &[&MetadataTreeNode],
+    max_depth: Option<usize>,
+    language: Language,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    let mut rows = Vec::new();
+    let mut stack = nodes
+        .iter()
+        .rev()
+        .map(|node| (*node, 0))
+        .collect::<Vec<_>>();
+    while let Some((node, depth)) = stack.pop() {
+        let entry = node.entry.as_ref();
+        rows.push(TreeRow {
+            path: node
+                .path
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(","),
+            depth,
+            name: entry.and_then(|entry| {
+                label(
+                    &entry.name_of_time_series_j,
+                    &entry.name_of_time_series,
+                    language,
+                )
+            }),
+            series_code: node.series_code().filter(|code| !code.is_empty()),
+            frequency: entry
+                .and_then(|entry| entry.frequency.as_deref())
+                .filter(|frequency| !frequency.is_empty()),
+        });
+        if max_depth.is_none_or(|max_depth| depth < max_depth) {
+            stack.extend(node.children.iter().rev().map(|child| (child, depth + 1)));
+        }
+    }
+
+    let indented = format == OutputFormat::Table;
+    let cells = |row: &TreeRow<'_>| {
+        let name = row.name.unwrap_or_default();
+        vec![
+            row.path.clone(),
+            if indented {
+                format!("{}{name}", "  ".repeat(row.depth))
+            } else {
+                name.to_string()
+            },
+            row.series_code.unwrap_or_default().to_string(),
+            row.frequency.unwrap_or_default().to_string(),
+        ]
+    };
+    write_rows(
+        out,
+        &["path", "name", "series_code", "frequency"],
+        &rows,
+        cells,
+        format,
+    )
+}
+
+/// Writes serializable `rows` as a table or CSV through `cells`, or as JSON.
+fn write_rows<R: Serialize, W: Write>(
+    mut out: W,
+    headers: &[&str],
+    rows: &[R],
+    cells: impl Fn(&R) -> Vec<String>,
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    match format {
+        OutputFormat::Table => {
+            write_table(out, headers, &rows.iter().map(cells).collect::<Vec<_>>())
+        }
+        OutputFormat::Csv => {
+            write_csv(out, headers, &rows.iter().map(cells).collect::<Vec<_>>())
+                .map_err(io::Error::from)?;
+            Ok(())
+        }
+        OutputFormat::Json => write_json(out, rows),
+        OutputFormat::Jsonl => {
+            for row in rows {
+                serde_json::to_writer(&mut out, row).map_err(io::Error::from)?;
+                out.write_all(b"\n")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Returns the label in `language`, falling back to the other language.
+fn label<'a>(
+    japanese: &'a Option<String>,
+    english: &'a Option<String>,
+    language: Language,
+) -> Option<&'a str> {
+    match language {
+        Language::Jp => first_non_empty(japanese, english),
+        Language::En => first_non_empty(english, japanese),
+    }
+}
+
+fn metadata_rows(entries: &[MetadataEntry]) -> Vec<Vec<String>> {
+    entries
+        .iter()
+        .map(|entry| {
+            let name = first_non_empty(&entry.name_of_time_series_j, &entry.name_of_time_series);
+            let unit = first_non_empty(&entry.unit_j, &entry.unit);
+            [
+                entry.series_code.as_deref(),
+                name,
+                unit,
+                entry.frequency.as_deref(),
+                entry.start_of_the_time_series.as_deref(),
+                entry.end_of_the_time_series.as_deref(),
+                entry.last_update.as_deref(),
+            ]
+            .map(|cell| cell.unwrap_or_default().to_string())
+            .to_vec()
+        })
+        .collect()
+}
+
+fn first_non_empty<'a>(first: &'a Option<String>, second: &'a Option<String>) -> Option<&'a str> {
+    [first, second]
+        .into_iter()
+        .filter_map(Option::as_deref)
+        .find(|text| !text.is_empty())
+}
+
+fn write_json<S: Serialize + ?Sized, W: Write>(mut out: W, value: &S) -> Result<(), CliError> {
+    serde_json::to_writer_pretty(&mut out, value).map_err(io::Error::from)?;
+    out.write_all(b"\n")?;
+    Ok(())
+}
+
+fn write_csv<W: Write>(out: W, headers: &[&str], rows: &[Vec<String>]) -> csv::Result<()> {
+    let mut csv = csv::WriterBuilder::new()
+        .terminator(csv::Terminator::CRLF)
+        .from_writer(out);
+    csv.write_record(headers)?;
+    for row in rows {
+        csv.write_record(row)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+/// Writes `rows` under `headers` in columns padded to their display width,
+/// so full-width Japanese labels line up.
+fn write_table<W: Write>(
+    mut out: W,
+    headers: &[&str],
+    rows: &[Vec<String>],
+) -> Result<(), CliError> {
+    let mut widths = headers
+        .iter()
+        .map(|header| header.width())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.width());
+        }
+    }
+
+    let header = headers.iter().map(|header| header.to_string()).collect();
+    for row in std::iter::once(&header).chain(rows) {
+        let mut line = String::new();
+        for (index, (cell, width)) in row.iter().zip(&widths).enumerate() {
+            if index > 0 {
+                line.push_str("  ");
+            }
+            line.push_str(cell);
+            if index + 1 < row.len() {
+                line.push_str(&" ".repeat(width - cell.width()));
+            }
+        }
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boj_client::model::{CodeSeries, DataPoint, MetadataResponse, MetadataTree, ResponseMeta};
+
+    fn series() -> Vec<CodeSeries> {
+        vec![CodeSeries {
+            series_code: "FXERD01".to_string(),
+            name_of_time_series_j: None,
+            name_of_time_series: None,
+            unit_j: None,
+            unit: None,
+            frequency: Some("DAILY".to_string()),
+            category_j: None,
+            category: None,
+            last_update: None,
+            points: vec![
+                DataPoint {
+                    survey_date: "20240104".to_string(),
+                    value: Some("143.15".to_string()),
+                },
+                DataPoint {
+                    survey_date: "20240105".to_string(),
+                    value: None,
+                },
+            ],
+            extras: Default::default(),
+        }]
+    }
+
+    fn entry(code: &str, name_j: &str) -> MetadataEntry {
+        serde_json::from_value(serde_json::json!({
+            "series_code": code,
+            "name_of_time_series_j": name_j,
+            "name_of_time_series": "",
+            "unit_j": "円",
+            "unit": null,
+            "frequency": "DAILY",
+            "category_j": null,
+            "category": null,
+            "layer1": 1,
+            "layer2": 1,
+            "layer3": null,
+            "layer4": null,
+            "layer5": null,
+            "start_of_the_time_series": "19980101",
+            "end_of_the_time_series": "20260218",
+            "last_update": "20260219",
+            "notes_j": null,
+            "notes": null,
+        }))
+        .unwrap()
+    }
+
+    fn render(format: OutputFormat) -> String {
+        let mut out = Vec::new();
+        write_series(&mut out, &series(), format).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn series_table_aligns_columns() {
+        assert_eq!(
+            render(OutputFormat::Table),
+            "series_code  period    value\n\
+             FXERD01      20240104  143.15\n\
+             FXERD01      20240105\n"
+        );
+    }
+
+    #[test]
+    fn series_csv_and_jsonl_use_long_layout() {
+        assert_eq!(
+            render(OutputFormat::Csv),
+            "series_code,period,value\r\nFXERD01,20240104,143.15\r\nFXERD01,20240105,\r\n"
+        );
+        assert_eq!(
+            render(OutputFormat::Jsonl),
+            "{\"series_code\":\"FXERD01\",\"period\":\"20240104\",\"value\":143.15}\n\
+             {\"series_code\":\"FXERD01\",\"period\":\"20240105\",\"value\":null}\n"
+        );
+    }
+
+    #[test]
+    fn metadata_table_pads_by_display_width() {
+        let entries = [entry("FXERD01", "ドル・円"), entry("FXERD04", "ドル")];
+        let mut out = Vec::new();
+        write_metadata(&mut out, &entries, OutputFormat::Table).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(
+            lines[1],
+            "FXERD01      ドル・円  円    DAILY      19980101  20260218  20260219"
+        );
+        assert_eq!(
+            lines[2],
+            "FXERD04      ドル      円    DAILY      19980101  20260218  20260219"
+        );
+    }
+
+    #[test]
+    fn tree_table_indents_names_and_stops_at_depth() {
+        let mut header = entry("", "為替相場");
+        header.layer2 = None;
+        let mut spot = entry("FXERD04", "ドル・円 スポット");
+        spot.layer2 = Some(2);
+        let response = MetadataResponse {
+            meta: ResponseMeta {
+                status: 200,
+                message_id: "M181000I".to_string(),
+                message: "ok".to_string(),
+                date: None,
+            },
+            db: "FM08".to_string(),
+            entries: vec![header, entry("FXERD01", "ドル・円"), spot],
+            raw: String::new(),
+        };
+        let tree = MetadataTree::from_response(&response);
+        let nodes = tree.roots.iter().collect::<Vec<_>>();
+
+        let mut out = Vec::new();
+        write_tree(&mut out, &nodes, None, Language::Jp, OutputFormat::Table).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "path  name                 series_code  frequency\n\
+             1     為替相場                          DAILY\n\
+             1,1     ドル・円           FXERD01      DAILY\n\
+             1,2     ドル・円 スポット  FXERD04      DAILY\n"
+        );
+
+        let mut out = Vec::new();
+        write_tree(&mut out, &nodes, Some(0), Language::Jp, OutputFormat::Csv).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "path,name,series_code,frequency\r\n1,為替相場,,DAILY\r\n"
+        );
+    }
+}
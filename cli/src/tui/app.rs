@@ -0,0 +1,476 @@
+use std::collections::HashMap;
+
+use boj_client::catalog::{self, DatabaseEntry};
+use boj_client::error::BojError;
+use boj_client::model::{DataPoint, MetadataResponse, MetadataTree, MetadataTreeNode};
+use boj_client::query::{CodeQuery, Format, Language};
+use ratatui::crossterm::event::KeyCode;
+
+/// Number of most recent observations shown for a series.
+pub const RECENT_POINTS: usize = 36;
+
+/// Work the event loop does for the app, which itself never touches the
+/// network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Request {
+    /// Load `getMetadata` of the DB and call [`App::open_database`].
+    OpenDatabase(String),
+    /// Fetch the series and call [`App::set_values`].
+    LoadValues { db: String, series_code: String },
+    /// Leave the TUI, printing the basket in the given form.
+    Quit(Option<BasketExport>),
+}
+
+/// Form in which the basket is printed on exit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BasketExport {
+    /// One `CodeQuery` JSON object per DB and frequency.
+    Query,
+    /// One `getDataCode` URL per DB and frequency.
+    Url,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Focus {
+    Browser,
+    Basket,
+}
+
+/// A row of the database list: a category heading or a DB under it.
+#[derive(Debug, Clone, Copy)]
+pub enum DatabaseRow {
+    Category(&'static str),
+    Database(&'static DatabaseEntry),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BasketItem {
+    pub db: String,
+    pub series_code: String,
+    pub frequency: Option<String>,
+    pub name: String,
+}
+
+/// Layer hierarchy of the open DB and the position in it.
+#[derive(Debug)]
+pub struct LayerBrowser {
+    pub tree: MetadataTree,
+    /// Path of the node whose children are listed; empty for the roots.
+    pub path: Vec<u32>,
+    pub selected: usize,
+}
+
+impl LayerBrowser {
+    pub fn nodes(&self) -> &[MetadataTreeNode] {
+        self.tree.children(&self.path).unwrap_or_default()
+    }
+
+    pub fn selected_node(&self) -> Option<&MetadataTreeNode> {
+        self.nodes().get(self.selected)
+    }
+
+    /// Names of the nodes from the top level down to the listed one.
+    pub fn breadcrumb(&self, language: Language) -> Vec<String> {
+        (1..=self.path.len())
+            .filter_map(|depth| self.tree.node(&self.path[..depth]))
+            .map(|node| node.display_name(language))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct App {
+    pub language: Language,
+    pub rows: Vec<DatabaseRow>,
+    pub database_selected: usize,
+    pub browser: Option<LayerBrowser>,
+    pub basket: Vec<BasketItem>,
+    pub basket_selected: usize,
+    pub focus: Focus,
+    pub status: String,
+    values: HashMap<(String, String), Vec<DataPoint>>,
+}
+
+impl App {
+    pub fn new(language: Language) -> Self {
+        let mut rows = Vec::new();
+        for entry in catalog::databases() {
+            let new_category = match rows.last() {
+                Some(DatabaseRow::Database(previous)) => previous.category_ja != entry.category_ja,
+                _ => true,
+            };
+            if new_category {
+                rows.push(DatabaseRow::Category(entry.category_ja));
+            }
+            rows.push(DatabaseRow::Database(entry));
+        }
+        let database_selected = rows
+            .iter()
+            .position(|row| matches!(row, DatabaseRow::Database(_)))
+            .unwrap_or_default();
+
+        Self {
+            language,
+            rows,
+            database_selected,
+            browser: None,
+            basket: Vec::new(),
+            basket_selected: 0,
+            focus: Focus::Browser,
+            status: "Enter: open  a: add to basket  Tab: basket  e/u: export  q: quit".to_string(),
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn selected_database(&self) -> Option<&'static DatabaseEntry> {
+        match self.rows.get(self.database_selected) {
+            Some(DatabaseRow::Database(entry)) => Some(entry),
+            _ => None,
+        }
+    }
+
+    /// Returns the series node under the cursor with its DB.
+    pub fn selected_series(&self) -> Option<(&str, &MetadataTreeNode)> {
+        let browser = self.browser.as_ref()?;
+        let node = browser.selected_node()?;
+        series_code(node).map(|_| (browser.tree.db.as_str(), node))
+    }
+
+    /// Returns the loaded recent observations of a series.
+    pub fn recent_values(&self, db: &str, series_code: &str) -> Option<&[DataPoint]> {
+        self.values
+            .get(&(db.to_string(), series_code.to_string()))
+            .map(Vec::as_slice)
+    }
+
+    pub fn open_database(&mut self, response: &MetadataResponse) {
+        let tree = MetadataTree::from_response(response);
+        let series = tree
+            .series_codes_under(&[])
+            .filter(|code| !code.is_empty())
+            .count();
+        self.status = format!("{}: {series} series", tree.db);
+        self.browser = Some(LayerBrowser {
+            tree,
+            path: Vec::new(),
+            selected: 0,
+        });
+    }
+
+    /// Keeps the last [`RECENT_POINTS`] observations of a fetched series.
+    pub fn set_values(&mut self, db: &str, series_code: &str, mut points: Vec<DataPoint>) {
+        let skip = points.len().saturating_sub(RECENT_POINTS);
+        points.drain(..skip);
+        self.values
+            .insert((db.to_string(), series_code.to_string()), points);
+    }
+
+    pub fn handle_key(&mut self, key: KeyCode) -> Option<Request> {
+        match key {
+            KeyCode::Char('q') => return Some(Request::Quit(None)),
+            KeyCode::Char('e') => return self.export_request(BasketExport::Query),
+            KeyCode::Char('u') => return self.export_request(BasketExport::Url),
+            KeyCode::Tab => {
+                self.focus = match self.focus {
+                    Focus::Browser => Focus::Basket,
+                    Focus::Basket => Focus::Browser,
+                };
+                return None;
+            }
+            _ => {}
+        }
+        match self.focus {
+            Focus::Browser => self.handle_browser_key(key),
+            Focus::Basket => {
+                self.handle_basket_key(key);
+                None
+            }
+        }
+    }
+
+    fn handle_browser_key(&mut self, key: KeyCode) -> Option<Request> {
+        match (key, self.browser.as_mut()) {
+            (KeyCode::Up | KeyCode::Char('k'), None) => self.move_database(-1),
+            (KeyCode::Down | KeyCode::Char('j'), None) => self.move_database(1),
+            (KeyCode::Enter | KeyCode::Right | KeyCode::Char('l'), None) => {
+                return self
+                    .selected_database()
+                    .map(|entry| Request::OpenDatabase(entry.code.to_string()));
+            }
+            (KeyCode::Up | KeyCode::Char('k'), Some(browser)) => {
+                browser.selected = browser.selected.saturating_sub(1);
+            }
+            (KeyCode::Down | KeyCode::Char('j'), Some(browser))
+                if browser.selected + 1 < browser.nodes().len() =>
+            {
+                browser.selected += 1;
+            }
+            (KeyCode::Enter | KeyCode::Right | KeyCode::Char('l'), Some(browser)) => {
+                let node = browser.selected_node()?;
+                if !node.children.is_empty() {
+                    browser.path = node.path.clone();
+                    browser.selected = 0;
+                } else if let Some(code) = series_code(node) {
+                    return Some(Request::LoadValues {
+                        db: browser.tree.db.clone(),
+                        series_code: code.to_string(),
+                    });
+                }
+            }
+            (KeyCode::Left | KeyCode::Char('h') | KeyCode::Backspace | KeyCode::Esc, Some(_)) => {
+                self.go_up();
+            }
+            (KeyCode::Char('a') | KeyCode::Char(' '), Some(_)) => self.add_to_basket(),
+            _ => {}
+        }
+        None
+    }
+
+    fn handle_basket_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.basket_selected = self.basket_selected.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') if self.basket_selected + 1 < self.basket.len() => {
+                self.basket_selected += 1;
+            }
+            KeyCode::Char('x') | KeyCode::Delete | KeyCode::Backspace
+                if self.basket_selected < self.basket.len() =>
+            {
+                let item = self.basket.remove(self.basket_selected);
+                self.status = format!("removed {} from basket", item.series_code);
+                self.basket_selected = self
+                    .basket_selected
+                    .min(self.basket.len().saturating_sub(1));
+            }
+            _ => {}
+        }
+    }
+
+    fn move_database(&mut self, step: isize) {
+        let mut index = self.database_selected;
+        loop {
+            index = match index.checked_add_signed(step) {
+                Some(index) if index < self.rows.len() => index,
+                _ => return,
+            };
+            if matches!(self.rows[index], DatabaseRow::Database(_)) {
+                self.database_selected = index;
+                return;
+            }
+        }
+    }
+
+    /// Moves to the parent node, or back to the database list from the top.
+    fn go_up(&mut self) {
+        let Some(browser) = self.browser.as_mut() else {
+            return;
+        };
+        let Some(index) = browser.path.pop() else {
+            self.browser = None;
+            return;
+        };
+        browser.selected = browser
+            .nodes()
+            .iter()
+            .position(|node| node.path.last() == Some(&index))
+            .unwrap_or_default();
+    }
+
+    fn add_to_basket(&mut self) {
+        let Some((db, node)) = self.selected_series() else {
+            self.status = "only series can be added to the basket".to_string();
+            return;
+        };
+        let item = BasketItem {
+            db: db.to_string(),
+            series_code: series_code(node).unwrap_or_default().to_string(),
+            frequency: node
+                .entry
+                .as_ref()
+                .and_then(|entry| entry.frequency.clone()),
+            name: node.display_name(self.language),
+        };
+        if self.basket.contains(&item) {
+            self.status = format!("{} is already in the basket", item.series_code);
+        } else {
+            self.status = format!("added {} to basket", item.series_code);
+            self.basket.push(item);
+        }
+    }
+
+    fn export_request(&mut self, export: BasketExport) -> Option<Request> {
+        if self.basket.is_empty() {
+            self.status = "basket is empty; add series with a".to_string();
+            return None;
+        }
+        Some(Request::Quit(Some(export)))
+    }
+
+    /// Builds one `getDataCode` query per DB and frequency in the basket,
+    /// since BOJ only accepts codes of one frequency per request.
+    pub fn basket_queries(&self) -> Result<Vec<CodeQuery>, BojError> {
+        let mut groups: Vec<(&str, Option<&str>, Vec<String>)> = Vec::new();
+        for item in &self.basket {
+            let key = (item.db.as_str(), item.frequency.as_deref());
+            match groups
+                .iter_mut()
+                .find(|(db, frequency, _)| (*db, *frequency) == key)
+            {
+                Some((_, _, codes)) => codes.push(item.series_code.clone()),
+                None => groups.push((key.0, key.1, vec![item.series_code.clone()])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(db, _, codes)| Ok(CodeQuery::new(db, codes)?.with_format(Format::Json)))
+            .collect()
+    }
+}
+
+/// Series code of a node, ignoring the empty code of heading rows.
+fn series_code(node: &MetadataTreeNode) -> Option<&str> {
+    node.series_code().filter(|code| !code.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use boj_client::model::{MetadataEntry, ResponseMeta};
+
+    fn entry(code: &str, name: &str, layers: &[u32], frequency: &str) -> MetadataEntry {
+        let mut value = serde_json::json!({
+            "series_code": code,
+            "name_of_time_series_j": name,
+            "frequency": frequency,
+        });
+        for (index, layer) in layers.iter().enumerate() {
+            value[format!("layer{}", index + 1)] = serde_json::json!(layer);
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn response() -> MetadataResponse {
+        MetadataResponse {
+            meta: ResponseMeta {
+                status: 200,
+                message_id: "M181000I".to_string(),
+                message: "ok".to_string(),
+                date: None,
+            },
+            db: "FM08".to_string(),
+            entries: vec![
+                entry("", "外国為替市況", &[1], ""),
+                entry("FXERD01", "ドル・円 9時", &[1, 1], "DAILY"),
+                entry("FXERD04", "ドル・円 17時", &[1, 2], "DAILY"),
+                entry("FXERM01", "ドル・円 月中平均", &[2], "MONTHLY"),
+            ],
+            raw: String::new(),
+        }
+    }
+
+    fn opened_app() -> App {
+        let mut app = App::new(Language::Jp);
+        app.open_database(&response());
+        app
+    }
+
+    #[test]
+    fn database_list_groups_by_category_and_skips_headings() {
+        let mut app = App::new(Language::Jp);
+        assert!(matches!(app.rows[0], DatabaseRow::Category(_)));
+        assert!(app.selected_database().is_some());
+
+        for _ in 0..app.rows.len() {
+            app.handle_key(KeyCode::Down);
+            assert!(app.selected_database().is_some());
+        }
+        let first = catalog::databases()[0].code.to_string();
+        for _ in 0..app.rows.len() {
+            app.handle_key(KeyCode::Up);
+        }
+        assert_eq!(
+            app.handle_key(KeyCode::Enter),
+            Some(Request::OpenDatabase(first))
+        );
+    }
+
+    #[test]
+    fn drills_into_layers_and_back_out() {
+        let mut app = opened_app();
+        assert_eq!(app.browser.as_ref().unwrap().nodes().len(), 2);
+
+        app.handle_key(KeyCode::Enter);
+        app.handle_key(KeyCode::Down);
+        let browser = app.browser.as_ref().unwrap();
+        assert_eq!(browser.path, [1]);
+        assert_eq!(browser.breadcrumb(Language::Jp), ["外国為替市況"]);
+        assert_eq!(
+            app.handle_key(KeyCode::Enter),
+            Some(Request::LoadValues {
+                db: "FM08".to_string(),
+                series_code: "FXERD04".to_string(),
+            })
+        );
+
+        app.handle_key(KeyCode::Left);
+        let browser = app.browser.as_ref().unwrap();
+        assert!(browser.path.is_empty());
+        assert_eq!(browser.selected, 0);
+        app.handle_key(KeyCode::Left);
+        assert!(app.browser.is_none());
+    }
+
+    #[test]
+    fn keeps_only_recent_values() {
+        let mut app = opened_app();
+        let points = (0..50)
+            .map(|day| DataPoint {
+                survey_date: format!("202401{day:02}"),
+                value: Some(day.to_string()),
+            })
+            .collect();
+        app.set_values("FM08", "FXERD01", points);
+
+        let recent = app.recent_values("FM08", "FXERD01").unwrap();
+        assert_eq!(recent.len(), RECENT_POINTS);
+        assert_eq!(recent[0].survey_date, "20240114");
+    }
+
+    #[test]
+    fn basket_exports_one_query_per_frequency() {
+        let mut app = opened_app();
+        assert_eq!(app.handle_key(KeyCode::Char('e')), None);
+
+        app.handle_key(KeyCode::Enter);
+        app.handle_key(KeyCode::Char('a'));
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Char('a'));
+        app.handle_key(KeyCode::Char('a'));
+        app.handle_key(KeyCode::Left);
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Char('a'));
+        assert_eq!(app.basket.len(), 3);
+
+        let queries = app.basket_queries().unwrap();
+        assert_eq!(
+            queries
+                .iter()
+                .map(|query| query.to_url("https://www.stat-search.boj.or.jp"))
+                .collect::<Vec<_>>(),
+            [
+                "https://www.stat-search.boj.or.jp/api/v1/getDataCode?format=json&db=FM08&code=FXERD01%2CFXERD04",
+                "https://www.stat-search.boj.or.jp/api/v1/getDataCode?format=json&db=FM08&code=FXERM01",
+            ]
+        );
+        assert_eq!(
+            app.handle_key(KeyCode::Char('u')),
+            Some(Request::Quit(Some(BasketExport::Url)))
+        );
+
+        app.handle_key(KeyCode::Tab);
+        app.handle_key(KeyCode::Char('x'));
+        assert_eq!(app.basket.len(), 2);
+        assert_eq!(app.basket[0].series_code, "FXERD04");
+    }
+}
@@ -0,0 +1,6 @@
+mod app;
+mod run;
+mod ui;
+
+pub use app::{App, BasketExport, BasketItem, RECENT_POINTS, Request};
+pub use run::run_tui;
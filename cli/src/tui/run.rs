@@ -0,0 +1,103 @@
+use std::io::{self, Write};
+
+use boj_client::client::BojClient;
+use boj_client::query::{CodeQuery, Format, Language};
+use ratatui::Terminal;
+use ratatui::backend::CrosstermBackend;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{
+    EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+};
+
+use super::app::{App, BasketExport, Request};
+use super::ui;
+use crate::cache::MetadataCache;
+use crate::error::CliError;
+
+/// Runs the TUI, drawing on stderr so the basket printed to `out` on exit
+/// can be piped or redirected.
+pub fn run_tui<W: Write>(
+    client: &BojClient,
+    cache: &MetadataCache,
+    base_url: &str,
+    language: Language,
+    mut out: W,
+) -> Result<(), CliError> {
+    enable_raw_mode()?;
+    let mut stderr = io::stderr();
+    if let Err(error) = execute!(stderr, EnterAlternateScreen) {
+        let _ = disable_raw_mode();
+        return Err(error.into());
+    }
+    let result = Terminal::new(CrosstermBackend::new(stderr))
+        .map_err(CliError::from)
+        .and_then(|mut terminal| event_loop(&mut terminal, client, cache, language));
+    let _ = execute!(io::stderr(), LeaveAlternateScreen);
+    let _ = disable_raw_mode();
+
+    let Some((app, export)) = result? else {
+        return Ok(());
+    };
+    for query in app.basket_queries()? {
+        match export {
+            BasketExport::Query => {
+                serde_json::to_writer(&mut out, &query).map_err(io::Error::from)?;
+                writeln!(out)?;
+            }
+            BasketExport::Url => writeln!(out, "{}", query.to_url(base_url))?,
+        }
+    }
+    Ok(())
+}
+
+fn event_loop<W: Write>(
+    terminal: &mut Terminal<CrosstermBackend<W>>,
+    client: &BojClient,
+    cache: &MetadataCache,
+    language: Language,
+) -> Result<Option<(App, BasketExport)>, CliError> {
+    let mut app = App::new(language);
+    loop {
+        terminal.draw(|frame| ui::draw(frame, &app))?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match app.handle_key(key.code) {
+            None => {}
+            Some(Request::Quit(None)) => return Ok(None),
+            Some(Request::Quit(Some(export))) => return Ok(Some((app, export))),
+            Some(Request::OpenDatabase(db)) => {
+                app.status = format!("loading metadata of {db}...");
+                terminal.draw(|frame| ui::draw(frame, &app))?;
+                match cache.metadata(client, &db) {
+                    Ok(response) => app.open_database(&response),
+                    Err(error) => app.status = error.to_string(),
+                }
+            }
+            Some(Request::LoadValues { db, series_code }) => {
+                app.status = format!("loading {series_code}...");
+                terminal.draw(|frame| ui::draw(frame, &app))?;
+                let response = CodeQuery::new(&db, vec![series_code.clone()])
+                    .map(|query| query.with_format(Format::Json))
+                    .and_then(|query| client.get_data_code(&query));
+                match response {
+                    Ok(response) => {
+                        let points = response
+                            .series
+                            .into_iter()
+                            .find(|series| series.series_code == series_code)
+                            .map(|series| series.points)
+                            .unwrap_or_default();
+                        app.status = format!("{series_code}: {} observations", points.len());
+                        app.set_values(&db, &series_code, points);
+                    }
+                    Err(error) => app.status = error.to_string(),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use boj_client::model::{DataPoint, MetadataTreeNode};
+use boj_client::query::Language;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Sparkline, Wrap};
+
+use super::app::{App, DatabaseRow, Focus};
+
+/// Rows of recent values listed under the sparkline.
+const VALUE_ROWS: usize = 12;
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let basket_height = (app.basket.len() as u16).clamp(1, 6) + 2;
+    let [main, basket, status] = Layout::vertical([
+        Constraint::Min(0),
+        Constraint::Length(basket_height),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+    let [list, preview] =
+        Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)]).areas(main);
+
+    match &app.browser {
+        Some(_) => draw_layers(frame, app, list),
+        None => draw_databases(frame, app, list),
+    }
+    draw_preview(frame, app, preview);
+    draw_basket(frame, app, basket);
+    frame.render_widget(Paragraph::new(app.status.as_str()).dim(), status);
+}
+
+fn focused_block(title: String, focused: bool) -> Block<'static> {
+    let block = Block::bordered().title(title);
+    if focused {
+        block.border_style(Style::new().add_modifier(Modifier::BOLD))
+    } else {
+        block.border_style(Style::new().add_modifier(Modifier::DIM))
+    }
+}
+
+fn highlighted(list: List<'_>) -> List<'_> {
+    list.highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ")
+}
+
+fn draw_databases(frame: &mut Frame, app: &App, area: Rect) {
+    let items = app.rows.iter().map(|row| match row {
+        DatabaseRow::Category(category) => ListItem::new(Line::from(*category).bold()),
+        DatabaseRow::Database(entry) => {
+            ListItem::new(format!("  {:<8}{}", entry.code, entry.name_ja))
+        }
+    });
+    let list = highlighted(List::new(items)).block(focused_block(
+        "データベース".to_string(),
+        app.focus == Focus::Browser,
+    ));
+    let mut state = ListState::default().with_selected(Some(app.database_selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_layers(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(browser) = &app.browser else {
+        return;
+    };
+    let items = browser.nodes().iter().map(|node| {
+        let mut spans = vec![Span::raw(node.display_name(app.language))];
+        match node.series_code().filter(|code| !code.is_empty()) {
+            Some(code) => spans.push(Span::raw(format!("  {code}")).dim()),
+            None if !node.children.is_empty() => spans.push(Span::raw(" ▸")),
+            None => {}
+        }
+        ListItem::new(Line::from(spans))
+    });
+    let mut title = vec![browser.tree.db.clone()];
+    title.extend(browser.breadcrumb(app.language));
+    let list = highlighted(List::new(items)).block(focused_block(
+        title.join(" > "),
+        app.focus == Focus::Browser,
+    ));
+    let mut state = ListState::default().with_selected(Some(browser.selected));
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+fn draw_preview(frame: &mut Frame, app: &App, area: Rect) {
+    let block = Block::bordered().title("プレビュー");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let node = app
+        .browser
+        .as_ref()
+        .and_then(|browser| browser.selected_node());
+    let lines = match node {
+        Some(node) => metadata_lines(node, app.language),
+        None => match app.selected_database() {
+            Some(entry) => vec![
+                Line::from(entry.name_ja).bold(),
+                Line::from(format!("DB: {}", entry.code)),
+                Line::from(format!("分類: {}", entry.category_ja)),
+                Line::from("Enter で系列の階層を表示").dim(),
+            ],
+            None => Vec::new(),
+        },
+    };
+    let values = app
+        .selected_series()
+        .and_then(|(db, node)| app.recent_values(db, node.series_code()?));
+
+    let Some(values) = values else {
+        let mut lines = lines;
+        if app.selected_series().is_some() {
+            lines.push(Line::from(""));
+            lines.push(Line::from("Enter で直近の値を取得").dim());
+        }
+        frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+        return;
+    };
+    let [text, sparkline, table] = Layout::vertical([
+        Constraint::Min(4),
+        Constraint::Length(4),
+        Constraint::Length(VALUE_ROWS.min(values.len()) as u16),
+    ])
+    .areas(inner);
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), text);
+    frame.render_widget(Sparkline::default().data(sparkline_bars(values)), sparkline);
+    let rows = values
+        .iter()
+        .rev()
+        .take(VALUE_ROWS)
+        .map(|point| {
+            Line::from(format!(
+                "{}  {}",
+                point.survey_date,
+                point.value.as_deref().unwrap_or("—")
+            ))
+        })
+        .collect::<Vec<_>>();
+    frame.render_widget(Paragraph::new(rows), table);
+}
+
+fn metadata_lines(node: &MetadataTreeNode, language: Language) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(node.display_name(language)).bold()];
+    let Some(entry) = &node.entry else {
+        return lines;
+    };
+    let (unit, category, notes) = match language {
+        Language::Jp => (&entry.unit_j, &entry.category_j, &entry.notes_j),
+        Language::En => (&entry.unit, &entry.category, &entry.notes),
+    };
+    let fields = [
+        ("系列コード", &entry.series_code),
+        ("単位", unit),
+        ("期種", &entry.frequency),
+        ("分類", category),
+        ("収録開始", &entry.start_of_the_time_series),
+        ("収録終了", &entry.end_of_the_time_series),
+        ("最終更新", &entry.last_update),
+    ];
+    for (label, value) in fields {
+        if let Some(value) = value.as_deref().filter(|value| !value.is_empty()) {
+            lines.push(Line::from(format!("{label}: {value}")));
+        }
+    }
+    if let Some(notes) = notes.as_deref().filter(|notes| !notes.is_empty()) {
+        lines.push(Line::from(""));
+        lines.extend(
+            notes
+                .lines()
+                .map(|line| Line::from(line.to_string()).italic()),
+        );
+    }
+    lines
+}
+
+/// Scales values to `1..=100`, leaving missing observations empty.
+fn sparkline_bars(points: &[DataPoint]) -> Vec<Option<u64>> {
+    let values = points
+        .iter()
+        .map(|point| point.value.as_deref()?.trim().parse::<f64>().ok())
+        .collect::<Vec<_>>();
+    let (min, max) = values
+        .iter()
+        .flatten()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        });
+    let span = if max > min { max - min } else { 1.0 };
+    values
+        .into_iter()
+        .map(|value| value.map(|value| ((value - min) / span * 99.0).round() as u64 + 1))
+        .collect()
+}
+
+fn draw_basket(frame: &mut Frame, app: &App, area: Rect) {
+    let items = app.basket.iter().map(|item| {
+        ListItem::new(format!(
+            "{}  {}  {}  {}",
+            item.db,
+            item.series_code,
+            item.frequency.as_deref().unwrap_or_default(),
+            item.name
+        ))
+    });
+    let focused = app.focus == Focus::Basket;
+    let list = highlighted(List::new(items)).block(focused_block(
+        format!("バスケット ({})", app.basket.len()),
+        focused,
+    ));
+    let selected = (focused && !app.basket.is_empty()).then_some(app.basket_selected);
+    let mut state = ListState::default().with_selected(selected);
+    frame.render_stateful_widget(list, area, &mut state);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+    use unicode_width::UnicodeWidthStr;
+
+    fn point(value: Option<&str>) -> DataPoint {
+        DataPoint {
+            survey_date: "20240101".to_string(),
+            value: value.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn sparkline_scales_values_and_keeps_gaps() {
+        let points = [point(Some("150.0")), point(None), point(Some("140.0"))];
+        assert_eq!(sparkline_bars(&points), [Some(100), None, Some(1)]);
+    }
+
+    #[test]
+    fn draws_database_list_with_categories() {
+        let app = App::new(Language::Jp);
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| draw(frame, &app)).unwrap();
+
+        // Wide characters are followed by a blank filler cell.
+        let mut screen = String::new();
+        let mut filler = 0;
+        for cell in terminal.backend().buffer().content() {
+            if filler > 0 {
+                filler -= 1;
+                continue;
+            }
+            screen.push_str(cell.symbol());
+            filler = cell.symbol().width().saturating_sub(1);
+        }
+        let first = boj_client::catalog::databases()[0];
+        assert!(screen.contains("データベース"));
+        assert!(screen.contains(first.code));
+        assert!(screen.contains("バスケット (0)"));
+    }
+}
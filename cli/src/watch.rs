@@ -0,0 +1,481 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::process::{Command, ExitStatus, Stdio};
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use boj_client::client::BojClient;
+use boj_client::error::BojError;
+use boj_client::model::{CodeSeries, MetadataEntry, ResponseDiff};
+use boj_client::query::{Format, MetadataQuery};
+use serde::Serialize;
+
+use crate::args::WatchArgs;
+use crate::error::CliError;
+
+/// JST is UTC+9 all year; Japan has no daylight saving time.
+const JST_OFFSET_SECS: u64 = 9 * 3600;
+const SECS_PER_DAY: u64 = 24 * 3600;
+
+/// Time of day in JST, written `HH:MM`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckTime {
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl CheckTime {
+    fn secs_of_day(self) -> u64 {
+        u64::from(self.hour) * 3600 + u64::from(self.minute) * 60
+    }
+}
+
+impl FromStr for CheckTime {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("expected HH:MM in JST, got {text:?}");
+        let (hour, minute) = text.split_once(':').ok_or_else(invalid)?;
+        let hour = hour.parse::<u8>().map_err(|_| invalid())?;
+        let minute = minute.parse::<u8>().map_err(|_| invalid())?;
+        if hour > 23 || minute > 59 {
+            return Err(invalid());
+        }
+        Ok(Self { hour, minute })
+    }
+}
+
+impl fmt::Display for CheckTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// When `watch` polls: a daily window opening at `check_at` JST, polls
+/// `poll_interval` apart inside it, and exponential backoff on errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WatchSchedule {
+    pub check_at: CheckTime,
+    pub window: Duration,
+    pub poll_interval: Duration,
+    pub max_backoff: Duration,
+}
+
+impl WatchSchedule {
+    /// Returns how long to wait from `now` until the check window is open;
+    /// zero inside the window.
+    pub fn delay_until_window(&self, now: SystemTime) -> Duration {
+        let secs_of_day = jst_secs_of_day(now);
+        let opens = self.check_at.secs_of_day();
+        let closes = opens + self.window.as_secs();
+        let in_window = (opens..closes).contains(&secs_of_day)
+            || (opens..closes).contains(&(secs_of_day + SECS_PER_DAY));
+        let wait = if in_window {
+            0
+        } else if secs_of_day < opens {
+            opens - secs_of_day
+        } else {
+            opens + SECS_PER_DAY - secs_of_day
+        };
+        Duration::from_secs(wait)
+    }
+
+    /// Returns how long the window opened at or before `now` stays open.
+    pub fn remaining_window(&self, now: SystemTime) -> Duration {
+        let secs_of_day = jst_secs_of_day(now);
+        let opens = self.check_at.secs_of_day();
+        let elapsed = if secs_of_day >= opens {
+            secs_of_day - opens
+        } else {
+            secs_of_day + SECS_PER_DAY - opens
+        };
+        Duration::from_secs(self.window.as_secs().saturating_sub(elapsed))
+    }
+
+    /// Delay after `failures` consecutive retryable errors: the poll interval
+    /// doubled per failure, capped at `max_backoff`.
+    pub fn backoff(&self, failures: u32) -> Duration {
+        let multiplier = 2_u32.saturating_pow(failures.saturating_sub(1));
+        self.poll_interval
+            .saturating_mul(multiplier)
+            .min(self.max_backoff)
+    }
+}
+
+/// Keeps consecutive BOJ requests at least `min_interval` apart.
+#[derive(Debug)]
+pub struct RateLimiter {
+    min_interval: Duration,
+    last_request: Option<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            last_request: None,
+        }
+    }
+
+    /// Sleeps until the next request is allowed and records it.
+    pub fn wait(&mut self) {
+        if let Some(last_request) = self.last_request {
+            let elapsed = last_request.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+}
+
+/// One detected update: the series whose `last_update` changed and the
+/// points that were added or revised.
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchEvent {
+    pub db: String,
+    pub detected_at: String,
+    pub updated_series: Vec<String>,
+    pub diff: ResponseDiff,
+}
+
+/// Last seen `last_update` and data of the watched series.
+#[derive(Debug)]
+pub struct Watcher<'a> {
+    args: &'a WatchArgs,
+    last_updates: BTreeMap<String, Option<String>>,
+    series: Vec<CodeSeries>,
+    baseline: bool,
+}
+
+impl<'a> Watcher<'a> {
+    pub fn new(args: &'a WatchArgs) -> Self {
+        Self {
+            args,
+            last_updates: BTreeMap::new(),
+            series: Vec::new(),
+            baseline: false,
+        }
+    }
+
+    pub fn has_baseline(&self) -> bool {
+        self.baseline
+    }
+
+    /// Checks `last_update` in the DB metadata and fetches only the series
+    /// that changed since the previous poll.
+    ///
+    /// The first call records the baseline and returns `None`.
+    pub fn poll(
+        &mut self,
+        client: &BojClient,
+        limiter: &mut RateLimiter,
+    ) -> Result<Option<WatchEvent>, BojError> {
+        limiter.wait();
+        let query = MetadataQuery::new(self.args.db.clone())?.with_format(Format::Json);
+        let metadata = client.get_metadata(&query)?;
+        let current = last_updates(&metadata.entries, &self.args.codes)?;
+
+        let changed = if self.baseline {
+            current
+                .iter()
+                .filter(|(code, last_update)| self.last_updates.get(*code) != Some(last_update))
+                .map(|(code, _)| code.clone())
+                .collect::<Vec<_>>()
+        } else {
+            self.args.codes.clone()
+        };
+        if changed.is_empty() {
+            return Ok(None);
+        }
+
+        limiter.wait();
+        let response = client.get_data_code_all(&self.args.code_query(changed.clone())?)?;
+        let event = self.baseline.then(|| {
+            let previous = self
+                .series
+                .iter()
+                .filter(|series| changed.contains(&series.series_code));
+            WatchEvent {
+                db: metadata.db.clone(),
+                detected_at: format_jst(SystemTime::now()),
+                updated_series: changed.clone(),
+                diff: ResponseDiff::between(previous, &response.series),
+            }
+        });
+
+        self.series
+            .retain(|series| !changed.contains(&series.series_code));
+        self.series.extend(response.series);
+        self.last_updates = current;
+        self.baseline = true;
+        Ok(event)
+    }
+}
+
+/// Runs `watch` until a non-retryable error occurs.
+pub fn watch<W: Write>(client: &BojClient, args: &WatchArgs, mut out: W) -> Result<(), CliError> {
+    // Fail on bad codes or dates before waiting for the first window.
+    args.code_query(args.codes.clone())?;
+    let schedule = args.schedule();
+    let mut limiter = RateLimiter::new(Duration::from_millis(args.request_interval_ms));
+    let mut watcher = Watcher::new(args);
+    let mut failures = 0_u32;
+
+    loop {
+        if watcher.has_baseline() {
+            let wait = schedule.delay_until_window(SystemTime::now());
+            if !wait.is_zero() {
+                eprintln!(
+                    "boj: next check of {} at {}",
+                    args.db,
+                    format_jst(SystemTime::now() + wait)
+                );
+                thread::sleep(wait);
+            }
+        }
+
+        match watcher.poll(client, &mut limiter) {
+            Ok(event) => {
+                failures = 0;
+                let Some(event) = event else {
+                    thread::sleep(schedule.poll_interval);
+                    continue;
+                };
+                if !event.diff.is_empty() {
+                    report(&mut out, args.hook.as_deref(), &event)?;
+                }
+                // Series are updated once a day; wait for the next window.
+                thread::sleep(schedule.remaining_window(SystemTime::now()));
+            }
+            Err(error) if is_retryable(&error) => {
+                failures += 1;
+                let delay = schedule.backoff(failures);
+                eprintln!(
+                    "boj: {error}; retrying in {}s (failure {failures})",
+                    delay.as_secs()
+                );
+                thread::sleep(delay);
+            }
+            Err(error) => return Err(error.into()),
+        }
+    }
+}
+
+fn report<W: Write>(out: &mut W, hook: Option<&str>, event: &WatchEvent) -> Result<(), CliError> {
+    let json = serde_json::to_vec(event).map_err(io::Error::from)?;
+    match hook {
+        Some(hook) => match run_hook(hook, &event.db, &json) {
+            Ok(status) if status.success() => {}
+            Ok(status) => eprintln!("boj: hook exited with {status}"),
+            Err(error) => eprintln!("boj: failed to run hook: {error}"),
+        },
+        None => {
+            out.write_all(&json)?;
+            out.write_all(b"\n")?;
+            out.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs `hook` through the shell with the event JSON on stdin.
+fn run_hook(hook: &str, db: &str, json: &[u8]) -> io::Result<ExitStatus> {
+    let mut command = if cfg!(windows) {
+        let mut command = Command::new("cmd");
+        command.args(["/C", hook]);
+        command
+    } else {
+        let mut command = Command::new("sh");
+        command.args(["-c", hook]);
+        command
+    };
+    let mut child = command
+        .env("BOJ_WATCH_DB", db)
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(json)?;
+    }
+    child.wait()
+}
+
+/// Same rule as the MCP server's retry policy: network failures and BOJ
+/// 500/503 are worth retrying, anything else will fail again.
+fn is_retryable(error: &BojError) -> bool {
+    match error {
+        BojError::TransportError(_) => true,
+        BojError::ApiError { status, .. } => *status == 500 || *status == 503,
+        BojError::ValidationError(_) | BojError::DecodeError(_) => false,
+    }
+}
+
+fn last_updates(
+    entries: &[MetadataEntry],
+    codes: &[String],
+) -> Result<BTreeMap<String, Option<String>>, BojError> {
+    codes
+        .iter()
+        .map(|code| {
+            let entry = entries
+                .iter()
+                .find(|entry| entry.series_code.as_deref() == Some(code.as_str()))
+                .ok_or_else(|| {
+                    BojError::validation(format!("series {code} is not in the DB metadata"))
+                })?;
+            Ok((code.clone(), entry.last_update.clone()))
+        })
+        .collect()
+}
+
+fn jst_secs_of_day(time: SystemTime) -> u64 {
+    (unix_secs(time) + JST_OFFSET_SECS) % SECS_PER_DAY
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Formats `time` as `YYYY-MM-DDTHH:MM:SS+09:00`.
+fn format_jst(time: SystemTime) -> String {
+    let secs = unix_secs(time) + JST_OFFSET_SECS;
+    let (year, month, day) = civil_from_days(secs / SECS_PER_DAY);
+    let secs_of_day = secs % SECS_PER_DAY;
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}+09:00",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
+}
+
+/// Converts days since 1970-01-01 to a proleptic Gregorian date.
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2026-02-19T08:00:00+09:00.
+    const FEB_19_0800_JST: u64 = 1_771_455_600;
+
+    fn jst(offset_secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(FEB_19_0800_JST + offset_secs)
+    }
+
+    fn schedule() -> WatchSchedule {
+        WatchSchedule {
+            check_at: "08:50".parse().unwrap(),
+            window: Duration::from_secs(2 * 3600),
+            poll_interval: Duration::from_secs(300),
+            max_backoff: Duration::from_secs(3600),
+        }
+    }
+
+    #[test]
+    fn check_time_parses_hours_and_minutes() {
+        assert_eq!(
+            "8:05".parse::<CheckTime>(),
+            Ok(CheckTime { hour: 8, minute: 5 })
+        );
+        assert!("24:00".parse::<CheckTime>().is_err());
+        assert!("0850".parse::<CheckTime>().is_err());
+    }
+
+    #[test]
+    fn waits_for_window_and_polls_inside_it() {
+        let schedule = schedule();
+        assert_eq!(format_jst(jst(0)), "2026-02-19T08:00:00+09:00");
+        assert_eq!(
+            schedule.delay_until_window(jst(0)),
+            Duration::from_secs(50 * 60)
+        );
+        assert_eq!(schedule.delay_until_window(jst(60 * 60)), Duration::ZERO);
+        assert_eq!(
+            schedule.remaining_window(jst(60 * 60)),
+            Duration::from_secs(110 * 60)
+        );
+        // After the window closes at 10:50 the next one opens tomorrow.
+        assert_eq!(
+            schedule.delay_until_window(jst(3 * 3600)),
+            Duration::from_secs(21 * 3600 + 50 * 60)
+        );
+        assert_eq!(schedule.remaining_window(jst(3 * 3600)), Duration::ZERO);
+    }
+
+    #[test]
+    fn window_may_run_past_midnight() {
+        let schedule = WatchSchedule {
+            check_at: "23:30".parse().unwrap(),
+            ..schedule()
+        };
+        // 2026-02-20T00:30 JST is an hour into the window opened at 23:30.
+        let after_midnight = jst(16 * 3600 + 30 * 60);
+        assert_eq!(schedule.delay_until_window(after_midnight), Duration::ZERO);
+        assert_eq!(
+            schedule.remaining_window(after_midnight),
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_cap() {
+        let schedule = schedule();
+        assert_eq!(schedule.backoff(1), Duration::from_secs(300));
+        assert_eq!(schedule.backoff(2), Duration::from_secs(600));
+        assert_eq!(schedule.backoff(4), Duration::from_secs(2400));
+        assert_eq!(schedule.backoff(5), Duration::from_secs(3600));
+        assert_eq!(schedule.backoff(40), Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn only_transport_and_unavailable_errors_are_retried() {
+        assert!(is_retryable(&BojError::transport("down")));
+        assert!(is_retryable(&BojError::ApiError {
+            status: 503,
+            message_id: "M181090S".to_string(),
+            message: "maintenance".to_string(),
+        }));
+        assert!(!is_retryable(&BojError::ApiError {
+            status: 400,
+            message_id: "M181005E".to_string(),
+            message: "bad db".to_string(),
+        }));
+        assert!(!is_retryable(&BojError::validation("bad code")));
+    }
+
+    #[test]
+    fn last_updates_require_every_watched_code() {
+        let entries: Vec<MetadataEntry> = serde_json::from_value(serde_json::json!([
+            {"series_code": "STRDCLUCON", "last_update": "20260219"},
+            {"series_code": "STRDCLUCONH", "last_update": "20260218"},
+        ]))
+        .unwrap();
+
+        let updates = last_updates(&entries, &["STRDCLUCON".to_string()]).unwrap();
+        assert_eq!(
+            updates.get("STRDCLUCON"),
+            Some(&Some("20260219".to_string()))
+        );
+        assert!(last_updates(&entries, &["MISSING".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+
+use crate::model::{CodeResponse, DataPoint, LayerResponse, TimeSeries};
+
+use super::{
+    CATEGORY_J_KEY, CATEGORY_KEY, DB_KEY, FREQUENCY_KEY, LAST_UPDATE_KEY, NAME_J_KEY, NAME_KEY,
+    SERIES_KEY, UNIT_J_KEY, UNIT_KEY,
+};
+
+/// Borrowed view over a [`TimeSeries`] with its labels keyed for Arrow metadata.
+pub(crate) struct SeriesRef<'a> {
+    pub(crate) series_code: &'a str,
+    pub(crate) labels: [(&'static str, Option<&'a str>); 8],
+    pub(crate) points: &'a [DataPoint],
+}
+
+impl<'a> SeriesRef<'a> {
+    pub(crate) fn new<T: TimeSeries>(series: &'a T) -> Self {
+        Self {
+            series_code: series.series_code(),
+            labels: [
+                (NAME_J_KEY, series.name_j()),
+                (NAME_KEY, series.name()),
+                (UNIT_J_KEY, series.unit_j()),
+                (UNIT_KEY, series.unit()),
+                (FREQUENCY_KEY, series.frequency()),
+                (CATEGORY_J_KEY, series.category_j()),
+                (CATEGORY_KEY, series.category()),
+                (LAST_UPDATE_KEY, series.last_update()),
+            ],
+            points: series.points(),
+        }
+    }
+}
+
+impl SeriesRef<'_> {
+    /// Returns the label stored under `key`, such as [`FREQUENCY_KEY`].
+    #[cfg_attr(not(feature = "parquet"), allow(dead_code))]
+    pub(crate) fn label(&self, key: &str) -> Option<&str> {
+        self.labels
+            .iter()
+            .find(|(label_key, _)| *label_key == key)
+            .and_then(|(_, value)| *value)
+    }
+
+    fn metadata(&self) -> HashMap<String, String> {
+        self.labels
+            .iter()
+            .filter_map(|(key, value)| Some((key.to_string(), (*value)?.to_string())))
+            .collect()
+    }
+}
+
+impl CodeResponse {
+    /// Converts the series into a long-form Arrow [`RecordBatch`].
+    ///
+    /// The batch has one row per observation with the columns `series_code`
+    /// (`Utf8`), `period` (`Utf8`, the BOJ survey date) and `value`
+    /// (`Float64`, null for missing values). Series labels are stored as JSON
+    /// under the [`SERIES_KEY`](crate::arrow::SERIES_KEY) schema metadata key.
+    ///
+    /// Responses merged by [`BojClient::get_data_code_all`] convert the same
+    /// way, so every page ends up in one batch.
+    ///
+    /// [`BojClient::get_data_code_all`]: crate::client::BojClient::get_data_code_all
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowError`] when a value is not a number.
+    pub fn to_long_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        long_record_batch(
+            self.parameter.db.as_deref(),
+            self.series.iter().map(SeriesRef::new),
+        )
+    }
+
+    /// Converts the series into a wide-form Arrow [`RecordBatch`].
+    ///
+    /// The first column is `period` (`Utf8`) holding every survey date found
+    /// in any series, sorted ascending. Each series becomes one nullable
+    /// `Float64` column named by its series code, whose field metadata carries
+    /// the series names, units, category, frequency and last update.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowError`] when a value is not a number.
+    pub fn to_wide_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        wide_record_batch(
+            self.parameter.db.as_deref(),
+            self.series.iter().map(SeriesRef::new),
+        )
+    }
+}
+
+impl LayerResponse {
+    /// Converts the series into a long-form Arrow [`RecordBatch`].
+    ///
+    /// See [`CodeResponse::to_long_record_batch`] for the layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowError`] when a value is not a number.
+    pub fn to_long_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        long_record_batch(
+            self.parameter.db.as_deref(),
+            self.series.iter().map(SeriesRef::new),
+        )
+    }
+
+    /// Converts the series into a wide-form Arrow [`RecordBatch`].
+    ///
+    /// See [`CodeResponse::to_wide_record_batch`] for the layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArrowError`] when a value is not a number.
+    pub fn to_wide_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        wide_record_batch(
+            self.parameter.db.as_deref(),
+            self.series.iter().map(SeriesRef::new),
+        )
+    }
+}
+
+fn long_record_batch<'a>(
+    db: Option<&str>,
+    series: impl Iterator<Item = SeriesRef<'a>>,
+) -> Result<RecordBatch, ArrowError> {
+    let mut codes = Vec::new();
+    let mut periods = Vec::new();
+    let mut values = Vec::new();
+    let mut labels = serde_json::Map::new();
+
+    for series in series {
+        for point in series.points {
+            codes.push(series.series_code);
+            periods.push(point.survey_date.as_str());
+            values.push(parse_value(series.series_code, point)?);
+        }
+        let metadata = series
+            .metadata()
+            .into_iter()
+            .map(|(key, value)| (key, serde_json::Value::String(value)))
+            .collect::<serde_json::Map<_, _>>();
+        labels.insert(
+            series.series_code.to_string(),
+            serde_json::Value::Object(metadata),
+        );
+    }
+
+    let mut metadata = schema_metadata(db);
+    metadata.insert(
+        SERIES_KEY.to_string(),
+        serde_json::Value::Object(labels).to_string(),
+    );
+    let schema = Schema::new_with_metadata(
+        vec![
+            Field::new("series_code", DataType::Utf8, false),
+            Field::new("period", DataType::Utf8, false),
+            Field::new("value", DataType::Float64, true),
+        ],
+        metadata,
+    );
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(codes)),
+        Arc::new(StringArray::from(periods)),
+        Arc::new(Float64Array::from(values)),
+    ];
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+fn wide_record_batch<'a>(
+    db: Option<&str>,
+    series: impl Iterator<Item = SeriesRef<'a>>,
+) -> Result<RecordBatch, ArrowError> {
+    let series = series.collect::<Vec<_>>();
+    let periods = series
+        .iter()
+        .flat_map(|series| series.points.iter())
+        .map(|point| point.survey_date.as_str())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let rows = periods
+        .iter()
+        .enumerate()
+        .map(|(row, period)| (*period, row))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut fields = vec![Field::new("period", DataType::Utf8, false)];
+    let mut columns: Vec<ArrayRef> = vec![Arc::new(StringArray::from(periods.clone()))];
+    for series in &series {
+        let mut values = vec![None; periods.len()];
+        for point in series.points {
+            values[rows[point.survey_date.as_str()]] = parse_value(series.series_code, point)?;
+        }
+        fields.push(
+            Field::new(series.series_code, DataType::Float64, true)
+                .with_metadata(series.metadata()),
+        );
+        columns.push(Arc::new(Float64Array::from(values)));
+    }
+
+    let schema = Schema::new_with_metadata(fields, schema_metadata(db));
+    RecordBatch::try_new(Arc::new(schema), columns)
+}
+
+fn schema_metadata(db: Option<&str>) -> HashMap<String, String> {
+    db.map(|db| (DB_KEY.to_string(), db.to_string()))
+        .into_iter()
+        .collect()
+}
+
+/// Parses a BOJ value as `f64`, keeping missing values as `None`.
+pub(crate) fn parse_value(series_code: &str, point: &DataPoint) -> Result<Option<f64>, ArrowError> {
+    point
+        .numeric_value()
+        .map_err(|error| ArrowError::ParseError(format!("series {series_code}: {error}")))
+}
@@ -0,0 +1,31 @@
+mod convert;
+
+#[cfg(feature = "parquet")]
+pub(crate) use convert::{SeriesRef, parse_value};
+
+pub use arrow_array::RecordBatch;
+pub use arrow_schema::ArrowError;
+
+/// Schema metadata key holding the DB code echoed by the response.
+pub const DB_KEY: &str = "boj:db";
+/// Schema metadata key holding per-series labels of a long-form batch.
+///
+/// The value is a JSON object keyed by series code, whose values use the
+/// field metadata keys below (for example `{"FXERD01": {"boj:unit_j": "円"}}`).
+pub const SERIES_KEY: &str = "boj:series";
+/// Field metadata key for the Japanese series name.
+pub const NAME_J_KEY: &str = "boj:name_j";
+/// Field metadata key for the English series name.
+pub const NAME_KEY: &str = "boj:name";
+/// Field metadata key for the Japanese unit label.
+pub const UNIT_J_KEY: &str = "boj:unit_j";
+/// Field metadata key for the English unit label.
+pub const UNIT_KEY: &str = "boj:unit";
+/// Field metadata key for the frequency label reported by BOJ.
+pub const FREQUENCY_KEY: &str = "boj:frequency";
+/// Field metadata key for the Japanese category label.
+pub const CATEGORY_J_KEY: &str = "boj:category_j";
+/// Field metadata key for the English category label.
+pub const CATEGORY_KEY: &str = "boj:category";
+/// Field metadata key for the last update reported by BOJ.
+pub const LAST_UPDATE_KEY: &str = "boj:last_update";
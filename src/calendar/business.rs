@@ -0,0 +1,218 @@
+use crate::error::BojError;
+use crate::period::{date_from_days, days_since_epoch, weekday};
+use crate::query::Frequency;
+use crate::transform::{NumericSeries, Observation, Period};
+
+use super::holidays::holidays;
+
+/// Returns `true` for the bank holidays of December 31 and January 1 to 3,
+/// when Japanese banks and financial markets are closed.
+pub fn is_bank_holiday(date: u32) -> bool {
+    matches!(date % 10_000, 1231 | 101 | 102 | 103)
+}
+
+/// Returns `true` when `date` (`YYYYMMDD`) is a Tokyo business day: a
+/// weekday that is neither a [holiday](super::holiday) nor a
+/// [bank holiday](is_bank_holiday).
+///
+/// Invalid dates are not business days.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::is_business_day;
+///
+/// assert!(is_business_day(20240104));
+/// assert!(!is_business_day(20240103)); // bank holiday
+/// assert!(!is_business_day(20240108)); // Coming of Age Day
+/// assert!(!is_business_day(20240113)); // Saturday
+/// ```
+pub fn is_business_day(date: u32) -> bool {
+    match valid_day(date) {
+        Ok(day) => HolidayCache::default().is_business_day(day),
+        Err(_) => false,
+    }
+}
+
+/// Business days from `first` through `last` (`YYYYMMDD`), in order.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when either date is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::business_days;
+///
+/// let days = business_days(20231229, 20240105)?.collect::<Vec<_>>();
+/// assert_eq!(days, [20231229, 20240104, 20240105]);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn business_days(first: u32, last: u32) -> Result<BusinessDays, BojError> {
+    Ok(BusinessDays {
+        next: valid_day(first)?,
+        last: valid_day(last)?,
+        holidays: HolidayCache::default(),
+    })
+}
+
+/// Returns `date` when it is a business day, otherwise the next business
+/// day.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `date` is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::{roll_backward, roll_forward};
+///
+/// assert_eq!(roll_forward(20240101)?, 20240104);
+/// assert_eq!(roll_backward(20240101)?, 20231229);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn roll_forward(date: u32) -> Result<u32, BojError> {
+    roll(date, 1)
+}
+
+/// Returns `date` when it is a business day, otherwise the previous
+/// business day.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `date` is invalid.
+pub fn roll_backward(date: u32) -> Result<u32, BojError> {
+    roll(date, -1)
+}
+
+/// Business days between the first and last value of a daily series that
+/// have no value, because the observation is absent or reported empty.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `series` is not daily.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::missing_business_days;
+/// use boj_client::query::Frequency;
+/// use boj_client::transform::{NumericSeries, Observation, Period};
+///
+/// let observations = ["20240104", "20240105", "20240110"]
+///     .into_iter()
+///     .map(|label| Ok(Observation { period: Period::parse(label, Frequency::D)?, value: Some(0.1) }))
+///     .collect::<Result<Vec<_>, boj_client::error::BojError>>()?;
+/// let series = NumericSeries::new("FM01", Frequency::D, observations)?;
+///
+/// // January 8 is Coming of Age Day.
+/// let missing = missing_business_days(&series)?;
+/// assert_eq!(missing.iter().map(ToString::to_string).collect::<Vec<_>>(), ["20240109"]);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn missing_business_days(series: &NumericSeries) -> Result<Vec<Period>, BojError> {
+    Ok(business_day_periods(series)?
+        .into_iter()
+        .filter(|&period| series.value_at(period).is_none())
+        .collect())
+}
+
+/// Reindexes a daily series on the business days between its first and
+/// last value.
+///
+/// Business days without a value become missing observations and
+/// observations on other days are dropped, so series from different
+/// sources line up day by day, e.g. before
+/// [`align`](crate::transform::align).
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `series` is not daily.
+pub fn to_business_days(series: &NumericSeries) -> Result<NumericSeries, BojError> {
+    let observations = business_day_periods(series)?
+        .into_iter()
+        .map(|period| Observation {
+            period,
+            value: series.value_at(period),
+        })
+        .collect();
+    NumericSeries::new(series.series_code.clone(), Frequency::D, observations)
+}
+
+/// Iterator over business days returned by [`business_days`].
+#[derive(Debug, Clone)]
+pub struct BusinessDays {
+    next: i32,
+    last: i32,
+    holidays: HolidayCache,
+}
+
+impl Iterator for BusinessDays {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        while self.next <= self.last {
+            let day = self.next;
+            self.next += 1;
+            if self.holidays.is_business_day(day) {
+                return Some(date_from_days(day));
+            }
+        }
+        None
+    }
+}
+
+/// Holiday dates of the most recently used year, so iterating over a range
+/// computes each year's holidays once.
+#[derive(Debug, Clone, Default)]
+struct HolidayCache {
+    year: Option<u32>,
+    dates: Vec<u32>,
+}
+
+impl HolidayCache {
+    fn is_business_day(&mut self, day: i32) -> bool {
+        let date = date_from_days(day);
+        if weekday(day) >= 5 || is_bank_holiday(date) {
+            return false;
+        }
+        let year = date / 10_000;
+        if self.year != Some(year) {
+            self.year = Some(year);
+            self.dates = holidays(year).iter().map(|holiday| holiday.date).collect();
+        }
+        !self.dates.contains(&date)
+    }
+}
+
+fn valid_day(date: u32) -> Result<i32, BojError> {
+    Period::parse(&date.to_string(), Frequency::D)?;
+    Ok(days_since_epoch(date))
+}
+
+fn roll(date: u32, step: i32) -> Result<u32, BojError> {
+    let mut day = valid_day(date)?;
+    let mut holidays = HolidayCache::default();
+    while !holidays.is_business_day(day) {
+        day += step;
+    }
+    Ok(date_from_days(day))
+}
+
+fn business_day_periods(series: &NumericSeries) -> Result<Vec<Period>, BojError> {
+    if series.frequency != Frequency::D {
+        return Err(BojError::validation(format!(
+            "business days apply to daily series; {} is {}",
+            series.series_code,
+            series.frequency.as_query_value()
+        )));
+    }
+    let Some((first, last)) = series.observed_range() else {
+        return Ok(Vec::new());
+    };
+    business_days(first.first_day(), last.first_day())?
+        .map(|date| Period::containing(date, Frequency::D))
+        .collect()
+}
@@ -0,0 +1,217 @@
+use crate::error::BojError;
+use crate::query::Frequency;
+use crate::transform::Period;
+
+/// Returns the period of `frequency` that contains `period`, such as the
+/// fiscal year of a month or the calendar year of a fiscal quarter.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when no single period of
+/// `frequency` contains `period`, e.g. the fiscal year of calendar 2023,
+/// which spans FY2022 and FY2023.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::to_frequency;
+/// use boj_client::query::Frequency;
+/// use boj_client::transform::Period;
+///
+/// let march = Period::parse("202403", Frequency::M)?;
+/// assert_eq!(to_frequency(march, Frequency::Fy)?.to_string(), "2023");
+/// assert_eq!(to_frequency(march, Frequency::Fh)?.to_string(), "202302");
+/// assert_eq!(to_frequency(march, Frequency::Cy)?.to_string(), "2024");
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn to_frequency(period: Period, frequency: Frequency) -> Result<Period, BojError> {
+    let containing = Period::containing(period.first_day(), frequency)?;
+    if !containing.contains(period) {
+        return Err(BojError::validation(format!(
+            "{} {period} is not inside a single {} period",
+            period.frequency().as_query_value(),
+            frequency.as_query_value()
+        )));
+    }
+    Ok(containing)
+}
+
+/// Splits `period` into the periods of `frequency` it is made of, such as
+/// the months or quarters of a fiscal year.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `frequency` is `W`, whose
+/// periods depend on the weekday of each series, or when `period` does not
+/// start and end on `frequency` boundaries, e.g. a fiscal year split into
+/// calendar halves.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::expand;
+/// use boj_client::query::Frequency;
+/// use boj_client::transform::Period;
+///
+/// let fy2023 = Period::parse("2023", Frequency::Fy)?;
+/// let quarters = expand(fy2023, Frequency::Q)?;
+/// let labels = quarters.iter().map(ToString::to_string).collect::<Vec<_>>();
+/// assert_eq!(labels, ["202302", "202303", "202304", "202401"]);
+/// assert_eq!(expand(fy2023, Frequency::M)?.len(), 12);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn expand(period: Period, frequency: Frequency) -> Result<Vec<Period>, BojError> {
+    let periods = overlapping(period, frequency)?;
+    let aligned = periods.first().map(|first| first.first_day()) == Some(period.first_day())
+        && periods.last().map(|last| last.last_day()) == Some(period.last_day());
+    if !aligned {
+        return Err(BojError::validation(format!(
+            "{} {period} is not made of whole {} periods",
+            period.frequency().as_query_value(),
+            frequency.as_query_value()
+        )));
+    }
+    Ok(periods)
+}
+
+/// Returns every period of `frequency` that shares at least one day with
+/// `period`, in order.
+///
+/// Unlike [`expand`], the result may reach outside `period`: calendar 2023
+/// overlaps fiscal years 2022 and 2023.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `frequency` is `W`.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::overlapping;
+/// use boj_client::query::Frequency;
+/// use boj_client::transform::Period;
+///
+/// let cy2023 = Period::parse("2023", Frequency::Cy)?;
+/// let fiscal_years = overlapping(cy2023, Frequency::Fy)?;
+/// let labels = fiscal_years.iter().map(ToString::to_string).collect::<Vec<_>>();
+/// assert_eq!(labels, ["2022", "2023"]);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn overlapping(period: Period, frequency: Frequency) -> Result<Vec<Period>, BojError> {
+    if frequency == Frequency::W {
+        return Err(BojError::validation(
+            "weekly periods depend on the weekday of each series and cannot be derived from dates",
+        ));
+    }
+    let first = Period::containing(period.first_day(), frequency)?;
+    let last = Period::containing(period.last_day(), frequency)?;
+    let count = last.periods_since(first).unwrap_or(0);
+    Ok((0..=count).map(|index| first.offset(index)).collect())
+}
+
+/// Returns the fiscal quarter (1 to 4) of `period`, counting from April:
+/// April to June is the first quarter and January to March the fourth.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `period` is longer than a
+/// quarter.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::fiscal_quarter;
+/// use boj_client::query::Frequency;
+/// use boj_client::transform::Period;
+///
+/// assert_eq!(fiscal_quarter(Period::parse("202405", Frequency::M)?)?, 1);
+/// assert_eq!(fiscal_quarter(Period::parse("202401", Frequency::Q)?)?, 4);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn fiscal_quarter(period: Period) -> Result<u8, BojError> {
+    let quarter = to_frequency(period, Frequency::Q)?;
+    let month = quarter.first_day() / 100 % 100;
+    Ok(match month {
+        4 => 1,
+        7 => 2,
+        10 => 3,
+        _ => 4,
+    })
+}
+
+/// `STARTDATE`/`ENDDATE` periods that request a range given in another
+/// basis, such as fiscal 2023 of a monthly series.
+///
+/// The API takes `YYYY` dates for `CY`/`FY` series, `YYYYHH` for halves,
+/// `YYYYQQ` for quarters and `YYYYMM` for monthly, weekly and daily series.
+/// When the range does not fall on boundaries of the series frequency, the
+/// query covers every period that overlaps it, so the response may start
+/// before or end after the requested range.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::QueryRange;
+/// use boj_client::query::{CodeQuery, Frequency};
+/// use boj_client::transform::Period;
+///
+/// let fy2023 = Period::parse("2023", Frequency::Fy)?;
+/// let range = QueryRange::for_period(fy2023, Frequency::M)?;
+/// assert_eq!(range.start().to_string(), "202304");
+/// assert_eq!(range.end().to_string(), "202403");
+///
+/// let _query = CodeQuery::new("PR01", vec!["PRCG20_2200000000".to_string()])?
+///     .with_start_date(range.start().to_string())?
+///     .with_end_date(range.end().to_string())?;
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueryRange {
+    start: Period,
+    end: Period,
+}
+
+impl QueryRange {
+    /// Query range covering `period` for a series of `frequency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when a period cannot be derived
+    /// from the dates of `period`.
+    pub fn for_period(period: Period, frequency: Frequency) -> Result<Self, BojError> {
+        Self::between(period, period, frequency)
+    }
+
+    /// Query range covering `first` through `last` for a series of
+    /// `frequency`; the two periods may have different frequencies.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `last` ends before `first`
+    /// starts.
+    pub fn between(first: Period, last: Period, frequency: Frequency) -> Result<Self, BojError> {
+        if last.last_day() < first.first_day() {
+            return Err(BojError::validation(format!(
+                "range end {last} is earlier than range start {first}"
+            )));
+        }
+        let query_frequency = match frequency {
+            Frequency::W | Frequency::D => Frequency::M,
+            other => other,
+        };
+        Ok(Self {
+            start: Period::containing(first.first_day(), query_frequency)?,
+            end: Period::containing(last.last_day(), query_frequency)?,
+        })
+    }
+
+    /// Period to pass as `STARTDATE`.
+    pub fn start(&self) -> Period {
+        self.start
+    }
+
+    /// Period to pass as `ENDDATE`.
+    pub fn end(&self) -> Period {
+        self.end
+    }
+}
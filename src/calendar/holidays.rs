@@ -0,0 +1,259 @@
+use serde::Serialize;
+
+use crate::period::{date_from_days, days_since_epoch, weekday, ymd};
+
+/// A Japanese national holiday, substitute holiday or citizens' holiday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Holiday {
+    /// Date as a `YYYYMMDD` integer.
+    pub date: u32,
+    /// English name.
+    pub name: &'static str,
+    /// Japanese name.
+    pub name_j: &'static str,
+}
+
+/// Returns the holidays of `year` in date order.
+///
+/// The list follows the Act on National Holidays (国民の祝日に関する法律)
+/// from 1949, including the moved holidays of 2020 and 2021 and the
+/// one-off ceremonial holidays. A holiday on a Sunday gives a substitute
+/// holiday (振替休日) from April 1973, on the next day that is not a
+/// holiday from 2007. A day between two holidays is a citizens' holiday
+/// (国民の休日) from 1986. Equinox days use the astronomical approximation,
+/// which matches the dates the Cabinet announces each February.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::holidays;
+///
+/// let holidays = holidays(2026);
+/// let september = holidays
+///     .iter()
+///     .filter(|holiday| holiday.date / 100 == 202609)
+///     .map(|holiday| (holiday.date, holiday.name))
+///     .collect::<Vec<_>>();
+/// assert_eq!(
+///     september,
+///     [
+///         (20260921, "Respect for the Aged Day"),
+///         (20260922, "Citizens' Holiday"),
+///         (20260923, "Autumnal Equinox Day"),
+///     ]
+/// );
+/// ```
+pub fn holidays(year: u32) -> Vec<Holiday> {
+    let mut holidays = national_holidays(year);
+
+    let mut substitutes = Vec::new();
+    for holiday in &holidays {
+        if holiday.date < 19730412 || weekday(days_since_epoch(holiday.date)) != 6 {
+            continue;
+        }
+        let mut day = days_since_epoch(holiday.date) + 1;
+        // Until 2006 the substitute was the Monday only.
+        while holiday.date >= 20070101 && is_listed(&holidays, date_from_days(day)) {
+            day += 1;
+        }
+        let date = date_from_days(day);
+        if !is_listed(&holidays, date) {
+            substitutes.push(Holiday {
+                date,
+                name: "Substitute Holiday",
+                name_j: "振替休日",
+            });
+        }
+    }
+
+    let mut citizens = Vec::new();
+    for pair in holidays.windows(2) {
+        let (before, after) = (
+            days_since_epoch(pair[0].date),
+            days_since_epoch(pair[1].date),
+        );
+        let date = date_from_days(before + 1);
+        // Before 2007 a Sunday or substitute holiday stayed as it was.
+        let displaced =
+            date < 20070101 && (weekday(before + 1) == 6 || is_listed(&substitutes, date));
+        if after - before == 2 && date >= 19851227 && !displaced {
+            citizens.push(Holiday {
+                date,
+                name: "Citizens' Holiday",
+                name_j: "国民の休日",
+            });
+        }
+    }
+
+    holidays.extend(substitutes);
+    holidays.extend(citizens);
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays.dedup_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// Returns the holiday on `date` (`YYYYMMDD`), if any.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::calendar::holiday;
+///
+/// assert_eq!(holiday(20240506).map(|holiday| holiday.name_j), Some("振替休日"));
+/// assert_eq!(holiday(20240507), None);
+/// ```
+pub fn holiday(date: u32) -> Option<Holiday> {
+    holidays(date / 10_000)
+        .into_iter()
+        .find(|holiday| holiday.date == date)
+}
+
+fn is_listed(holidays: &[Holiday], date: u32) -> bool {
+    holidays.iter().any(|holiday| holiday.date == date)
+}
+
+/// Holidays named in the Act on National Holidays, without substitute and
+/// citizens' holidays.
+fn national_holidays(year: u32) -> Vec<Holiday> {
+    if year < 1949 {
+        return Vec::new();
+    }
+    let mut holidays = Vec::new();
+    let mut add = |date: u32, name: &'static str, name_j: &'static str| {
+        holidays.push(Holiday { date, name, name_j });
+    };
+    let on = |month: u32, day: u32| ymd(year, month, day);
+
+    add(on(1, 1), "New Year's Day", "元日");
+    let coming_of_age = if year >= 2000 {
+        nth_monday(year, 1, 2)
+    } else {
+        on(1, 15)
+    };
+    add(coming_of_age, "Coming of Age Day", "成人の日");
+    if year >= 1967 {
+        add(on(2, 11), "National Foundation Day", "建国記念の日");
+    }
+    if year >= 2020 {
+        add(on(2, 23), "The Emperor's Birthday", "天皇誕生日");
+    }
+    add(
+        on(3, equinox_day(year, Equinox::Vernal)),
+        "Vernal Equinox Day",
+        "春分の日",
+    );
+    match year {
+        2007.. => add(on(4, 29), "Showa Day", "昭和の日"),
+        1989..=2006 => add(on(4, 29), "Greenery Day", "みどりの日"),
+        _ => add(on(4, 29), "The Emperor's Birthday", "天皇誕生日"),
+    }
+    add(on(5, 3), "Constitution Memorial Day", "憲法記念日");
+    if year >= 2007 {
+        add(on(5, 4), "Greenery Day", "みどりの日");
+    }
+    add(on(5, 5), "Children's Day", "こどもの日");
+    let marine = match year {
+        2020 => Some(on(7, 23)),
+        2021 => Some(on(7, 22)),
+        2003.. => Some(nth_monday(year, 7, 3)),
+        1996..=2002 => Some(on(7, 20)),
+        _ => None,
+    };
+    if let Some(date) = marine {
+        add(date, "Marine Day", "海の日");
+    }
+    let mountain = match year {
+        2020 => Some(on(8, 10)),
+        2021 => Some(on(8, 8)),
+        2016.. => Some(on(8, 11)),
+        _ => None,
+    };
+    if let Some(date) = mountain {
+        add(date, "Mountain Day", "山の日");
+    }
+    let respect_for_the_aged = match year {
+        2003.. => Some(nth_monday(year, 9, 3)),
+        1966..=2002 => Some(on(9, 15)),
+        _ => None,
+    };
+    if let Some(date) = respect_for_the_aged {
+        add(date, "Respect for the Aged Day", "敬老の日");
+    }
+    add(
+        on(9, equinox_day(year, Equinox::Autumnal)),
+        "Autumnal Equinox Day",
+        "秋分の日",
+    );
+    match year {
+        2020 => add(on(7, 24), "Sports Day", "スポーツの日"),
+        2021 => add(on(7, 23), "Sports Day", "スポーツの日"),
+        2022.. => add(nth_monday(year, 10, 2), "Sports Day", "スポーツの日"),
+        2000..=2019 => add(nth_monday(year, 10, 2), "Health and Sports Day", "体育の日"),
+        1966..=1999 => add(on(10, 10), "Health and Sports Day", "体育の日"),
+        _ => {}
+    }
+    add(on(11, 3), "Culture Day", "文化の日");
+    add(on(11, 23), "Labor Thanksgiving Day", "勤労感謝の日");
+    if (1989..=2018).contains(&year) {
+        add(on(12, 23), "The Emperor's Birthday", "天皇誕生日");
+    }
+
+    for &(date, name, name_j) in CEREMONIAL_HOLIDAYS {
+        if date / 10_000 == year {
+            add(date, name, name_j);
+        }
+    }
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
+
+/// One-off holidays enacted for imperial ceremonies.
+const CEREMONIAL_HOLIDAYS: &[(u32, &str, &str)] = &[
+    (
+        19590410,
+        "Wedding Ceremony of Crown Prince Akihito",
+        "皇太子明仁親王の結婚の儀",
+    ),
+    (
+        19890224,
+        "Funeral Ceremony of Emperor Showa",
+        "昭和天皇の大喪の礼",
+    ),
+    (19901112, "Enthronement Ceremony", "即位礼正殿の儀"),
+    (
+        19930609,
+        "Wedding Ceremony of Crown Prince Naruhito",
+        "皇太子徳仁親王の結婚の儀",
+    ),
+    (20190501, "Enthronement Day", "天皇の即位の日"),
+    (20191022, "Enthronement Ceremony", "即位礼正殿の儀"),
+];
+
+#[derive(Clone, Copy)]
+enum Equinox {
+    Vernal,
+    Autumnal,
+}
+
+/// Day in March or September of the equinox holiday of `year`.
+fn equinox_day(year: u32, equinox: Equinox) -> u32 {
+    let (vernal, autumnal, leap_base) = match year {
+        ..=1979 => (20.8357, 23.2588, 1983),
+        1980..=2099 => (20.8431, 23.2488, 1980),
+        _ => (21.8510, 24.2488, 1980),
+    };
+    let base = match equinox {
+        Equinox::Vernal => vernal,
+        Equinox::Autumnal => autumnal,
+    };
+    let year = year as i32;
+    let day = base + 0.242194 * f64::from(year - 1980) - f64::from((year - leap_base) / 4);
+    day.floor() as u32
+}
+
+/// Date of the `nth` Monday of `month`.
+fn nth_monday(year: u32, month: u32, nth: u32) -> u32 {
+    let first_weekday = weekday(days_since_epoch(ymd(year, month, 1)));
+    let first_monday = 1 + (7 - first_weekday) % 7;
+    ymd(year, month, first_monday + 7 * (nth - 1))
+}
@@ -0,0 +1,10 @@
+mod business;
+mod fiscal;
+mod holidays;
+
+pub use business::{
+    BusinessDays, business_days, is_bank_holiday, is_business_day, missing_business_days,
+    roll_backward, roll_forward, to_business_days,
+};
+pub use fiscal::{QueryRange, expand, fiscal_quarter, overlapping, to_frequency};
+pub use holidays::{Holiday, holiday, holidays};
@@ -0,0 +1,347 @@
+use std::collections::HashMap;
+
+use crate::model::{
+    Bilingual, BilingualMismatch, CodeResponse, CodeSeries, DataPoint, LayerResponse, LayerSeries,
+    MetadataEntry, MetadataResponse,
+};
+use crate::query::Language;
+
+/// A row that can be joined across Japanese and English responses.
+trait BilingualRow {
+    fn merge_key(&self) -> String;
+    fn points(&self) -> &[DataPoint];
+    fn fill_from_english(&mut self, english: Self);
+}
+
+impl BilingualRow for CodeSeries {
+    fn merge_key(&self) -> String {
+        self.series_code.clone()
+    }
+
+    fn points(&self) -> &[DataPoint] {
+        &self.points
+    }
+
+    fn fill_from_english(&mut self, english: Self) {
+        prefer(&mut self.name_of_time_series, english.name_of_time_series);
+        fill(
+            &mut self.name_of_time_series_j,
+            english.name_of_time_series_j,
+        );
+        prefer(&mut self.unit, english.unit);
+        fill(&mut self.unit_j, english.unit_j);
+        prefer(&mut self.category, english.category);
+        fill(&mut self.category_j, english.category_j);
+        fill(&mut self.frequency, english.frequency);
+        fill(&mut self.last_update, english.last_update);
+        for (key, value) in english.extras {
+            self.extras.entry(key).or_insert(value);
+        }
+    }
+}
+
+impl BilingualRow for LayerSeries {
+    fn merge_key(&self) -> String {
+        self.series_code.clone()
+    }
+
+    fn points(&self) -> &[DataPoint] {
+        &self.points
+    }
+
+    fn fill_from_english(&mut self, english: Self) {
+        prefer(&mut self.name_of_time_series, english.name_of_time_series);
+        fill(
+            &mut self.name_of_time_series_j,
+            english.name_of_time_series_j,
+        );
+        prefer(&mut self.unit, english.unit);
+        fill(&mut self.unit_j, english.unit_j);
+        prefer(&mut self.category, english.category);
+        fill(&mut self.category_j, english.category_j);
+        fill(&mut self.frequency, english.frequency);
+        fill(&mut self.last_update, english.last_update);
+        for (key, value) in english.extras {
+            self.extras.entry(key).or_insert(value);
+        }
+    }
+}
+
+impl BilingualRow for MetadataEntry {
+    fn merge_key(&self) -> String {
+        match &self.series_code {
+            Some(series_code) => series_code.clone(),
+            None => [
+                self.layer1,
+                self.layer2,
+                self.layer3,
+                self.layer4,
+                self.layer5,
+            ]
+            .iter()
+            .map(|layer| layer.unwrap_or(0).to_string())
+            .collect::<Vec<_>>()
+            .join("."),
+        }
+    }
+
+    fn points(&self) -> &[DataPoint] {
+        &[]
+    }
+
+    fn fill_from_english(&mut self, english: Self) {
+        prefer(&mut self.name_of_time_series, english.name_of_time_series);
+        fill(
+            &mut self.name_of_time_series_j,
+            english.name_of_time_series_j,
+        );
+        prefer(&mut self.unit, english.unit);
+        fill(&mut self.unit_j, english.unit_j);
+        prefer(&mut self.category, english.category);
+        fill(&mut self.category_j, english.category_j);
+        prefer(&mut self.notes, english.notes);
+        fill(&mut self.notes_j, english.notes_j);
+        fill(&mut self.frequency, english.frequency);
+        fill(
+            &mut self.start_of_the_time_series,
+            english.start_of_the_time_series,
+        );
+        fill(
+            &mut self.end_of_the_time_series,
+            english.end_of_the_time_series,
+        );
+        fill(&mut self.last_update, english.last_update);
+        for (key, value) in english.extras {
+            self.extras.entry(key).or_insert(value);
+        }
+    }
+}
+
+pub(super) fn merge_code_responses(
+    japanese: CodeResponse,
+    english: CodeResponse,
+) -> Bilingual<CodeResponse> {
+    let mut mismatches = Vec::new();
+    push_next_position_mismatch(
+        japanese.next_position,
+        english.next_position,
+        &mut mismatches,
+    );
+    let series = merge_rows(japanese.series, english.series, &mut mismatches);
+
+    Bilingual {
+        merged: CodeResponse { series, ..japanese },
+        english_meta: english.meta,
+        mismatches,
+    }
+}
+
+pub(super) fn merge_layer_responses(
+    japanese: LayerResponse,
+    english: LayerResponse,
+) -> Bilingual<LayerResponse> {
+    let mut mismatches = Vec::new();
+    push_next_position_mismatch(
+        japanese.next_position,
+        english.next_position,
+        &mut mismatches,
+    );
+    let series = merge_rows(japanese.series, english.series, &mut mismatches);
+
+    Bilingual {
+        merged: LayerResponse { series, ..japanese },
+        english_meta: english.meta,
+        mismatches,
+    }
+}
+
+pub(super) fn merge_metadata_responses(
+    japanese: MetadataResponse,
+    english: MetadataResponse,
+) -> Bilingual<MetadataResponse> {
+    let mut mismatches = Vec::new();
+    let entries = merge_rows(japanese.entries, english.entries, &mut mismatches);
+
+    Bilingual {
+        merged: MetadataResponse {
+            entries,
+            ..japanese
+        },
+        english_meta: english.meta,
+        mismatches,
+    }
+}
+
+fn merge_rows<T: BilingualRow>(
+    japanese: Vec<T>,
+    english: Vec<T>,
+    mismatches: &mut Vec<BilingualMismatch>,
+) -> Vec<T> {
+    let mut positions = HashMap::<String, usize>::new();
+    for (index, row) in english.iter().enumerate() {
+        positions.entry(row.merge_key()).or_insert(index);
+    }
+    let mut english = english.into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut merged = Vec::with_capacity(japanese.len());
+    for mut row in japanese {
+        let key = row.merge_key();
+        let counterpart = positions
+            .remove(&key)
+            .and_then(|index| english[index].take());
+        match counterpart {
+            Some(counterpart) => {
+                compare_points(&key, row.points(), counterpart.points(), mismatches);
+                row.fill_from_english(counterpart);
+            }
+            None => mismatches.push(BilingualMismatch::MissingSeries {
+                key,
+                missing_in: Language::En,
+            }),
+        }
+        merged.push(row);
+    }
+
+    for row in english.into_iter().flatten() {
+        mismatches.push(BilingualMismatch::MissingSeries {
+            key: row.merge_key(),
+            missing_in: Language::Jp,
+        });
+        merged.push(row);
+    }
+
+    merged
+}
+
+fn compare_points(
+    series_code: &str,
+    japanese: &[DataPoint],
+    english: &[DataPoint],
+    mismatches: &mut Vec<BilingualMismatch>,
+) {
+    let mut english_values = HashMap::<&str, &Option<String>>::new();
+    for point in english {
+        english_values.insert(point.survey_date.as_str(), &point.value);
+    }
+
+    for point in japanese {
+        match english_values.remove(point.survey_date.as_str()) {
+            Some(value) if *value == point.value => {}
+            Some(value) => mismatches.push(BilingualMismatch::ValueMismatch {
+                series_code: series_code.to_string(),
+                survey_date: point.survey_date.clone(),
+                japanese: point.value.clone(),
+                english: value.clone(),
+            }),
+            None => mismatches.push(BilingualMismatch::MissingPoint {
+                series_code: series_code.to_string(),
+                survey_date: point.survey_date.clone(),
+                missing_in: Language::En,
+            }),
+        }
+    }
+
+    for point in english {
+        if english_values.contains_key(point.survey_date.as_str()) {
+            mismatches.push(BilingualMismatch::MissingPoint {
+                series_code: series_code.to_string(),
+                survey_date: point.survey_date.clone(),
+                missing_in: Language::Jp,
+            });
+        }
+    }
+}
+
+fn push_next_position_mismatch(
+    japanese: Option<u32>,
+    english: Option<u32>,
+    mismatches: &mut Vec<BilingualMismatch>,
+) {
+    if japanese != english {
+        mismatches.push(BilingualMismatch::NextPositionMismatch { japanese, english });
+    }
+}
+
+/// Replaces `target` with `source` when `source` has a value.
+fn prefer(target: &mut Option<String>, source: Option<String>) {
+    if source.is_some() {
+        *target = source;
+    }
+}
+
+/// Fills `target` from `source` only when `target` is empty.
+fn fill(target: &mut Option<String>, source: Option<String>) {
+    if target.is_none() {
+        *target = source;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::merge_rows;
+    use crate::model::{BilingualMismatch, CodeSeries, DataPoint};
+    use crate::query::Language;
+
+    fn series(code: &str, points: &[(&str, Option<&str>)]) -> CodeSeries {
+        CodeSeries {
+            series_code: code.to_string(),
+            name_of_time_series_j: None,
+            name_of_time_series: None,
+            unit_j: None,
+            unit: None,
+            frequency: None,
+            category_j: None,
+            category: None,
+            last_update: None,
+            points: points
+                .iter()
+                .map(|(date, value)| DataPoint {
+                    survey_date: date.to_string(),
+                    value: value.map(str::to_string),
+                })
+                .collect(),
+            extras: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn merge_rows_reports_code_and_point_mismatches() {
+        let mut japanese = series("A", &[("202401", Some("1")), ("202402", Some("2"))]);
+        japanese.name_of_time_series_j = Some("系列A".to_string());
+        let mut english = series("A", &[("202401", Some("1")), ("202402", Some("3"))]);
+        english.name_of_time_series = Some("Series A".to_string());
+        let english_only = series("B", &[("202403", None)]);
+
+        let mut mismatches = Vec::new();
+        let merged = merge_rows(
+            vec![japanese, series("C", &[])],
+            vec![english, english_only],
+            &mut mismatches,
+        );
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged[0].name_of_time_series_j.as_deref(), Some("系列A"));
+        assert_eq!(merged[0].name_of_time_series.as_deref(), Some("Series A"));
+        assert_eq!(
+            mismatches,
+            vec![
+                BilingualMismatch::ValueMismatch {
+                    series_code: "A".to_string(),
+                    survey_date: "202402".to_string(),
+                    japanese: Some("2".to_string()),
+                    english: Some("3".to_string()),
+                },
+                BilingualMismatch::MissingSeries {
+                    key: "C".to_string(),
+                    missing_in: Language::En,
+                },
+                BilingualMismatch::MissingSeries {
+                    key: "B".to_string(),
+                    missing_in: Language::Jp,
+                },
+            ]
+        );
+    }
+}
@@ -1,10 +1,12 @@
 use crate::decode::{decode_code, decode_layer, decode_metadata};
 use crate::error::BojError;
-use crate::model::{CodeResponse, LayerResponse, MetadataResponse};
-use crate::query::{CodeQuery, LayerQuery, MetadataQuery};
+use crate::model::{Bilingual, CodeResponse, LayerResponse, MetadataResponse};
+use crate::query::{CodeQuery, Language, LayerQuery, MetadataQuery};
 use crate::transport::ReqwestTransport;
 
+use super::bilingual::{merge_code_responses, merge_layer_responses, merge_metadata_responses};
 use super::http::{execute_request, header_value};
+use super::pagination::{append_code_page, append_layer_page, next_start_position};
 use super::response::{ensure_success_status, normalize_response_body};
 
 const DEFAULT_BASE_URL: &str = "https://www.stat-search.boj.or.jp";
@@ -152,4 +154,102 @@ impl BojClient {
 
         Ok(decoded)
     }
+
+    /// Calls `getDataCode` and follows `NEXTPOSITION` until every page is
+    /// fetched, returning one merged [`CodeResponse`].
+    ///
+    /// The merged response keeps the metadata, parameter echo, and `raw` body
+    /// of the first page. Series from later pages are appended, and points of
+    /// a series split across pages are concatenated. `next_position` is
+    /// `None` once all pages are merged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when any page request fails, or when
+    /// `NEXTPOSITION` does not advance between pages.
+    pub fn get_data_code_all(&self, query: &CodeQuery) -> Result<CodeResponse, BojError> {
+        let mut merged = self.get_data_code(query)?;
+        let mut current = None;
+        while let Some(next) = next_start_position(current, merged.next_position)? {
+            let page = self.get_data_code(&query.clone().with_start_position(next)?)?;
+            append_code_page(&mut merged, page);
+            current = Some(next);
+        }
+        Ok(merged)
+    }
+
+    /// Calls `getDataLayer` and follows `NEXTPOSITION` until every page is
+    /// fetched, returning one merged [`LayerResponse`].
+    ///
+    /// See [`BojClient::get_data_code_all`] for merge rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when any page request fails, or when
+    /// `NEXTPOSITION` does not advance between pages.
+    pub fn get_data_layer_all(&self, query: &LayerQuery) -> Result<LayerResponse, BojError> {
+        let mut merged = self.get_data_layer(query)?;
+        let mut current = None;
+        while let Some(next) = next_start_position(current, merged.next_position)? {
+            let page = self.get_data_layer(&query.clone().with_start_position(next)?)?;
+            append_layer_page(&mut merged, page);
+            current = Some(next);
+        }
+        Ok(merged)
+    }
+
+    /// Calls `getDataCode` with `lang=jp` and `lang=en` and merges the two
+    /// responses by series code.
+    ///
+    /// The merged response fills both the Japanese (`*_j`) and English label
+    /// fields. Codes or points that differ between the two responses are
+    /// reported in [`Bilingual::mismatches`]. Any language set on `query` is
+    /// overridden.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when either call fails.
+    pub fn get_data_code_bilingual(
+        &self,
+        query: &CodeQuery,
+    ) -> Result<Bilingual<CodeResponse>, BojError> {
+        let japanese = self.get_data_code(&query.clone().with_lang(Language::Jp))?;
+        let english = self.get_data_code(&query.clone().with_lang(Language::En))?;
+        Ok(merge_code_responses(japanese, english))
+    }
+
+    /// Calls `getDataLayer` with `lang=jp` and `lang=en` and merges the two
+    /// responses by series code.
+    ///
+    /// See [`BojClient::get_data_code_bilingual`] for merge rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when either call fails.
+    pub fn get_data_layer_bilingual(
+        &self,
+        query: &LayerQuery,
+    ) -> Result<Bilingual<LayerResponse>, BojError> {
+        let japanese = self.get_data_layer(&query.clone().with_lang(Language::Jp))?;
+        let english = self.get_data_layer(&query.clone().with_lang(Language::En))?;
+        Ok(merge_layer_responses(japanese, english))
+    }
+
+    /// Calls `getMetadata` with `lang=jp` and `lang=en` and merges the two
+    /// responses by series code.
+    ///
+    /// Hierarchy rows without a series code are joined by their layer path.
+    /// Names, units, categories, and notes are filled in both languages.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when either call fails.
+    pub fn get_metadata_bilingual(
+        &self,
+        query: &MetadataQuery,
+    ) -> Result<Bilingual<MetadataResponse>, BojError> {
+        let japanese = self.get_metadata(&query.clone().with_lang(Language::Jp))?;
+        let english = self.get_metadata(&query.clone().with_lang(Language::En))?;
+        Ok(merge_metadata_responses(japanese, english))
+    }
 }
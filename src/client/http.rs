@@ -19,7 +19,7 @@ pub(super) fn execute_request<T: Transport>(
     })
 }
 
-pub(super) fn build_url(
+pub(crate) fn build_url(
     base_url: &str,
     endpoint: &str,
     query_pairs: &[(String, String)],
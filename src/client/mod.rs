@@ -1,5 +1,8 @@
+mod bilingual;
 mod core;
 mod http;
+mod pagination;
 mod response;
 
 pub use core::BojClient;
+pub(crate) use http::build_url;
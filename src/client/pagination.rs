@@ -0,0 +1,74 @@
+use crate::error::BojError;
+use crate::model::{CodeResponse, CodeSeries, DataPoint, LayerResponse, LayerSeries};
+
+/// A series row that can be joined across pages of one request.
+trait PagedSeries {
+    fn series_code(&self) -> &str;
+    fn points_mut(&mut self) -> &mut Vec<DataPoint>;
+    fn into_points(self) -> Vec<DataPoint>;
+}
+
+impl PagedSeries for CodeSeries {
+    fn series_code(&self) -> &str {
+        &self.series_code
+    }
+
+    fn points_mut(&mut self) -> &mut Vec<DataPoint> {
+        &mut self.points
+    }
+
+    fn into_points(self) -> Vec<DataPoint> {
+        self.points
+    }
+}
+
+impl PagedSeries for LayerSeries {
+    fn series_code(&self) -> &str {
+        &self.series_code
+    }
+
+    fn points_mut(&mut self) -> &mut Vec<DataPoint> {
+        &mut self.points
+    }
+
+    fn into_points(self) -> Vec<DataPoint> {
+        self.points
+    }
+}
+
+/// Returns the start position of the next page, or `None` on the last page.
+pub(super) fn next_start_position(
+    current: Option<u32>,
+    next_position: Option<u32>,
+) -> Result<Option<u32>, BojError> {
+    match next_position {
+        None | Some(0) => Ok(None),
+        Some(next) if current.is_some_and(|current| next <= current) => Err(BojError::decode(
+            format!("NEXTPOSITION {next} does not advance past startPosition"),
+        )),
+        Some(next) => Ok(Some(next)),
+    }
+}
+
+pub(super) fn append_code_page(merged: &mut CodeResponse, page: CodeResponse) {
+    append_series(&mut merged.series, page.series);
+    merged.next_position = page.next_position;
+}
+
+pub(super) fn append_layer_page(merged: &mut LayerResponse, page: LayerResponse) {
+    append_series(&mut merged.series, page.series);
+    merged.next_position = page.next_position;
+}
+
+/// Appends `page` rows, extending the points of series already present.
+fn append_series<T: PagedSeries>(merged: &mut Vec<T>, page: Vec<T>) {
+    for row in page {
+        match merged
+            .iter_mut()
+            .find(|existing| existing.series_code() == row.series_code())
+        {
+            Some(existing) => existing.points_mut().extend(row.into_points()),
+            None => merged.push(row),
+        }
+    }
+}
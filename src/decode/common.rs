@@ -3,7 +3,8 @@ use std::collections::{BTreeMap, HashMap};
 use serde_json::{Map, Value};
 
 use crate::error::BojError;
-use crate::model::{CodeParameterEcho, LayerParameterEcho, ResponseMeta};
+use crate::model::{CodeParameterEcho, LayerParameterEcho, LayerSelector, ResponseMeta};
+use crate::query::parse_layer_value;
 
 pub(crate) fn parse_json_text(bytes: &[u8]) -> Result<String, BojError> {
     std::str::from_utf8(bytes)
@@ -124,6 +125,7 @@ pub(crate) fn parse_layer_parameter_map(
                 | "LANG"
                 | "DB"
                 | "FREQUENCY"
+                | "LAYER"
                 | "LAYER1"
                 | "LAYER2"
                 | "LAYER3"
@@ -140,6 +142,11 @@ pub(crate) fn parse_layer_parameter_map(
         }
     }
 
+    let layer = map.get("LAYER").and_then(|value| normalize_optional(value));
+    let combined = match &layer {
+        Some(value) => parse_combined_layer_selectors(value)?,
+        None => Vec::new(),
+    };
     Ok(LayerParameterEcho {
         format: map
             .get("FORMAT")
@@ -149,11 +156,12 @@ pub(crate) fn parse_layer_parameter_map(
         frequency: map
             .get("FREQUENCY")
             .and_then(|value| normalize_optional(value)),
-        layer1: parse_optional_u32_from_text(map.get("LAYER1").map(String::as_str), "LAYER1")?,
-        layer2: parse_optional_u32_from_text(map.get("LAYER2").map(String::as_str), "LAYER2")?,
-        layer3: parse_optional_u32_from_text(map.get("LAYER3").map(String::as_str), "LAYER3")?,
-        layer4: parse_optional_u32_from_text(map.get("LAYER4").map(String::as_str), "LAYER4")?,
-        layer5: parse_optional_u32_from_text(map.get("LAYER5").map(String::as_str), "LAYER5")?,
+        layer1: parse_layer_level(map, &combined, 1)?,
+        layer2: parse_layer_level(map, &combined, 2)?,
+        layer3: parse_layer_level(map, &combined, 3)?,
+        layer4: parse_layer_level(map, &combined, 4)?,
+        layer5: parse_layer_level(map, &combined, 5)?,
+        layer,
         start_date: map
             .get("STARTDATE")
             .and_then(|value| normalize_optional(value)),
@@ -168,6 +176,46 @@ pub(crate) fn parse_layer_parameter_map(
     })
 }
 
+fn parse_layer_level(
+    map: &BTreeMap<String, String>,
+    combined: &[Option<LayerSelector>],
+    level: usize,
+) -> Result<Option<LayerSelector>, BojError> {
+    let field = format!("LAYER{level}");
+    let selector =
+        parse_optional_layer_selector_from_text(map.get(&field).map(String::as_str), &field)?;
+    Ok(selector.or_else(|| combined.get(level - 1).copied().flatten()))
+}
+
+fn parse_combined_layer_selectors(value: &str) -> Result<Vec<Option<LayerSelector>>, BojError> {
+    value
+        .split(',')
+        .map(|part| parse_optional_layer_selector_from_text(Some(part), "LAYER"))
+        .collect()
+}
+
+fn parse_optional_layer_selector_from_text(
+    value: Option<&str>,
+    field: &str,
+) -> Result<Option<LayerSelector>, BojError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+
+    let Some(value) = normalize_optional(value) else {
+        return Ok(None);
+    };
+    if value.eq_ignore_ascii_case("null") {
+        return Ok(None);
+    }
+
+    parse_layer_value(&value).map(Some).map_err(|_| {
+        BojError::decode(format!(
+            "{field} must be '*' or a positive integer, got '{value}'"
+        ))
+    })
+}
+
 pub(crate) fn parse_meta_from_csv_map(
     meta: &HashMap<String, String>,
 ) -> Result<ResponseMeta, BojError> {
@@ -191,7 +191,10 @@ pub(crate) fn decode_metadata(
 
 #[cfg(test)]
 mod tests {
-    use super::common::parse_next_position_from_text;
+    use std::collections::BTreeMap;
+
+    use super::common::{parse_layer_parameter_map, parse_next_position_from_text};
+    use crate::model::LayerSelector;
 
     #[test]
     fn parse_next_position_handles_blank_and_null() {
@@ -202,4 +205,40 @@ mod tests {
             Some(250)
         );
     }
+
+    #[test]
+    fn layer_echo_falls_back_to_combined_layer_parameter() {
+        let map = BTreeMap::from([
+            ("LAYER".to_string(), "2, *,3".to_string()),
+            ("LAYER3".to_string(), "4".to_string()),
+        ]);
+
+        let echo = parse_layer_parameter_map(&map).unwrap();
+        assert_eq!(echo.layer.as_deref(), Some("2, *,3"));
+        assert_eq!(echo.layer1, Some(LayerSelector::Index(2)));
+        assert_eq!(echo.layer2, Some(LayerSelector::Wildcard));
+        assert_eq!(echo.layer3, Some(LayerSelector::Index(4)));
+        assert_eq!(echo.layer4, None);
+        assert!(echo.extras.is_empty());
+
+        let invalid = BTreeMap::from([("LAYER2".to_string(), "x".to_string())]);
+        assert!(parse_layer_parameter_map(&invalid).is_err());
+        let zero = BTreeMap::from([("LAYER1".to_string(), "0".to_string())]);
+        assert!(parse_layer_parameter_map(&zero).is_err());
+    }
+
+    #[test]
+    fn layer_selector_deserializes_like_layer_query_values() {
+        let selectors: Vec<LayerSelector> = serde_json::from_str(r#"[2, "*", " 3 "]"#).unwrap();
+        assert_eq!(
+            selectors,
+            [
+                LayerSelector::Index(2),
+                LayerSelector::Wildcard,
+                LayerSelector::Index(3)
+            ]
+        );
+        assert!(serde_json::from_str::<LayerSelector>("0").is_err());
+        assert!(serde_json::from_str::<LayerSelector>(r#""0""#).is_err());
+    }
 }
@@ -0,0 +1,5 @@
+mod store;
+
+pub use duckdb::types::Value;
+pub use duckdb::{Connection, Error};
+pub use store::{DuckDbStore, SqlRows};
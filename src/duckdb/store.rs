@@ -0,0 +1,330 @@
+use std::path::Path;
+
+use duckdb::types::Value;
+use duckdb::{Connection, Error, Statement, Transaction, params};
+
+use crate::catalog;
+use crate::model::{CodeResponse, LayerResponse, MetadataResponse, ResponseMeta, TimeSeries};
+use crate::period::{iso_date, period_span};
+use crate::query::Frequency;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS databases (
+    db VARCHAR PRIMARY KEY,
+    name_ja VARCHAR NOT NULL,
+    category_ja VARCHAR NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS series (
+    db VARCHAR NOT NULL,
+    series_code VARCHAR NOT NULL,
+    name_of_time_series_j VARCHAR,
+    name_of_time_series VARCHAR,
+    unit_j VARCHAR,
+    unit VARCHAR,
+    frequency VARCHAR,
+    category_j VARCHAR,
+    category VARCHAR,
+    layer1 UINTEGER,
+    layer2 UINTEGER,
+    layer3 UINTEGER,
+    layer4 UINTEGER,
+    layer5 UINTEGER,
+    start_of_the_time_series VARCHAR,
+    end_of_the_time_series VARCHAR,
+    last_update VARCHAR,
+    notes_j VARCHAR,
+    notes VARCHAR,
+    PRIMARY KEY (db, series_code)
+);
+
+CREATE TABLE IF NOT EXISTS observations (
+    db VARCHAR NOT NULL,
+    series_code VARCHAR NOT NULL,
+    period VARCHAR NOT NULL,
+    period_start DATE,
+    period_end DATE,
+    value DOUBLE,
+    retrieved_at VARCHAR,
+    PRIMARY KEY (db, series_code, period)
+);
+";
+
+/// Result of [`DuckDbStore::query`]: column names and rows of values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SqlRows {
+    /// Column names in result order.
+    pub columns: Vec<String>,
+    /// Rows, each holding one [`Value`] per column.
+    pub rows: Vec<Vec<Value>>,
+}
+
+/// DuckDB database of BOJ series, metadata and DB catalog for ad-hoc SQL.
+///
+/// Opening a store creates three tables when missing:
+///
+/// | Table | Key | Columns |
+/// | --- | --- | --- |
+/// | `databases` | `db` | `name_ja`, `category_ja` from [`catalog::databases`] |
+/// | `series` | `db`, `series_code` | [`MetadataEntry`](crate::model::MetadataEntry) field names; `layer1`..`layer5` are `UINTEGER`, the rest `VARCHAR` |
+/// | `observations` | `db`, `series_code`, `period` | `period_start` / `period_end` (`DATE`, null when unparseable), `value` (`DOUBLE`), `retrieved_at` (response `DATE`) |
+///
+/// Every load upserts by key, so reloading a response replaces its rows
+/// instead of duplicating them. Labels missing from a data response keep the
+/// values loaded from `getMetadata`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::duckdb::DuckDbStore;
+/// use boj_client::query::{CodeQuery, MetadataQuery};
+///
+/// let client = BojClient::new()?;
+/// let store = DuckDbStore::open("boj.duckdb")?;
+///
+/// let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])?;
+/// store.load_code_response(&client.get_data_code_all(&query)?)?;
+/// store.load_metadata(&client.get_metadata(&MetadataQuery::new("FM08")?)?)?;
+///
+/// let monthly = store.query(
+///     "SELECT date_trunc('month', period_start) AS month, avg(value) \
+///      FROM observations WHERE series_code = 'FXERD01' GROUP BY ALL ORDER BY month",
+/// )?;
+/// println!("{:?}", monthly.rows);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct DuckDbStore {
+    connection: Connection,
+}
+
+impl DuckDbStore {
+    /// Opens the DuckDB database at `path`, creating it when missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when the database cannot be opened or initialized.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Opens a database held in memory, discarded when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when DuckDB cannot create the database.
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    /// Uses an existing connection, creating the tables when missing and
+    /// refreshing `databases` from the catalog.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when initialization fails.
+    pub fn from_connection(mut connection: Connection) -> Result<Self, Error> {
+        connection.execute_batch(SCHEMA)?;
+        let transaction = connection.transaction()?;
+        {
+            let mut statement =
+                transaction.prepare("INSERT OR REPLACE INTO databases VALUES (?, ?, ?)")?;
+            for entry in catalog::databases() {
+                statement.execute(params![entry.code, entry.name_ja, entry.category_ja])?;
+            }
+        }
+        transaction.commit()?;
+        Ok(Self { connection })
+    }
+
+    /// Returns the underlying connection for queries [`query`](Self::query)
+    /// does not cover, such as parameters or `COPY`.
+    pub fn connection(&self) -> &Connection {
+        &self.connection
+    }
+
+    /// Upserts the series and observations of a `getDataCode` response and
+    /// returns how many observations were written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when the response does not echo its DB, a value is
+    /// not a number, or writing fails.
+    pub fn load_code_response(&self, response: &CodeResponse) -> Result<usize, Error> {
+        self.load_series(
+            response.parameter.db.as_deref(),
+            &response.meta,
+            &response.series,
+        )
+    }
+
+    /// Upserts the series and observations of a `getDataLayer` response and
+    /// returns how many observations were written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when the response does not echo its DB, a value is
+    /// not a number, or writing fails.
+    pub fn load_layer_response(&self, response: &LayerResponse) -> Result<usize, Error> {
+        self.load_series(
+            response.parameter.db.as_deref(),
+            &response.meta,
+            &response.series,
+        )
+    }
+
+    /// Upserts the series rows of a `getMetadata` response and returns how
+    /// many were written. Layer heading rows without a series code are
+    /// skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when writing fails.
+    pub fn load_metadata(&self, response: &MetadataResponse) -> Result<usize, Error> {
+        let transaction = self.connection.unchecked_transaction()?;
+        let written = {
+            let mut statement = transaction.prepare(
+                "INSERT OR REPLACE INTO series VALUES \
+                 (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )?;
+            let db = response.db.to_ascii_uppercase();
+            let mut written = 0;
+            for entry in &response.entries {
+                let Some(code) = entry.series_code.as_deref().filter(|code| !code.is_empty())
+                else {
+                    continue;
+                };
+                statement.execute(params![
+                    db,
+                    code,
+                    entry.name_of_time_series_j,
+                    entry.name_of_time_series,
+                    entry.unit_j,
+                    entry.unit,
+                    entry.frequency,
+                    entry.category_j,
+                    entry.category,
+                    entry.layer1,
+                    entry.layer2,
+                    entry.layer3,
+                    entry.layer4,
+                    entry.layer5,
+                    entry.start_of_the_time_series,
+                    entry.end_of_the_time_series,
+                    entry.last_update,
+                    entry.notes_j,
+                    entry.notes,
+                ])?;
+                written += 1;
+            }
+            written
+        };
+        transaction.commit()?;
+        Ok(written)
+    }
+
+    /// Runs `sql` and returns every result row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error`] when the statement fails.
+    pub fn query(&self, sql: &str) -> Result<SqlRows, Error> {
+        let mut statement = self.connection.prepare(sql)?;
+        let mut rows = statement.query([])?;
+        let columns = rows
+            .as_ref()
+            .map(Statement::column_names)
+            .unwrap_or_default();
+        let mut values = Vec::new();
+        while let Some(row) = rows.next()? {
+            values.push(
+                (0..columns.len())
+                    .map(|index| row.get::<_, Value>(index))
+                    .collect::<Result<Vec<_>, _>>()?,
+            );
+        }
+        Ok(SqlRows {
+            columns,
+            rows: values,
+        })
+    }
+
+    fn load_series<T: TimeSeries>(
+        &self,
+        db: Option<&str>,
+        meta: &ResponseMeta,
+        series: &[T],
+    ) -> Result<usize, Error> {
+        let db = db
+            .filter(|db| !db.is_empty())
+            .ok_or_else(|| conversion_error("response does not echo a DB code"))?
+            .to_ascii_uppercase();
+        let transaction = self.connection.unchecked_transaction()?;
+        let written = write_series(&transaction, &db, meta.date.as_deref(), series)?;
+        transaction.commit()?;
+        Ok(written)
+    }
+}
+
+fn write_series<T: TimeSeries>(
+    transaction: &Transaction<'_>,
+    db: &str,
+    retrieved_at: Option<&str>,
+    series: &[T],
+) -> Result<usize, Error> {
+    let mut labels = transaction.prepare(
+        "INSERT INTO series (db, series_code, name_of_time_series_j, name_of_time_series, \
+         unit_j, unit, frequency, category_j, category, last_update) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+         ON CONFLICT (db, series_code) DO UPDATE SET \
+         name_of_time_series_j = coalesce(excluded.name_of_time_series_j, name_of_time_series_j), \
+         name_of_time_series = coalesce(excluded.name_of_time_series, name_of_time_series), \
+         unit_j = coalesce(excluded.unit_j, unit_j), unit = coalesce(excluded.unit, unit), \
+         frequency = coalesce(excluded.frequency, frequency), \
+         category_j = coalesce(excluded.category_j, category_j), \
+         category = coalesce(excluded.category, category), \
+         last_update = coalesce(excluded.last_update, last_update)",
+    )?;
+    let mut observations = transaction.prepare(
+        "INSERT OR REPLACE INTO observations VALUES \
+         (?, ?, ?, CAST(? AS DATE), CAST(? AS DATE), ?, ?)",
+    )?;
+    let mut written = 0;
+    for series in series {
+        labels.execute(params![
+            db,
+            series.series_code(),
+            series.name_j(),
+            series.name(),
+            series.unit_j(),
+            series.unit(),
+            series.frequency(),
+            series.category_j(),
+            series.category(),
+            series.last_update(),
+        ])?;
+        let frequency = series.frequency().and_then(Frequency::from_label);
+        for point in series.points() {
+            let value = point.numeric_value().map_err(|error| {
+                conversion_error(format!("series {}: {error}", series.series_code()))
+            })?;
+            let span = period_span(&point.survey_date, frequency);
+            observations.execute(params![
+                db,
+                series.series_code(),
+                point.survey_date,
+                span.map(|span| iso_date(span.first)),
+                span.map(|span| iso_date(span.last)),
+                value,
+                retrieved_at,
+            ])?;
+            written += 1;
+        }
+    }
+    Ok(written)
+}
+
+fn conversion_error(message: impl Into<String>) -> Error {
+    Error::ToSqlConversionFailure(message.into().into())
+}
@@ -0,0 +1,287 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value, json};
+
+use crate::catalog;
+use crate::model::{MetadataEntry, MetadataResponse, TimeSeries};
+use crate::query::{Frequency, Language};
+
+use super::error::ExportError;
+use super::options::{DataPackageOptions, ExportFormat, ExportOptions, Layout};
+use super::writer::write_series;
+
+/// Directory of the BOJ API documents cited as source and terms.
+const BOJ_INFO_URL: &str = "https://www.stat-search.boj.or.jp/info";
+
+/// BOJ document stating the conditions for using API data.
+const TERMS_DOCUMENT: &str = "api_notice.pdf";
+
+/// Writes `series` to `directory` as a Frictionless Tabular Data Package and
+/// returns the path of its `datapackage.json`.
+///
+/// Series are grouped by frequency into one wide CSV resource each, named
+/// `<db>-<frequency>` (`fm08-d.csv`) in order of first appearance; series
+/// without a recognized frequency go to `<db>-unknown.csv`. Each CSV has a
+/// `period` column and one column per series code, as written by
+/// [`write_series`] with [`Layout::Wide`], and empty cells for missing values.
+///
+/// The field schema comes from the `metadata` entry of each series code:
+/// the series name becomes the field title, and the category, coverage,
+/// last update and notes its description, in the language selected by
+/// [`DataPackageOptions::with_labels`]. Series absent from `metadata` fall
+/// back to their own labels. The package cites the API manual recorded in
+/// [`catalog::snapshot`] as its source and links the BOJ terms of use
+/// (`api_notice.pdf`).
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::export::{DataPackageOptions, write_data_package};
+/// use boj_client::query::{CodeQuery, MetadataQuery};
+///
+/// let client = BojClient::new()?;
+/// let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])?;
+/// let response = client.get_data_code_all(&query)?;
+/// let metadata = client.get_metadata(&MetadataQuery::new("FM08")?)?;
+///
+/// let options = DataPackageOptions::new().with_title("USD/JPY spot rates");
+/// let path = write_data_package("fm08-package", &response.series, &metadata, &options)?;
+/// println!("wrote {}", path.display());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ExportError`] when the package name is not a valid Frictionless
+/// name, a value is not a number, or writing fails.
+pub fn write_data_package<'a, T: TimeSeries + 'a>(
+    directory: impl AsRef<Path>,
+    series: impl IntoIterator<Item = &'a T>,
+    metadata: &MetadataResponse,
+    options: &DataPackageOptions,
+) -> Result<PathBuf, ExportError> {
+    let db = metadata.db.to_ascii_lowercase();
+    let name = options
+        .name()
+        .map_or_else(|| format!("boj-{db}"), str::to_string);
+    if !is_package_name(&name) {
+        return Err(ExportError::InvalidName { name });
+    }
+
+    let entries = metadata
+        .entries
+        .iter()
+        .filter_map(|entry| Some((entry.series_code.as_deref()?, entry)))
+        .collect::<BTreeMap<_, _>>();
+    let mut groups: Vec<(String, Vec<&T>)> = Vec::new();
+    for series in series {
+        let entry = entries.get(series.series_code()).copied();
+        let frequency = series
+            .frequency()
+            .or_else(|| entry.and_then(|entry| entry.frequency.as_deref()))
+            .and_then(Frequency::from_label)
+            .map_or("unknown", Frequency::as_query_value)
+            .to_ascii_lowercase();
+        match groups.iter_mut().find(|(key, _)| *key == frequency) {
+            Some((_, members)) => members.push(series),
+            None => groups.push((frequency, vec![series])),
+        }
+    }
+
+    let directory = directory.as_ref();
+    fs::create_dir_all(directory)?;
+    let csv = ExportOptions::new(ExportFormat::Csv).with_layout(Layout::Wide);
+    let language = options.language();
+    let mut resources = Vec::with_capacity(groups.len());
+    for (frequency, members) in &groups {
+        let resource_name = format!("{db}-{frequency}");
+        let path = format!("{resource_name}.csv");
+        write_series(
+            File::create(directory.join(&path))?,
+            members.iter().copied(),
+            &csv,
+        )?;
+
+        let mut fields = vec![period_field(language)];
+        fields.extend(members.iter().map(|series| {
+            series_field(
+                *series,
+                entries.get(series.series_code()).copied(),
+                language,
+            )
+        }));
+        resources.push(json!({
+            "name": resource_name,
+            "path": path,
+            "profile": "tabular-data-resource",
+            "format": "csv",
+            "mediatype": "text/csv",
+            "encoding": "utf-8",
+            "dialect": { "delimiter": ",", "lineTerminator": "\r\n", "header": true },
+            "schema": {
+                "fields": fields,
+                "missingValues": [""],
+                "primaryKey": ["period"],
+            },
+        }));
+    }
+
+    let snapshot = catalog::snapshot();
+    let mut package = Map::new();
+    package.insert("profile".into(), "tabular-data-package".into());
+    package.insert("name".into(), name.into());
+    if let Some(title) = options.title() {
+        package.insert("title".into(), title.into());
+    }
+    if let Some(created) = metadata.meta.date.as_deref() {
+        package.insert("created".into(), created.into());
+    }
+    package.insert(
+        "sources".into(),
+        json!([{
+            "title": format!(
+                "日本銀行 時系列統計データ検索サイト API機能 ({}, {})",
+                snapshot.source_document, snapshot.source_date
+            ),
+            "path": format!("{BOJ_INFO_URL}/{}", snapshot.source_document),
+        }]),
+    );
+    package.insert(
+        "licenses".into(),
+        json!([{
+            "title": "API機能利用時の留意点",
+            "path": format!("{BOJ_INFO_URL}/{TERMS_DOCUMENT}"),
+        }]),
+    );
+    package.insert("resources".into(), resources.into());
+
+    let path = directory.join("datapackage.json");
+    let text = serde_json::to_string_pretty(&Value::Object(package))
+        .map_err(|error| ExportError::Io(error.into()))?;
+    fs::write(&path, text + "\n")?;
+    Ok(path)
+}
+
+/// Returns `true` for Frictionless package names: lowercase alphanumerics,
+/// `.`, `_` and `-`.
+fn is_package_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.bytes().all(|byte| {
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'.' | b'_' | b'-')
+        })
+}
+
+fn period_field(language: Language) -> Value {
+    let (title, description) = match language {
+        Language::Jp => ("時点", "BOJ の時点表記（YYYY、YYYYMM、YYYYMMDD など）"),
+        Language::En => (
+            "Period",
+            "Survey date as reported by BOJ (YYYY, YYYYMM, YYYYMMDD, ...)",
+        ),
+    };
+    json!({ "name": "period", "type": "string", "title": title, "description": description })
+}
+
+fn series_field<T: TimeSeries>(
+    series: &T,
+    entry: Option<&MetadataEntry>,
+    language: Language,
+) -> Value {
+    let labels = SeriesLabels::new(series, entry, language);
+    let mut field = Map::new();
+    field.insert("name".into(), series.series_code().into());
+    field.insert("type".into(), "number".into());
+    if let Some(title) = labels.name {
+        field.insert("title".into(), title.into());
+    }
+    let description = labels.description(language);
+    if !description.is_empty() {
+        field.insert("description".into(), description.into());
+    }
+    if let Some(unit) = labels.unit {
+        field.insert("unit".into(), unit.into());
+    }
+    Value::Object(field)
+}
+
+/// Labels of one series in the selected language, preferring non-empty
+/// metadata entry fields and falling back to the other language.
+struct SeriesLabels<'a> {
+    name: Option<&'a str>,
+    unit: Option<&'a str>,
+    category: Option<&'a str>,
+    notes: Option<&'a str>,
+    start: Option<&'a str>,
+    end: Option<&'a str>,
+    last_update: Option<&'a str>,
+}
+
+impl<'a> SeriesLabels<'a> {
+    fn new<T: TimeSeries>(
+        series: &'a T,
+        entry: Option<&'a MetadataEntry>,
+        language: Language,
+    ) -> Self {
+        let pick = |jp: Option<&'a str>, en: Option<&'a str>| {
+            let (first, second) = match language {
+                Language::Jp => (jp, en),
+                Language::En => (en, jp),
+            };
+            first
+                .filter(|text| !text.is_empty())
+                .or(second.filter(|text| !text.is_empty()))
+        };
+        let field = |select: fn(&MetadataEntry) -> &Option<String>| {
+            entry
+                .and_then(|entry| select(entry).as_deref())
+                .filter(|text| !text.is_empty())
+        };
+        Self {
+            name: pick(
+                field(|entry| &entry.name_of_time_series_j).or(series.name_j()),
+                field(|entry| &entry.name_of_time_series).or(series.name()),
+            ),
+            unit: pick(
+                field(|entry| &entry.unit_j).or(series.unit_j()),
+                field(|entry| &entry.unit).or(series.unit()),
+            ),
+            category: pick(
+                field(|entry| &entry.category_j).or(series.category_j()),
+                field(|entry| &entry.category).or(series.category()),
+            ),
+            notes: pick(field(|entry| &entry.notes_j), field(|entry| &entry.notes)),
+            start: field(|entry| &entry.start_of_the_time_series),
+            end: field(|entry| &entry.end_of_the_time_series),
+            last_update: field(|entry| &entry.last_update).or(series.last_update()),
+        }
+    }
+
+    /// Joins category, coverage, last update and notes, one per line.
+    fn description(&self, language: Language) -> String {
+        let (category, coverage, last_update, notes) = match language {
+            Language::Jp => ("分類", "収録期間", "最終更新", "注記"),
+            Language::En => ("Category", "Coverage", "Last update", "Notes"),
+        };
+        let coverage_text = match (self.start, self.end) {
+            (None, None) => None,
+            (start, end) => Some(format!(
+                "{}–{}",
+                start.unwrap_or_default(),
+                end.unwrap_or_default()
+            )),
+        };
+        [
+            (category, self.category.map(str::to_string)),
+            (coverage, coverage_text),
+            (last_update, self.last_update.map(str::to_string)),
+            (notes, self.notes.map(str::to_string)),
+        ]
+        .into_iter()
+        .filter_map(|(label, text)| Some(format!("{label}: {}", text?)))
+        .collect::<Vec<_>>()
+        .join("\n")
+    }
+}
@@ -0,0 +1,37 @@
+use std::io;
+
+use thiserror::Error;
+
+/// Error returned by [`write_series`](super::write_series) and
+/// [`write_data_package`](super::write_data_package).
+#[derive(Debug, Error)]
+pub enum ExportError {
+    /// Writing to the destination failed.
+    #[error("export I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// A series value is present but not a number.
+    #[error("invalid value in series {series_code}: {message}")]
+    InvalidValue {
+        /// Code of the series holding the value.
+        series_code: String,
+        /// Description of the rejected value.
+        message: String,
+    },
+
+    /// The output contains a character the selected encoding cannot represent.
+    #[error("character {character:?} cannot be encoded as {encoding}")]
+    Unencodable {
+        /// First character that could not be encoded.
+        character: char,
+        /// Name of the selected encoding.
+        encoding: &'static str,
+    },
+
+    /// The data package name is not a valid Frictionless name.
+    #[error("invalid data package name {name:?}: use lowercase alphanumerics, '.', '_' or '-'")]
+    InvalidName {
+        /// Rejected name.
+        name: String,
+    },
+}
@@ -0,0 +1,9 @@
+mod datapackage;
+mod error;
+mod options;
+mod writer;
+
+pub use datapackage::write_data_package;
+pub use error::ExportError;
+pub use options::{DataPackageOptions, Encoding, ExportFormat, ExportOptions, Layout};
+pub use writer::write_series;
@@ -0,0 +1,195 @@
+use crate::query::Language;
+
+/// Output file format of [`write_series`](super::write_series).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Comma-separated values with CRLF line endings.
+    Csv,
+    /// Tab-separated values with CRLF line endings.
+    Tsv,
+    /// One JSON object per line with LF line endings.
+    JsonLines,
+}
+
+/// Table shape of exported series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// One row per observation with `series_code`, `period` and `value`.
+    #[default]
+    Long,
+    /// One row per period with one column per series code.
+    Wide,
+}
+
+/// Character encoding of exported text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encoding {
+    /// UTF-8 without byte order mark.
+    #[default]
+    Utf8,
+    /// UTF-8 prefixed with a byte order mark, which Excel needs to detect
+    /// UTF-8 CSV files.
+    Utf8Bom,
+    /// Shift_JIS, the default text encoding of Japanese Excel.
+    ShiftJis,
+}
+
+impl Encoding {
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::Utf8 => "UTF-8",
+            Self::Utf8Bom => "UTF-8 with BOM",
+            Self::ShiftJis => "Shift_JIS",
+        }
+    }
+}
+
+/// Options for [`write_series`](super::write_series).
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::export::{Encoding, ExportFormat, ExportOptions, Layout};
+/// use boj_client::query::Language;
+///
+/// let options = ExportOptions::new(ExportFormat::Csv)
+///     .with_layout(Layout::Wide)
+///     .with_encoding(Encoding::ShiftJis)
+///     .with_labels(Language::Jp);
+/// assert_eq!(options.layout(), Layout::Wide);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExportOptions {
+    format: ExportFormat,
+    layout: Layout,
+    encoding: Encoding,
+    labels: Option<Language>,
+}
+
+impl ExportOptions {
+    /// Creates options for `format` with the long layout, UTF-8 and no labels.
+    pub fn new(format: ExportFormat) -> Self {
+        Self {
+            format,
+            layout: Layout::default(),
+            encoding: Encoding::default(),
+            labels: None,
+        }
+    }
+
+    /// Sets the table layout.
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Sets the output encoding.
+    pub fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Includes series names and units in `language`.
+    ///
+    /// Labels missing in `language` fall back to the other language. The wide
+    /// CSV/TSV layout adds `name` and `unit` header rows below the series
+    /// codes; the long layout adds `name` and `unit` columns. Wide JSON Lines
+    /// rows are keyed by series code and carry no labels.
+    pub fn with_labels(mut self, language: Language) -> Self {
+        self.labels = Some(language);
+        self
+    }
+
+    /// Returns the output format.
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    /// Returns the table layout.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Returns the output encoding.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Returns the label language, if labels are included.
+    pub fn labels(&self) -> Option<Language> {
+        self.labels
+    }
+}
+
+/// Options for [`write_data_package`](super::write_data_package).
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::export::DataPackageOptions;
+/// use boj_client::query::Language;
+///
+/// let options = DataPackageOptions::new()
+///     .with_name("fx-daily")
+///     .with_labels(Language::En);
+/// assert_eq!(options.name(), Some("fx-daily"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataPackageOptions {
+    name: Option<String>,
+    title: Option<String>,
+    language: Language,
+}
+
+impl DataPackageOptions {
+    /// Creates options naming the package `boj-<db>` with Japanese labels.
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            title: None,
+            language: Language::Jp,
+        }
+    }
+
+    /// Sets the package `name`, which Frictionless restricts to lowercase
+    /// alphanumerics, `.`, `_` and `-`.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the package `title`.
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Writes field titles, units and descriptions in `language`.
+    ///
+    /// Labels missing in `language` fall back to the other language.
+    pub fn with_labels(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Returns the package name, if set.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the package title, if set.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Returns the label language.
+    pub fn language(&self) -> Language {
+        self.language
+    }
+}
+
+impl Default for DataPackageOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
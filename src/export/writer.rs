@@ -0,0 +1,302 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{self, Write};
+
+use encoding_rs::SHIFT_JIS;
+use serde::Serialize;
+
+use crate::model::{DataPoint, TimeSeries};
+use crate::query::Language;
+
+use super::error::ExportError;
+use super::options::{Encoding, ExportFormat, ExportOptions, Layout};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Writes `series` to `writer` in the format, layout and encoding selected by
+/// `options`.
+///
+/// `series` can be any collection of [`TimeSeries`], such as
+/// `&response.series` of a code or layer response. Layouts:
+///
+/// - long: header `series_code,period,value`, one row per observation in
+///   series order;
+/// - wide: header `period,<code>,<code>,...`, one row per period found in any
+///   series, sorted ascending.
+///
+/// Values are written as BOJ reported them; missing values are empty cells
+/// in CSV/TSV and `null` in JSON Lines, where values are JSON numbers.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::export::{ExportFormat, ExportOptions, Layout, write_series};
+/// use boj_client::model::{CodeSeries, DataPoint};
+///
+/// let series = CodeSeries {
+///     series_code: "FXERD01".to_string(),
+///     name_of_time_series_j: None,
+///     name_of_time_series: None,
+///     unit_j: None,
+///     unit: None,
+///     frequency: None,
+///     category_j: None,
+///     category: None,
+///     last_update: None,
+///     points: vec![DataPoint {
+///         survey_date: "20240104".to_string(),
+///         value: Some("143.15".to_string()),
+///     }],
+///     extras: Default::default(),
+/// };
+///
+/// let mut output = Vec::new();
+/// let options = ExportOptions::new(ExportFormat::Csv).with_layout(Layout::Wide);
+/// write_series(&mut output, [&series], &options)?;
+/// assert_eq!(output, b"period,FXERD01\r\n20240104,143.15\r\n");
+/// # Ok::<(), boj_client::export::ExportError>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ExportError`] when a value is not a number, the text cannot be
+/// represented in the selected encoding, or writing fails. Nothing is written
+/// when conversion fails.
+pub fn write_series<'a, T, W>(
+    mut writer: W,
+    series: impl IntoIterator<Item = &'a T>,
+    options: &ExportOptions,
+) -> Result<(), ExportError>
+where
+    T: TimeSeries + 'a,
+    W: Write,
+{
+    let series = series.into_iter().collect::<Vec<_>>();
+    let labels = options.labels();
+    let text = match (options.format(), options.layout()) {
+        (ExportFormat::Csv, Layout::Long) => delimited_long(&series, b',', labels)?,
+        (ExportFormat::Csv, Layout::Wide) => delimited_wide(&series, b',', labels)?,
+        (ExportFormat::Tsv, Layout::Long) => delimited_long(&series, b'\t', labels)?,
+        (ExportFormat::Tsv, Layout::Wide) => delimited_wide(&series, b'\t', labels)?,
+        (ExportFormat::JsonLines, Layout::Long) => json_lines_long(&series, labels)?,
+        (ExportFormat::JsonLines, Layout::Wide) => json_lines_wide(&series)?,
+    };
+
+    let bytes = encode(&text, options.encoding())?;
+    writer.write_all(&bytes)?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn delimited_long<T: TimeSeries>(
+    series: &[&T],
+    delimiter: u8,
+    labels: Option<Language>,
+) -> Result<String, ExportError> {
+    let mut csv = delimited_writer(delimiter);
+    let mut header = vec!["series_code", "period", "value"];
+    if labels.is_some() {
+        header.extend(["name", "unit"]);
+    }
+    csv.write_record(header).map_err(io::Error::from)?;
+
+    for series in series {
+        let labels = labels.map(|language| series_labels(*series, language));
+        for point in series.points() {
+            let mut record = vec![
+                series.series_code(),
+                point.survey_date.as_str(),
+                value_text(*series, point)?.unwrap_or_default(),
+            ];
+            if let Some((name, unit)) = labels {
+                record.extend([name.unwrap_or_default(), unit.unwrap_or_default()]);
+            }
+            csv.write_record(record).map_err(io::Error::from)?;
+        }
+    }
+    delimited_text(csv)
+}
+
+fn delimited_wide<T: TimeSeries>(
+    series: &[&T],
+    delimiter: u8,
+    labels: Option<Language>,
+) -> Result<String, ExportError> {
+    let mut csv = delimited_writer(delimiter);
+    let header = std::iter::once("period").chain(series.iter().map(|series| series.series_code()));
+    csv.write_record(header).map_err(io::Error::from)?;
+    if let Some(language) = labels {
+        let labels = series
+            .iter()
+            .map(|series| series_labels(*series, language))
+            .collect::<Vec<_>>();
+        let names = labels.iter().map(|(name, _)| name.unwrap_or_default());
+        csv.write_record(std::iter::once("name").chain(names))
+            .map_err(io::Error::from)?;
+        let units = labels.iter().map(|(_, unit)| unit.unwrap_or_default());
+        csv.write_record(std::iter::once("unit").chain(units))
+            .map_err(io::Error::from)?;
+    }
+
+    let (periods, columns) = pivot(series);
+    for period in periods {
+        let mut record = vec![period];
+        for (series, points) in series.iter().zip(&columns) {
+            let value = match points.get(period) {
+                Some(point) => value_text(*series, point)?,
+                None => None,
+            };
+            record.push(value.unwrap_or_default());
+        }
+        csv.write_record(record).map_err(io::Error::from)?;
+    }
+    delimited_text(csv)
+}
+
+#[derive(Serialize)]
+struct LongRow<'a> {
+    series_code: &'a str,
+    period: &'a str,
+    value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<Option<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unit: Option<Option<&'a str>>,
+}
+
+fn json_lines_long<T: TimeSeries>(
+    series: &[&T],
+    labels: Option<Language>,
+) -> Result<String, ExportError> {
+    let mut text = String::new();
+    for series in series {
+        let labels = labels.map(|language| series_labels(*series, language));
+        for point in series.points() {
+            let row = LongRow {
+                series_code: series.series_code(),
+                period: &point.survey_date,
+                value: numeric_value(*series, point)?,
+                name: labels.map(|(name, _)| name),
+                unit: labels.map(|(_, unit)| unit),
+            };
+            text.push_str(&to_json(&row)?);
+            text.push('\n');
+        }
+    }
+    Ok(text)
+}
+
+fn json_lines_wide<T: TimeSeries>(series: &[&T]) -> Result<String, ExportError> {
+    let mut text = String::new();
+    let (periods, columns) = pivot(series);
+    for period in periods {
+        text.push_str("{\"period\":");
+        text.push_str(&to_json(&period)?);
+        for (series, points) in series.iter().zip(&columns) {
+            let value = match points.get(period) {
+                Some(point) => numeric_value(*series, point)?,
+                None => None,
+            };
+            text.push(',');
+            text.push_str(&to_json(&series.series_code())?);
+            text.push(':');
+            text.push_str(&to_json(&value)?);
+        }
+        text.push_str("}\n");
+    }
+    Ok(text)
+}
+
+/// Returns the sorted periods of all series and, per series, its points by
+/// period.
+fn pivot<'a, T: TimeSeries>(
+    series: &[&'a T],
+) -> (BTreeSet<&'a str>, Vec<BTreeMap<&'a str, &'a DataPoint>>) {
+    let columns = series
+        .iter()
+        .map(|series| {
+            series
+                .points()
+                .iter()
+                .map(|point| (point.survey_date.as_str(), point))
+                .collect::<BTreeMap<_, _>>()
+        })
+        .collect::<Vec<_>>();
+    let periods = columns
+        .iter()
+        .flat_map(|points| points.keys().copied())
+        .collect();
+    (periods, columns)
+}
+
+fn series_labels<T: TimeSeries>(series: &T, language: Language) -> (Option<&str>, Option<&str>) {
+    match language {
+        Language::Jp => (
+            series.name_j().or(series.name()),
+            series.unit_j().or(series.unit()),
+        ),
+        Language::En => (
+            series.name().or(series.name_j()),
+            series.unit().or(series.unit_j()),
+        ),
+    }
+}
+
+/// Returns the value text as reported, after checking that it is a number.
+fn value_text<'a, T: TimeSeries>(
+    series: &T,
+    point: &'a DataPoint,
+) -> Result<Option<&'a str>, ExportError> {
+    numeric_value(series, point)?;
+    Ok(point.value.as_deref().map(str::trim))
+}
+
+fn numeric_value<T: TimeSeries>(series: &T, point: &DataPoint) -> Result<Option<f64>, ExportError> {
+    point
+        .numeric_value()
+        .map_err(|error| ExportError::InvalidValue {
+            series_code: series.series_code().to_string(),
+            message: error.to_string(),
+        })
+}
+
+fn to_json<S: Serialize + ?Sized>(value: &S) -> Result<String, ExportError> {
+    serde_json::to_string(value).map_err(|error| ExportError::Io(error.into()))
+}
+
+fn delimited_writer(delimiter: u8) -> csv::Writer<Vec<u8>> {
+    csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .terminator(csv::Terminator::CRLF)
+        .from_writer(Vec::new())
+}
+
+fn delimited_text(csv: csv::Writer<Vec<u8>>) -> Result<String, ExportError> {
+    let bytes = csv
+        .into_inner()
+        .map_err(|error| ExportError::Io(error.into_error()))?;
+    String::from_utf8(bytes).map_err(|error| ExportError::Io(io::Error::other(error)))
+}
+
+fn encode(text: &str, encoding: Encoding) -> Result<Vec<u8>, ExportError> {
+    match encoding {
+        Encoding::Utf8 => Ok(text.as_bytes().to_vec()),
+        Encoding::Utf8Bom => Ok([UTF8_BOM, text.as_bytes()].concat()),
+        Encoding::ShiftJis => {
+            let (bytes, _, had_errors) = SHIFT_JIS.encode(text);
+            if had_errors {
+                let character = text
+                    .chars()
+                    .find(|character| {
+                        let mut buffer = [0; 4];
+                        SHIFT_JIS.encode(character.encode_utf8(&mut buffer)).2
+                    })
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+                return Err(ExportError::Unencodable {
+                    character,
+                    encoding: encoding.name(),
+                });
+            }
+            Ok(bytes.into_owned())
+        }
+    }
+}
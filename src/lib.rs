@@ -4,6 +4,20 @@
 //! - request builders for each BOJ endpoint under [`query`],
 //! - a synchronous API client under [`client`],
 //! - strongly-typed response models under [`model`],
+//! - full-text search over series metadata under [`search`],
+//! - fiscal period conversion and the Japanese business-day calendar under
+//!   [`calendar`],
+//! - CSV, TSV, JSON Lines and Frictionless Data Package exporters under
+//!   [`export`],
+//! - Arrow `RecordBatch` conversion under `arrow` (feature `arrow`),
+//! - DuckDB loading and SQL queries under `duckdb` (feature `duckdb`),
+//! - a partitioned Parquet dataset writer under `parquet` (feature `parquet`),
+//! - Polars `DataFrame` conversion under `polars` (feature `polars`),
+//! - SDMX-JSON and SDMX-ML 2.1 export under `sdmx` (feature `sdmx`),
+//! - a local SQLite mirror with incremental sync under `store` (feature `store`),
+//! - growth rates, differences, rolling statistics, rebasing, frequency
+//!   conversion, alignment and seasonal decomposition of numeric series
+//!   under [`transform`],
 //! - shared error definitions under [`error`].
 //!
 //! Internal transport/decoder details are intentionally hidden from the
@@ -19,16 +33,44 @@
 #![deny(rustdoc::broken_intra_doc_links)]
 #![deny(rustdoc::bare_urls)]
 
+/// Arrow `RecordBatch` conversion for series responses.
+#[cfg(feature = "arrow")]
+pub mod arrow;
+/// Fiscal periods, Japanese holidays and business days.
+pub mod calendar;
 /// Static discovery catalog derived from BOJ API manual appendices.
 pub mod catalog;
 /// BOJ API client entry point.
 pub mod client;
+/// DuckDB tables of series, metadata and the DB catalog for ad-hoc SQL.
+#[cfg(feature = "duckdb")]
+pub mod duckdb;
 /// Error definitions shared across query, transport, and decode layers.
 pub mod error;
+/// CSV, TSV, JSON Lines and Frictionless Data Package exporters for series
+/// collections.
+pub mod export;
 /// Public response model types.
 pub mod model;
+/// Partitioned Parquet dataset writer for series data and metadata.
+#[cfg(feature = "parquet")]
+pub mod parquet;
+/// Polars `DataFrame` conversion for series responses.
+#[cfg(feature = "polars")]
+pub mod polars;
 /// Query builders and option enums for BOJ API requests.
 pub mod query;
+/// SDMX-JSON and SDMX-ML 2.1 data messages and structure definitions.
+#[cfg(feature = "sdmx")]
+pub mod sdmx;
+/// Full-text search index over series metadata.
+pub mod search;
+/// Local SQLite mirror of series metadata and observations.
+#[cfg(feature = "store")]
+pub mod store;
+/// Typed numeric series and their transformations.
+pub mod transform;
 
 mod decode;
+mod period;
 mod transport;
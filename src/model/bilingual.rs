@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use crate::query::Language;
+
+use super::response::ResponseMeta;
+
+/// A response merged from Japanese (`lang=jp`) and English (`lang=en`) calls.
+///
+/// `merged` keeps the Japanese response as its base (parameter echo,
+/// pagination cursor, points and `raw`) and fills the English label fields
+/// from the English response. Differences detected while joining the two
+/// responses are listed in `mismatches`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bilingual<T> {
+    /// Merged response carrying both Japanese and English labels.
+    pub merged: T,
+    /// Response metadata of the English call.
+    pub english_meta: ResponseMeta,
+    /// Differences found between the Japanese and English responses.
+    #[serde(default)]
+    pub mismatches: Vec<BilingualMismatch>,
+}
+
+impl<T> Bilingual<T> {
+    /// Returns `true` when both responses agreed on codes and points.
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// One difference between the Japanese and English responses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BilingualMismatch {
+    /// A series (or metadata row) is present in only one response.
+    ///
+    /// Metadata hierarchy rows without a series code are keyed by their layer
+    /// path (for example `1.1.0.0.0`).
+    MissingSeries {
+        /// Series code or layer path identifying the row.
+        key: String,
+        /// Language whose response lacks the row.
+        missing_in: Language,
+    },
+    /// A survey date is present in only one response for the same series.
+    MissingPoint {
+        /// Series code of the affected series.
+        series_code: String,
+        /// Survey date missing from one response.
+        survey_date: String,
+        /// Language whose response lacks the point.
+        missing_in: Language,
+    },
+    /// Both responses carry the survey date but disagree on its value.
+    ValueMismatch {
+        /// Series code of the affected series.
+        series_code: String,
+        /// Survey date whose values differ.
+        survey_date: String,
+        /// Value from the Japanese response.
+        japanese: Option<String>,
+        /// Value from the English response.
+        english: Option<String>,
+    },
+    /// The pagination cursors of the two responses differ.
+    NextPositionMismatch {
+        /// `NEXTPOSITION` from the Japanese response.
+        japanese: Option<u32>,
+        /// `NEXTPOSITION` from the English response.
+        english: Option<u32>,
+    },
+}
@@ -0,0 +1,228 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::response::{CodeResponse, DataPoint, LayerResponse};
+use super::series::TimeSeries;
+
+/// Structured difference between two fetches of the same query.
+///
+/// Build it with [`CodeResponse::diff`], [`LayerResponse::diff`] or
+/// [`ResponseDiff::between`]. It serializes to JSON with serde, so it can be
+/// stored or sent as a change notification as is.
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::query::CodeQuery;
+///
+/// let client = BojClient::new()?;
+/// let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])?;
+/// let yesterday = client.get_data_code_all(&query)?;
+/// // ... later ...
+/// let today = client.get_data_code_all(&query)?;
+///
+/// let diff = yesterday.diff(&today);
+/// if !diff.is_empty() {
+///     println!("{}", serde_json::to_string_pretty(&diff)?);
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseDiff {
+    /// Codes of series only in the newer response, in its order.
+    pub added_series: Vec<String>,
+    /// Codes of series only in the older response, in its order.
+    pub removed_series: Vec<String>,
+    /// Series present in both responses that changed, in the newer
+    /// response's order.
+    pub changed_series: Vec<SeriesDiff>,
+}
+
+/// Changes to one series present in both responses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesDiff {
+    /// Time-series code.
+    pub series_code: String,
+    /// Labels, frequency or `last_update` that changed.
+    pub label_changes: Vec<LabelChange>,
+    /// Points for survey dates the older response did not have.
+    pub added_points: Vec<DataPoint>,
+    /// Points for survey dates the newer response no longer has.
+    pub removed_points: Vec<DataPoint>,
+    /// Points whose value changed.
+    pub value_changes: Vec<ValueChange>,
+}
+
+/// A series label whose text changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LabelChange {
+    /// Series field name (`name_of_time_series_j`, `unit`, `last_update`,
+    /// ...).
+    pub field: String,
+    /// Text in the older response.
+    pub old: Option<String>,
+    /// Text in the newer response.
+    pub new: Option<String>,
+}
+
+/// An observation whose value changed between the responses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValueChange {
+    /// Survey date of the observation.
+    pub survey_date: String,
+    /// Value in the older response.
+    pub old: Option<String>,
+    /// Value in the newer response.
+    pub new: Option<String>,
+}
+
+impl ResponseDiff {
+    /// Compares the series of an older and a newer fetch.
+    ///
+    /// Series are matched by code and points by survey date. Values that
+    /// parse to the same number (`1.50` and `1.5`) are not reported as
+    /// changed.
+    pub fn between<'a, T: TimeSeries + 'a>(
+        old: impl IntoIterator<Item = &'a T>,
+        new: impl IntoIterator<Item = &'a T>,
+    ) -> Self {
+        let old = old.into_iter().collect::<Vec<_>>();
+        let new = new.into_iter().collect::<Vec<_>>();
+        let old_by_code = old
+            .iter()
+            .map(|series| (series.series_code(), *series))
+            .collect::<BTreeMap<_, _>>();
+        let new_codes = new
+            .iter()
+            .map(|series| series.series_code())
+            .collect::<BTreeSet<_>>();
+
+        let mut diff = Self {
+            removed_series: old
+                .iter()
+                .map(|series| series.series_code())
+                .filter(|code| !new_codes.contains(code))
+                .map(str::to_string)
+                .collect(),
+            ..Self::default()
+        };
+        for series in new {
+            match old_by_code.get(series.series_code()) {
+                Some(previous) => {
+                    let changes = SeriesDiff::between(*previous, series);
+                    if !changes.is_empty() {
+                        diff.changed_series.push(changes);
+                    }
+                }
+                None => diff.added_series.push(series.series_code().to_string()),
+            }
+        }
+        diff
+    }
+
+    /// Returns `true` when the responses hold the same series and values.
+    pub fn is_empty(&self) -> bool {
+        self.added_series.is_empty()
+            && self.removed_series.is_empty()
+            && self.changed_series.is_empty()
+    }
+}
+
+impl SeriesDiff {
+    fn between<T: TimeSeries>(old: &T, new: &T) -> Self {
+        let labels = [
+            ("name_of_time_series_j", old.name_j(), new.name_j()),
+            ("name_of_time_series", old.name(), new.name()),
+            ("unit_j", old.unit_j(), new.unit_j()),
+            ("unit", old.unit(), new.unit()),
+            ("frequency", old.frequency(), new.frequency()),
+            ("category_j", old.category_j(), new.category_j()),
+            ("category", old.category(), new.category()),
+            ("last_update", old.last_update(), new.last_update()),
+        ];
+        let label_changes = labels
+            .into_iter()
+            .filter(|(_, old, new)| old != new)
+            .map(|(field, old, new)| LabelChange {
+                field: field.to_string(),
+                old: old.map(str::to_string),
+                new: new.map(str::to_string),
+            })
+            .collect();
+
+        let old_points = points_by_date(old.points());
+        let new_points = points_by_date(new.points());
+        let mut added_points = Vec::new();
+        let mut value_changes = Vec::new();
+        for point in new.points() {
+            match old_points.get(point.survey_date.as_str()) {
+                None => added_points.push(point.clone()),
+                Some(previous) if !same_value(previous, point) => {
+                    value_changes.push(ValueChange {
+                        survey_date: point.survey_date.clone(),
+                        old: previous.value.clone(),
+                        new: point.value.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        let removed_points = old
+            .points()
+            .iter()
+            .filter(|point| !new_points.contains_key(point.survey_date.as_str()))
+            .cloned()
+            .collect();
+
+        Self {
+            series_code: new.series_code().to_string(),
+            label_changes,
+            added_points,
+            removed_points,
+            value_changes,
+        }
+    }
+
+    /// Returns `true` when nothing about the series changed.
+    pub fn is_empty(&self) -> bool {
+        self.label_changes.is_empty()
+            && self.added_points.is_empty()
+            && self.removed_points.is_empty()
+            && self.value_changes.is_empty()
+    }
+}
+
+impl CodeResponse {
+    /// Compares this response with a `newer` fetch of the same query.
+    ///
+    /// See [`ResponseDiff::between`] for matching rules.
+    pub fn diff(&self, newer: &CodeResponse) -> ResponseDiff {
+        ResponseDiff::between(&self.series, &newer.series)
+    }
+}
+
+impl LayerResponse {
+    /// Compares this response with a `newer` fetch of the same query.
+    ///
+    /// See [`ResponseDiff::between`] for matching rules.
+    pub fn diff(&self, newer: &LayerResponse) -> ResponseDiff {
+        ResponseDiff::between(&self.series, &newer.series)
+    }
+}
+
+fn points_by_date(points: &[DataPoint]) -> BTreeMap<&str, &DataPoint> {
+    points
+        .iter()
+        .map(|point| (point.survey_date.as_str(), point))
+        .collect()
+}
+
+fn same_value(old: &DataPoint, new: &DataPoint) -> bool {
+    old.value == new.value
+        || matches!(
+            (old.numeric_value(), new.numeric_value()),
+            (Ok(Some(old)), Ok(Some(new))) if old == new
+        )
+}
@@ -0,0 +1,254 @@
+use crate::error::BojError;
+use crate::period::{DaySpan, period_span};
+use crate::query::{CodeQuery, Frequency};
+
+use super::response::{MetadataEntry, MetadataResponse};
+
+/// Composable criteria for selecting series rows of a [`MetadataResponse`].
+///
+/// Every criterion that is set must match (AND semantics). Only rows with a
+/// series code are matched; hierarchy header rows never match.
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::model::MetadataFilter;
+/// use boj_client::query::{Frequency, MetadataQuery};
+///
+/// let client = BojClient::new()?;
+/// let metadata = client.get_metadata(&MetadataQuery::new("PR01")?)?;
+///
+/// let filter = MetadataFilter::new()
+///     .with_frequency(Frequency::M)
+///     .with_layer_prefix([2, 3])
+///     .with_updated_since("20260101")?;
+/// let query = metadata.code_query(&filter)?;
+/// let response = client.get_data_code(&query)?;
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetadataFilter {
+    frequency: Option<Frequency>,
+    layer_prefix: Vec<u32>,
+    code_pattern: Option<String>,
+    coverage: Option<(DaySpan, DaySpan)>,
+    updated_since: Option<DaySpan>,
+    updated_until: Option<DaySpan>,
+}
+
+impl MetadataFilter {
+    /// Creates a filter that matches every series row.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps series whose `FREQUENCY` label maps to `frequency`.
+    pub fn with_frequency(mut self, frequency: Frequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Keeps series located under `prefix` in the layer hierarchy.
+    ///
+    /// `[2, 3]` matches series whose layer path starts with `LAYER1=2`,
+    /// `LAYER2=3`. An empty prefix matches every series.
+    pub fn with_layer_prefix(mut self, prefix: impl Into<Vec<u32>>) -> Self {
+        self.layer_prefix = prefix.into();
+        self
+    }
+
+    /// Keeps series whose code matches `pattern`.
+    ///
+    /// `*` matches any run of characters and `?` matches a single character.
+    /// Matching ignores ASCII case, so `str*con` matches `STRDCLUCON`.
+    pub fn with_code_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.code_pattern = Some(pattern.into());
+        self
+    }
+
+    /// Keeps series whose observations cover `from` to `to`.
+    ///
+    /// Bounds are calendar periods in `YYYY`, `YYYYMM` or `YYYYMMDD` form. A
+    /// series matches when `START_OF_THE_TIME_SERIES` begins on or before the
+    /// first day of `from` and `END_OF_THE_TIME_SERIES` reaches the first day
+    /// of `to`. Period labels are read according to the series frequency, so
+    /// quarterly `202503` ends on September 30.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when a bound is not a valid period or `from` is
+    /// after `to`.
+    pub fn with_coverage(
+        mut self,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+    ) -> Result<Self, BojError> {
+        let from = parse_bound("coverage start", from.as_ref())?;
+        let to = parse_bound("coverage end", to.as_ref())?;
+        if from.first > to.first {
+            return Err(BojError::validation(
+                "coverage start must not be after coverage end",
+            ));
+        }
+        self.coverage = Some((from, to));
+        Ok(self)
+    }
+
+    /// Keeps series whose `LAST_UPDATE` is on or after `date`.
+    ///
+    /// `date` accepts `YYYY`, `YYYYMM` or `YYYYMMDD`; coarser forms start at
+    /// the first day of the period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when `date` is not a valid period.
+    pub fn with_updated_since(mut self, date: impl AsRef<str>) -> Result<Self, BojError> {
+        self.updated_since = Some(parse_bound("updated since", date.as_ref())?);
+        Ok(self)
+    }
+
+    /// Keeps series whose `LAST_UPDATE` is on or before `date`.
+    ///
+    /// `date` accepts `YYYY`, `YYYYMM` or `YYYYMMDD`; coarser forms end at
+    /// the last day of the period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when `date` is not a valid period.
+    pub fn with_updated_until(mut self, date: impl AsRef<str>) -> Result<Self, BojError> {
+        self.updated_until = Some(parse_bound("updated until", date.as_ref())?);
+        Ok(self)
+    }
+
+    /// Returns `true` when `entry` is a series row satisfying every criterion.
+    pub fn matches(&self, entry: &MetadataEntry) -> bool {
+        let Some(series_code) = entry.series_code.as_deref() else {
+            return false;
+        };
+
+        let frequency = entry.frequency.as_deref().and_then(Frequency::from_label);
+        if self.frequency.is_some() && frequency != self.frequency {
+            return false;
+        }
+        if !entry.layer_path().starts_with(&self.layer_prefix) {
+            return false;
+        }
+        if let Some(pattern) = &self.code_pattern
+            && !glob_match(pattern.as_bytes(), series_code.as_bytes())
+        {
+            return false;
+        }
+
+        if let Some((from, to)) = self.coverage {
+            let start = entry
+                .start_of_the_time_series
+                .as_deref()
+                .and_then(|start| period_span(start, frequency));
+            let end = entry
+                .end_of_the_time_series
+                .as_deref()
+                .and_then(|end| period_span(end, frequency));
+            match (start, end) {
+                (Some(start), Some(end)) if start.first <= from.first && end.last >= to.first => {}
+                _ => return false,
+            }
+        }
+
+        if self.updated_since.is_some() || self.updated_until.is_some() {
+            let Some(updated) = entry
+                .last_update
+                .as_deref()
+                .and_then(|date| period_span(date, None))
+            else {
+                return false;
+            };
+            if self
+                .updated_since
+                .is_some_and(|since| updated.first < since.first)
+                || self
+                    .updated_until
+                    .is_some_and(|until| updated.first > until.last)
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+impl MetadataResponse {
+    /// Iterates over series rows matching `filter`, in response order.
+    pub fn filter<'a>(
+        &'a self,
+        filter: &'a MetadataFilter,
+    ) -> impl Iterator<Item = &'a MetadataEntry> + 'a {
+        self.entries.iter().filter(|entry| filter.matches(entry))
+    }
+
+    /// Returns the series codes matching `filter`, in response order.
+    pub fn filter_codes(&self, filter: &MetadataFilter) -> Vec<String> {
+        self.filter(filter)
+            .filter_map(|entry| entry.series_code.clone())
+            .collect()
+    }
+
+    /// Builds a `getDataCode` query for this DB and the codes matching
+    /// `filter`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when no series matches or the matches exceed the
+    /// `CODE` limit of [`CodeQuery::new`].
+    pub fn code_query(&self, filter: &MetadataFilter) -> Result<CodeQuery, BojError> {
+        CodeQuery::new(self.db.clone(), self.filter_codes(filter))
+    }
+}
+
+fn parse_bound(name: &str, value: &str) -> Result<DaySpan, BojError> {
+    period_span(value, None)
+        .ok_or_else(|| BojError::validation(format!("{name} must be YYYY, YYYYMM or YYYYMMDD")))
+}
+
+/// Matches `text` against a `*`/`?` glob, ignoring ASCII case.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        match pattern.get(p) {
+            Some(b'*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&byte) if byte == b'?' || byte.eq_ignore_ascii_case(&text[t]) => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|byte| *byte == b'*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::glob_match;
+
+    #[test]
+    fn glob_match_supports_wildcards_and_ignores_case() {
+        assert!(glob_match(b"STR*CON", b"STRDCLUCON"));
+        assert!(glob_match(b"str?clucon", b"STRDCLUCON"));
+        assert!(glob_match(b"*", b""));
+        assert!(glob_match(b"FOF_*A1?0", b"FOF_FFAS100A110"));
+        assert!(!glob_match(b"FOF_*A1?0", b"FOF_FFAS100A111"));
+        assert!(!glob_match(b"STR", b"STRDCLUCON"));
+    }
+}
@@ -1,6 +1,16 @@
+mod bilingual;
+mod diff;
+mod filter;
 mod response;
+mod series;
+mod tree;
 
+pub use bilingual::{Bilingual, BilingualMismatch};
+pub use diff::{LabelChange, ResponseDiff, SeriesDiff, ValueChange};
+pub use filter::MetadataFilter;
 pub use response::{
     CodeParameterEcho, CodeResponse, CodeSeries, DataPoint, LayerParameterEcho, LayerResponse,
-    LayerSeries, MetadataEntry, MetadataResponse, ResponseMeta,
+    LayerSelector, LayerSeries, MetadataEntry, MetadataResponse, ResponseMeta,
 };
+pub use series::TimeSeries;
+pub use tree::{MetadataTree, MetadataTreeNode, SeriesCodes};
@@ -1,6 +1,9 @@
 use std::collections::BTreeMap;
+use std::fmt;
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::query::parse_layer_value;
 
 /// Shared response metadata returned by BOJ endpoints.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -44,6 +47,64 @@ pub struct CodeParameterEcho {
     pub extras: BTreeMap<String, String>,
 }
 
+/// A `getDataLayer` layer selector, as sent by
+/// [`LayerQuery`](crate::query::LayerQuery) and echoed back in responses.
+///
+/// Serialized as a JSON number for [`LayerSelector::Index`] and as the string
+/// `"*"` for [`LayerSelector::Wildcard`].
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::model::LayerSelector;
+///
+/// assert_eq!(LayerSelector::Index(2).to_string(), "2");
+/// assert_eq!(LayerSelector::Wildcard.to_string(), "*");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayerSelector {
+    /// All values in the layer (`*`).
+    Wildcard,
+    /// A specific positive layer index.
+    Index(u32),
+}
+
+impl fmt::Display for LayerSelector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Wildcard => f.write_str("*"),
+            Self::Index(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+impl Serialize for LayerSelector {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Wildcard => serializer.serialize_str("*"),
+            Self::Index(value) => serializer.serialize_u32(*value),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for LayerSelector {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Index(u32),
+            Text(String),
+        }
+
+        let text = match Repr::deserialize(deserializer)? {
+            Repr::Index(value) => value.to_string(),
+            Repr::Text(text) => text.trim().to_string(),
+        };
+        parse_layer_value(&text)
+            .map_err(|_| serde::de::Error::custom(format!("invalid layer selector '{text}'")))
+    }
+}
+
 /// Echoed request parameters for `getDataLayer`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 pub struct LayerParameterEcho {
@@ -55,16 +116,19 @@ pub struct LayerParameterEcho {
     pub db: Option<String>,
     /// Echoed `frequency` parameter, if present in the response payload.
     pub frequency: Option<String>,
-    /// Echoed first layer index, if present in the response payload.
-    pub layer1: Option<u32>,
-    /// Echoed second layer index, if present in the response payload.
-    pub layer2: Option<u32>,
-    /// Echoed third layer index, if present in the response payload.
-    pub layer3: Option<u32>,
-    /// Echoed fourth layer index, if present in the response payload.
-    pub layer4: Option<u32>,
-    /// Echoed fifth layer index, if present in the response payload.
-    pub layer5: Option<u32>,
+    /// Echoed combined `layer` parameter (for example `1,*`), if present in
+    /// the response payload.
+    pub layer: Option<String>,
+    /// Echoed first layer selector, if present in the response payload.
+    pub layer1: Option<LayerSelector>,
+    /// Echoed second layer selector, if present in the response payload.
+    pub layer2: Option<LayerSelector>,
+    /// Echoed third layer selector, if present in the response payload.
+    pub layer3: Option<LayerSelector>,
+    /// Echoed fourth layer selector, if present in the response payload.
+    pub layer4: Option<LayerSelector>,
+    /// Echoed fifth layer selector, if present in the response payload.
+    pub layer5: Option<LayerSelector>,
     /// Echoed `startDate` parameter, if present in the response payload.
     pub start_date: Option<String>,
     /// Echoed `endDate` parameter, if present in the response payload.
@@ -178,6 +242,56 @@ pub struct MetadataEntry {
     pub extras: BTreeMap<String, Option<String>>,
 }
 
+impl MetadataEntry {
+    /// Returns the layer path of this entry, such as `[1, 1, 2]`.
+    ///
+    /// Layers are read from `layer1` onward and stop at the first missing or
+    /// `0` value, so unused trailing layers are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use boj_client::model::MetadataEntry;
+    ///
+    /// let entry = MetadataEntry {
+    ///     series_code: Some("FOF_FFAS100A110".to_string()),
+    ///     name_of_time_series_j: None,
+    ///     name_of_time_series: None,
+    ///     unit_j: None,
+    ///     unit: None,
+    ///     frequency: None,
+    ///     category_j: None,
+    ///     category: None,
+    ///     layer1: Some(1),
+    ///     layer2: Some(1),
+    ///     layer3: Some(1),
+    ///     layer4: Some(2),
+    ///     layer5: Some(0),
+    ///     start_of_the_time_series: None,
+    ///     end_of_the_time_series: None,
+    ///     last_update: None,
+    ///     notes_j: None,
+    ///     notes: None,
+    ///     extras: BTreeMap::new(),
+    /// };
+    /// assert_eq!(entry.layer_path(), vec![1, 1, 1, 2]);
+    /// ```
+    pub fn layer_path(&self) -> Vec<u32> {
+        [
+            self.layer1,
+            self.layer2,
+            self.layer3,
+            self.layer4,
+            self.layer5,
+        ]
+        .into_iter()
+        .map_while(|layer| layer.filter(|value| *value != 0))
+        .collect()
+    }
+}
+
 /// Decoded response model for `getDataCode`.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CodeResponse {
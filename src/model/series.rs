@@ -0,0 +1,111 @@
+use crate::error::BojError;
+
+use super::response::{CodeSeries, DataPoint, LayerSeries};
+
+/// Read access shared by [`CodeSeries`] and [`LayerSeries`].
+///
+/// Conversions and exporters accept any `TimeSeries`, so the same code works
+/// for `getDataCode` and `getDataLayer` results.
+pub trait TimeSeries {
+    /// Time-series code.
+    fn series_code(&self) -> &str;
+    /// Japanese time-series name.
+    fn name_j(&self) -> Option<&str>;
+    /// English time-series name.
+    fn name(&self) -> Option<&str>;
+    /// Japanese unit label.
+    fn unit_j(&self) -> Option<&str>;
+    /// English unit label.
+    fn unit(&self) -> Option<&str>;
+    /// Frequency label reported by BOJ.
+    fn frequency(&self) -> Option<&str>;
+    /// Japanese category label.
+    fn category_j(&self) -> Option<&str>;
+    /// English category label.
+    fn category(&self) -> Option<&str>;
+    /// Last update timestamp/text from BOJ.
+    fn last_update(&self) -> Option<&str>;
+    /// Observation points.
+    fn points(&self) -> &[DataPoint];
+}
+
+macro_rules! impl_time_series {
+    ($series:ty) => {
+        impl TimeSeries for $series {
+            fn series_code(&self) -> &str {
+                &self.series_code
+            }
+
+            fn name_j(&self) -> Option<&str> {
+                self.name_of_time_series_j.as_deref()
+            }
+
+            fn name(&self) -> Option<&str> {
+                self.name_of_time_series.as_deref()
+            }
+
+            fn unit_j(&self) -> Option<&str> {
+                self.unit_j.as_deref()
+            }
+
+            fn unit(&self) -> Option<&str> {
+                self.unit.as_deref()
+            }
+
+            fn frequency(&self) -> Option<&str> {
+                self.frequency.as_deref()
+            }
+
+            fn category_j(&self) -> Option<&str> {
+                self.category_j.as_deref()
+            }
+
+            fn category(&self) -> Option<&str> {
+                self.category.as_deref()
+            }
+
+            fn last_update(&self) -> Option<&str> {
+                self.last_update.as_deref()
+            }
+
+            fn points(&self) -> &[DataPoint] {
+                &self.points
+            }
+        }
+    };
+}
+
+impl_time_series!(CodeSeries);
+impl_time_series!(LayerSeries);
+
+impl DataPoint {
+    /// Parses the value as `f64`, returning `None` for missing values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boj_client::model::DataPoint;
+    ///
+    /// let point = DataPoint {
+    ///     survey_date: "202401".to_string(),
+    ///     value: Some("1.25".to_string()),
+    /// };
+    /// assert_eq!(point.numeric_value()?, Some(1.25));
+    /// # Ok::<(), boj_client::error::BojError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when the value is present but not a number.
+    pub fn numeric_value(&self) -> Result<Option<f64>, BojError> {
+        let Some(value) = self.value.as_deref() else {
+            return Ok(None);
+        };
+        value.trim().parse::<f64>().map(Some).map_err(|_| {
+            BojError::decode(format!(
+                "non-numeric value {value:?} at {}",
+                self.survey_date
+            ))
+        })
+    }
+}
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::query::Language;
+
+use super::response::{MetadataEntry, MetadataResponse};
+
+/// Hierarchical view of one DB built from `getMetadata` layer information.
+///
+/// BOJ organizes every DB into up to five layers (`LAYER1`..`LAYER5`).
+/// Hierarchy rows carry names without a series code, and series rows sit at
+/// the bottom of the path they belong to. Paths are written as slices of
+/// non-zero layer indices, for example `&[1, 1, 1]`.
+///
+/// Missing intermediate rows are represented by placeholder nodes without an
+/// entry. Rows without any layer information are not placed in the tree.
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::model::MetadataTree;
+/// use boj_client::query::{Language, MetadataQuery};
+///
+/// let client = BojClient::new()?;
+/// let response = client.get_metadata(&MetadataQuery::new("FF")?)?;
+/// let tree = MetadataTree::from_response(&response);
+///
+/// for code in tree.series_codes_under(&[1, 1]) {
+///     println!("{code}: {:?}", tree.breadcrumb(code, Language::Jp));
+/// }
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataTree {
+    /// Database code the tree was built from.
+    pub db: String,
+    /// Top-level (`LAYER1`) nodes ordered by layer index.
+    pub roots: Vec<MetadataTreeNode>,
+}
+
+/// One node in a [`MetadataTree`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataTreeNode {
+    /// Layer path of this node.
+    pub path: Vec<u32>,
+    /// Metadata row for this node; `None` for placeholder nodes.
+    pub entry: Option<MetadataEntry>,
+    /// Child nodes ordered by layer index.
+    #[serde(default)]
+    pub children: Vec<MetadataTreeNode>,
+}
+
+impl MetadataTreeNode {
+    fn placeholder(path: Vec<u32>) -> Self {
+        Self {
+            path,
+            entry: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns the series code when this node is a series row.
+    pub fn series_code(&self) -> Option<&str> {
+        self.entry.as_ref()?.series_code.as_deref()
+    }
+
+    /// Returns the node label in `lang`, falling back to the other language.
+    pub fn name(&self, lang: Language) -> Option<&str> {
+        let entry = self.entry.as_ref()?;
+        let (preferred, fallback) = match lang {
+            Language::Jp => (&entry.name_of_time_series_j, &entry.name_of_time_series),
+            Language::En => (&entry.name_of_time_series, &entry.name_of_time_series_j),
+        };
+        preferred.as_deref().or(fallback.as_deref())
+    }
+
+    /// Returns the node label in `lang`, or the dotted layer path when the
+    /// node has no label.
+    pub fn display_name(&self, lang: Language) -> String {
+        match self.name(lang) {
+            Some(name) => name.to_string(),
+            None => self
+                .path
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join("."),
+        }
+    }
+
+    /// Iterates over series codes in this node and all of its descendants in
+    /// depth-first order.
+    pub fn series_codes(&self) -> SeriesCodes<'_> {
+        SeriesCodes { stack: vec![self] }
+    }
+}
+
+/// Depth-first iterator over series codes in a [`MetadataTree`] subtree.
+///
+/// Created by [`MetadataTreeNode::series_codes`] and
+/// [`MetadataTree::series_codes_under`].
+#[derive(Debug, Clone)]
+pub struct SeriesCodes<'a> {
+    stack: Vec<&'a MetadataTreeNode>,
+}
+
+impl<'a> Iterator for SeriesCodes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            self.stack.extend(node.children.iter().rev());
+            if let Some(code) = node.series_code() {
+                return Some(code);
+            }
+        }
+        None
+    }
+}
+
+impl MetadataTree {
+    /// Builds a tree from a decoded `getMetadata` response.
+    pub fn from_response(response: &MetadataResponse) -> Self {
+        let mut roots = Vec::new();
+        for entry in &response.entries {
+            let path = entry.layer_path();
+            if path.is_empty() {
+                continue;
+            }
+            insert_entry(&mut roots, &path, entry);
+        }
+        sort_nodes(&mut roots);
+
+        Self {
+            db: response.db.clone(),
+            roots,
+        }
+    }
+
+    /// Returns the node at `path`.
+    ///
+    /// When BOJ reports several rows with the same path, the first one is
+    /// returned.
+    pub fn node(&self, path: &[u32]) -> Option<&MetadataTreeNode> {
+        let (first, rest) = path.split_first()?;
+        let mut node = self.roots.iter().find(|node| node.path[0] == *first)?;
+        for (depth, index) in rest.iter().enumerate() {
+            node = node
+                .children
+                .iter()
+                .find(|child| child.path[depth + 1] == *index)?;
+        }
+        Some(node)
+    }
+
+    /// Returns the children of the node at `path`.
+    ///
+    /// An empty `path` returns the top-level nodes.
+    pub fn children(&self, path: &[u32]) -> Option<&[MetadataTreeNode]> {
+        if path.is_empty() {
+            return Some(&self.roots);
+        }
+        self.node(path).map(|node| node.children.as_slice())
+    }
+
+    /// Returns the ancestors of the node at `path`, from the top-level node
+    /// down to the direct parent.
+    pub fn ancestors(&self, path: &[u32]) -> Option<Vec<&MetadataTreeNode>> {
+        self.node(path)?;
+        (1..path.len())
+            .map(|depth| self.node(&path[..depth]))
+            .collect()
+    }
+
+    /// Returns the node holding `series_code`.
+    pub fn find_series(&self, series_code: &str) -> Option<&MetadataTreeNode> {
+        let mut stack = self.roots.iter().rev().collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            if node.series_code() == Some(series_code) {
+                return Some(node);
+            }
+            stack.extend(node.children.iter().rev());
+        }
+        None
+    }
+
+    /// Returns the names from the top-level node down to `series_code`.
+    ///
+    /// Nodes without a label are rendered as their dotted layer path.
+    pub fn breadcrumb(&self, series_code: &str, lang: Language) -> Option<Vec<String>> {
+        let node = self.find_series(series_code)?;
+        let mut names = self
+            .ancestors(&node.path)?
+            .into_iter()
+            .map(|ancestor| ancestor.display_name(lang))
+            .collect::<Vec<_>>();
+        names.push(node.display_name(lang));
+        Some(names)
+    }
+
+    /// Iterates over series codes under `path` (including the node itself).
+    ///
+    /// An empty `path` iterates over the whole tree.
+    pub fn series_codes_under(&self, path: &[u32]) -> SeriesCodes<'_> {
+        let stack = if path.is_empty() {
+            self.roots.iter().rev().collect()
+        } else {
+            self.node(path).into_iter().collect()
+        };
+        SeriesCodes { stack }
+    }
+}
+
+fn insert_entry(nodes: &mut Vec<MetadataTreeNode>, path: &[u32], entry: &MetadataEntry) {
+    let mut siblings = nodes;
+    for depth in 1..path.len() {
+        let prefix = &path[..depth];
+        let position = match siblings.iter().position(|node| node.path == prefix) {
+            Some(position) => position,
+            None => {
+                siblings.push(MetadataTreeNode::placeholder(prefix.to_vec()));
+                siblings.len() - 1
+            }
+        };
+        siblings = &mut siblings[position].children;
+    }
+
+    match siblings
+        .iter_mut()
+        .find(|node| node.path == path && node.entry.is_none())
+    {
+        Some(node) => node.entry = Some(entry.clone()),
+        None => siblings.push(MetadataTreeNode {
+            path: path.to_vec(),
+            entry: Some(entry.clone()),
+            children: Vec::new(),
+        }),
+    }
+}
+
+fn sort_nodes(nodes: &mut [MetadataTreeNode]) {
+    nodes.sort_by(|left, right| left.path.cmp(&right.path));
+    for node in nodes {
+        sort_nodes(&mut node.children);
+    }
+}
@@ -0,0 +1,4 @@
+mod store;
+
+pub use parquet::errors::ParquetError;
+pub use store::{ParquetStore, metadata_schema, observation_schema};
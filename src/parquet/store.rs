@@ -0,0 +1,329 @@
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Date32Array, Float64Array, RecordBatch, StringArray, UInt32Array};
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::errors::ParquetError;
+use parquet::file::properties::WriterProperties;
+
+use crate::arrow::{FREQUENCY_KEY, LAST_UPDATE_KEY, SeriesRef, parse_value};
+use crate::model::{CodeResponse, LayerResponse, MetadataEntry, MetadataResponse, ResponseMeta};
+use crate::period::{days_since_epoch, period_span};
+use crate::query::Frequency;
+
+const OBSERVATIONS_DIR: &str = "observations";
+const METADATA_DIR: &str = "metadata";
+const UNKNOWN_FREQUENCY: &str = "unknown";
+
+/// Returns the schema of observation files written by [`ParquetStore`].
+///
+/// | Column | Type | Notes |
+/// | --- | --- | --- |
+/// | `series_code` | `Utf8` | |
+/// | `period` | `Utf8` | BOJ survey date as reported (`202401`, `20240105`, ...) |
+/// | `period_start` | `Date32` | First day of the period; null when unparseable |
+/// | `period_end` | `Date32` | Last day of the period; null when unparseable |
+/// | `value` | `Float64` | Null for missing values |
+/// | `last_update` | `Utf8` | `LAST_UPDATE` of the series |
+/// | `retrieved_at` | `Utf8` | Response `DATE` of the fetch that produced the row |
+///
+/// The DB and frequency are not stored as columns; they are encoded in the
+/// Hive-style partition directories instead.
+pub fn observation_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("series_code", DataType::Utf8, false),
+        Field::new("period", DataType::Utf8, false),
+        Field::new("period_start", DataType::Date32, true),
+        Field::new("period_end", DataType::Date32, true),
+        Field::new("value", DataType::Float64, true),
+        Field::new("last_update", DataType::Utf8, true),
+        Field::new("retrieved_at", DataType::Utf8, true),
+    ]))
+}
+
+/// Returns the schema of metadata files written by [`ParquetStore`].
+///
+/// Columns follow [`MetadataEntry`](crate::model::MetadataEntry) field
+/// names, with `layer1`..`layer5` as `UInt32` and every other field as
+/// nullable `Utf8`, followed by `retrieved_at` (response `DATE`).
+pub fn metadata_schema() -> SchemaRef {
+    let text = |name: &str| Field::new(name, DataType::Utf8, true);
+    let layer = |name: &str| Field::new(name, DataType::UInt32, true);
+    Arc::new(Schema::new(vec![
+        text("series_code"),
+        text("name_of_time_series_j"),
+        text("name_of_time_series"),
+        text("unit_j"),
+        text("unit"),
+        text("frequency"),
+        text("category_j"),
+        text("category"),
+        layer("layer1"),
+        layer("layer2"),
+        layer("layer3"),
+        layer("layer4"),
+        layer("layer5"),
+        text("start_of_the_time_series"),
+        text("end_of_the_time_series"),
+        text("last_update"),
+        text("notes_j"),
+        text("notes"),
+        text("retrieved_at"),
+    ]))
+}
+
+/// Parquet dataset of BOJ observations and metadata under one root
+/// directory.
+///
+/// Files are laid out in Hive-style partitions so that query engines can
+/// prune by DB and frequency:
+///
+/// ```text
+/// <root>/observations/db=FM08/frequency=D/part-00000.parquet
+/// <root>/metadata/db=FM08/part-00000.parquet
+/// ```
+///
+/// Every write creates new `part-NNNNN.parquet` files next to the existing
+/// ones, so later fetches are appended without rewriting earlier files.
+/// Frequency partitions use the query values of [`Frequency`] (`M`, `Q`,
+/// `FY`, ...) and `unknown` when the series reports no recognizable
+/// frequency.
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::parquet::ParquetStore;
+/// use boj_client::query::{CodeQuery, MetadataQuery};
+///
+/// let client = BojClient::new()?;
+/// let store = ParquetStore::new("boj-lake");
+///
+/// let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])?;
+/// store.write_code_response(&client.get_data_code_all(&query)?)?;
+/// store.write_metadata(&client.get_metadata(&MetadataQuery::new("FM08")?)?)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParquetStore {
+    root: PathBuf,
+}
+
+impl ParquetStore {
+    /// Creates a store rooted at `root`. Directories are created on write.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Returns the root directory of the store.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Appends the observations of `response`, one file per frequency
+    /// partition, and returns the written file paths.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParquetError`] when the response does not echo its DB, a
+    /// value is not a number, or writing fails.
+    pub fn write_code_response(
+        &self,
+        response: &CodeResponse,
+    ) -> Result<Vec<PathBuf>, ParquetError> {
+        self.write_observations(
+            response.parameter.db.as_deref(),
+            &response.meta,
+            response.series.iter().map(SeriesRef::new),
+        )
+    }
+
+    /// Appends the observations of `response`, one file per frequency
+    /// partition, and returns the written file paths.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParquetError`] when the response does not echo its DB, a
+    /// value is not a number, or writing fails.
+    pub fn write_layer_response(
+        &self,
+        response: &LayerResponse,
+    ) -> Result<Vec<PathBuf>, ParquetError> {
+        self.write_observations(
+            response.parameter.db.as_deref(),
+            &response.meta,
+            response.series.iter().map(SeriesRef::new),
+        )
+    }
+
+    /// Appends every metadata row of `response` (hierarchy rows included) and
+    /// returns the written file path, or `None` when the response is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParquetError`] when the DB code is unusable as a partition
+    /// name or writing fails.
+    pub fn write_metadata(
+        &self,
+        response: &MetadataResponse,
+    ) -> Result<Option<PathBuf>, ParquetError> {
+        if response.entries.is_empty() {
+            return Ok(None);
+        }
+        let db = partition_value(Some(&response.db))?;
+        let entries = &response.entries;
+        let text = |field: fn(&MetadataEntry) -> Option<&str>| -> ArrayRef {
+            Arc::new(entries.iter().map(field).collect::<StringArray>())
+        };
+        let layer = |field: fn(&MetadataEntry) -> Option<u32>| -> ArrayRef {
+            Arc::new(entries.iter().map(field).collect::<UInt32Array>())
+        };
+
+        let columns = vec![
+            text(|entry| entry.series_code.as_deref()),
+            text(|entry| entry.name_of_time_series_j.as_deref()),
+            text(|entry| entry.name_of_time_series.as_deref()),
+            text(|entry| entry.unit_j.as_deref()),
+            text(|entry| entry.unit.as_deref()),
+            text(|entry| entry.frequency.as_deref()),
+            text(|entry| entry.category_j.as_deref()),
+            text(|entry| entry.category.as_deref()),
+            layer(|entry| entry.layer1),
+            layer(|entry| entry.layer2),
+            layer(|entry| entry.layer3),
+            layer(|entry| entry.layer4),
+            layer(|entry| entry.layer5),
+            text(|entry| entry.start_of_the_time_series.as_deref()),
+            text(|entry| entry.end_of_the_time_series.as_deref()),
+            text(|entry| entry.last_update.as_deref()),
+            text(|entry| entry.notes_j.as_deref()),
+            text(|entry| entry.notes.as_deref()),
+            Arc::new(StringArray::from(vec![
+                response.meta.date.as_deref();
+                entries.len()
+            ])),
+        ];
+        let batch = RecordBatch::try_new(metadata_schema(), columns)?;
+
+        let dir = self.root.join(METADATA_DIR).join(format!("db={db}"));
+        write_part(&dir, &batch).map(Some)
+    }
+
+    fn write_observations<'a>(
+        &self,
+        db: Option<&str>,
+        meta: &ResponseMeta,
+        series: impl Iterator<Item = SeriesRef<'a>>,
+    ) -> Result<Vec<PathBuf>, ParquetError> {
+        let db = partition_value(db)?;
+        let mut partitions = BTreeMap::<&str, Vec<SeriesRef<'a>>>::new();
+        for series in series {
+            let frequency = series.label(FREQUENCY_KEY).and_then(Frequency::from_label);
+            let key = frequency.map_or(UNKNOWN_FREQUENCY, Frequency::as_query_value);
+            partitions.entry(key).or_default().push(series);
+        }
+
+        let mut written = Vec::new();
+        for (key, series) in partitions {
+            let frequency = Frequency::from_label(key);
+            let batch = observation_batch(&series, frequency, meta.date.as_deref())?;
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let dir = self
+                .root
+                .join(OBSERVATIONS_DIR)
+                .join(format!("db={db}"))
+                .join(format!("frequency={key}"));
+            written.push(write_part(&dir, &batch)?);
+        }
+        Ok(written)
+    }
+}
+
+fn observation_batch(
+    series: &[SeriesRef<'_>],
+    frequency: Option<Frequency>,
+    retrieved_at: Option<&str>,
+) -> Result<RecordBatch, ParquetError> {
+    let mut codes = Vec::new();
+    let mut periods = Vec::new();
+    let mut starts = Vec::new();
+    let mut ends = Vec::new();
+    let mut values = Vec::new();
+    let mut last_updates = Vec::new();
+
+    for series in series {
+        for point in series.points {
+            let span = period_span(&point.survey_date, frequency);
+            codes.push(series.series_code);
+            periods.push(point.survey_date.as_str());
+            starts.push(span.map(|span| days_since_epoch(span.first)));
+            ends.push(span.map(|span| days_since_epoch(span.last)));
+            values.push(parse_value(series.series_code, point)?);
+            last_updates.push(series.label(LAST_UPDATE_KEY));
+        }
+    }
+
+    let rows = codes.len();
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(StringArray::from(codes)),
+        Arc::new(StringArray::from(periods)),
+        Arc::new(Date32Array::from(starts)),
+        Arc::new(Date32Array::from(ends)),
+        Arc::new(Float64Array::from(values)),
+        Arc::new(StringArray::from(last_updates)),
+        Arc::new(StringArray::from(vec![retrieved_at; rows])),
+    ];
+    Ok(RecordBatch::try_new(observation_schema(), columns)?)
+}
+
+/// Validates a DB code before it is used as a directory name.
+fn partition_value(db: Option<&str>) -> Result<&str, ParquetError> {
+    let db = db
+        .filter(|db| !db.is_empty())
+        .ok_or_else(|| ParquetError::General("response does not echo a DB code".to_string()))?;
+    if !db
+        .bytes()
+        .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_')
+    {
+        return Err(ParquetError::General(format!(
+            "DB code {db:?} cannot be used as a partition name"
+        )));
+    }
+    Ok(db)
+}
+
+/// Writes `batch` to the next unused `part-NNNNN.parquet` file in `dir`.
+fn write_part(dir: &Path, batch: &RecordBatch) -> Result<PathBuf, ParquetError> {
+    fs::create_dir_all(dir)?;
+    let mut next = 0;
+    for entry in fs::read_dir(dir)? {
+        let name = entry?.file_name();
+        let index = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("part-"))
+            .and_then(|name| name.strip_suffix(".parquet"))
+            .and_then(|index| index.parse::<u32>().ok());
+        if let Some(index) = index {
+            next = next.max(index + 1);
+        }
+    }
+
+    let path = dir.join(format!("part-{next:05}.parquet"));
+    let file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)?;
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .build();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(properties))?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(path)
+}
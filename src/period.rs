@@ -0,0 +1,189 @@
+use crate::query::Frequency;
+
+/// Inclusive calendar span of one BOJ period, as `YYYYMMDD` integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DaySpan {
+    pub(crate) first: u32,
+    pub(crate) last: u32,
+}
+
+/// Resolves a BOJ period label (`2025`, `202503`, `20250317`, ...) into the
+/// calendar days it covers.
+///
+/// `frequency` decides how six-digit labels are read (`202502` is February for
+/// monthly series, the second quarter for quarterly series, and October to
+/// March for fiscal half-years). Without a frequency the label length decides:
+/// four digits are a calendar year, six digits a month and eight digits a day.
+pub(crate) fn period_span(label: &str, frequency: Option<Frequency>) -> Option<DaySpan> {
+    let label = label.trim();
+    if !label.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let year = label.get(..4)?.parse::<u32>().ok()?;
+    let sub = label.get(4..6).map(str::parse::<u32>).transpose().ok()?;
+
+    let (first_month, months) = match (label.len(), frequency) {
+        (4, Some(Frequency::Fy)) => ((year, 4), 12),
+        (4, _) => ((year, 1), 12),
+        (6, Some(Frequency::Ch)) => (half_start(year, sub?, 1)?, 6),
+        (6, Some(Frequency::Fh)) => (half_start(year, sub?, 4)?, 6),
+        (6, Some(Frequency::Q)) => {
+            let quarter = sub?;
+            if !(1..=4).contains(&quarter) {
+                return None;
+            }
+            ((year, (quarter - 1) * 3 + 1), 3)
+        }
+        (6, _) => {
+            let month = sub?;
+            if !(1..=12).contains(&month) {
+                return None;
+            }
+            ((year, month), 1)
+        }
+        (8, _) => {
+            let month = sub?;
+            let day = label[6..8].parse::<u32>().ok()?;
+            if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+                return None;
+            }
+            let date = ymd(year, month, day);
+            return Some(DaySpan {
+                first: date,
+                last: date,
+            });
+        }
+        _ => return None,
+    };
+
+    let (start_year, start_month) = first_month;
+    let end_index = start_year * 12 + start_month - 1 + months - 1;
+    let (end_year, end_month) = (end_index / 12, end_index % 12 + 1);
+    Some(DaySpan {
+        first: ymd(start_year, start_month, 1),
+        last: ymd(end_year, end_month, days_in_month(end_year, end_month)),
+    })
+}
+
+fn half_start(year: u32, half: u32, first_month: u32) -> Option<(u32, u32)> {
+    match half {
+        1 => Some((year, first_month)),
+        2 => {
+            let index = year * 12 + first_month - 1 + 6;
+            Some((index / 12, index % 12 + 1))
+        }
+        _ => None,
+    }
+}
+
+/// Converts a `YYYYMMDD` integer into days since 1970-01-01.
+pub(crate) fn days_since_epoch(date: u32) -> i32 {
+    let (year, month, day) = (
+        (date / 10_000) as i32,
+        (date / 100 % 100) as i32,
+        (date % 100) as i32,
+    );
+    // Days from civil algorithm with March-based years.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Converts days since 1970-01-01 back into a `YYYYMMDD` integer.
+pub(crate) fn date_from_days(days: i32) -> u32 {
+    // Civil from days algorithm, the inverse of `days_since_epoch`.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i32::from(month <= 2);
+    ymd(year as u32, month as u32, day as u32)
+}
+
+/// Day of the week of days since 1970-01-01, from 0 (Monday) to 6 (Sunday).
+pub(crate) fn weekday(days: i32) -> u32 {
+    // 1970-01-01 was a Thursday.
+    (days + 3).rem_euclid(7) as u32
+}
+
+pub(crate) fn ymd(year: u32, month: u32, day: u32) -> u32 {
+    year * 10_000 + month * 100 + day
+}
+
+/// Formats a `YYYYMMDD` integer as `YYYY-MM-DD`.
+#[cfg_attr(not(any(feature = "duckdb", feature = "sdmx")), allow(dead_code))]
+pub(crate) fn iso_date(date: u32) -> String {
+    format!(
+        "{:04}-{:02}-{:02}",
+        date / 10_000,
+        date / 100 % 100,
+        date % 100
+    )
+}
+
+pub(crate) fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DaySpan, date_from_days, days_since_epoch, period_span};
+    use crate::query::Frequency;
+
+    #[test]
+    fn resolves_period_labels_by_frequency() {
+        let span = |first, last| Some(DaySpan { first, last });
+
+        assert_eq!(
+            period_span("2023", Some(Frequency::Fy)),
+            span(20230401, 20240331)
+        );
+        assert_eq!(
+            period_span("202302", Some(Frequency::Fh)),
+            span(20231001, 20240331)
+        );
+        assert_eq!(
+            period_span("202402", Some(Frequency::Q)),
+            span(20240401, 20240630)
+        );
+        assert_eq!(period_span("202402", None), span(20240201, 20240229));
+        assert_eq!(period_span("20240315", None), span(20240315, 20240315));
+        assert_eq!(period_span("202405", Some(Frequency::Q)), None);
+        assert_eq!(period_span("2024-03", None), None);
+    }
+
+    #[test]
+    fn counts_days_since_epoch() {
+        assert_eq!(days_since_epoch(19700101), 0);
+        assert_eq!(days_since_epoch(20000301), 11_017);
+        assert_eq!(days_since_epoch(20240229), 19_782);
+        assert_eq!(days_since_epoch(19691231), -1);
+    }
+
+    #[test]
+    fn converts_days_back_to_dates() {
+        for date in [19700101, 19691231, 20000229, 20000301, 20240229, 21001231] {
+            assert_eq!(date_from_days(days_since_epoch(date)), date);
+        }
+    }
+}
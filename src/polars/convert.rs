@@ -0,0 +1,162 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use polars::prelude::{Column, DataFrame, PolarsError, PolarsResult};
+
+use crate::model::{CodeResponse, DataPoint, LayerResponse, TimeSeries};
+
+impl CodeResponse {
+    /// Converts the series into a wide Polars [`DataFrame`].
+    ///
+    /// The first column is `period` (`String`) holding every survey date found
+    /// in any series, sorted ascending, so it acts as the frame's index. Each
+    /// series becomes one nullable `Float64` column named by its series code;
+    /// missing [`DataPoint::value`]s and periods a series does not report are
+    /// null. Names and units are available from
+    /// [`CodeResponse::to_polars_metadata`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolarsError`] when a value is not a number or series codes
+    /// are duplicated.
+    pub fn to_polars(&self) -> PolarsResult<DataFrame> {
+        wide_frame(&self.series)
+    }
+
+    /// Converts the series into a long Polars [`DataFrame`] with the columns
+    /// `series_code`, `period` and `value` (nullable `Float64`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolarsError`] when a value is not a number.
+    pub fn to_polars_long(&self) -> PolarsResult<DataFrame> {
+        long_frame(&self.series)
+    }
+
+    /// Returns one row per series with its labels.
+    ///
+    /// Columns are `series_code`, `name_of_time_series_j`,
+    /// `name_of_time_series`, `unit_j`, `unit`, `frequency`, `category_j`,
+    /// `category` and `last_update`, all `String` with nulls for missing
+    /// labels.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolarsError`] when the frame cannot be built.
+    pub fn to_polars_metadata(&self) -> PolarsResult<DataFrame> {
+        metadata_frame(&self.series)
+    }
+}
+
+impl LayerResponse {
+    /// Converts the series into a wide Polars [`DataFrame`].
+    ///
+    /// See [`CodeResponse::to_polars`] for the layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolarsError`] when a value is not a number or series codes
+    /// are duplicated.
+    pub fn to_polars(&self) -> PolarsResult<DataFrame> {
+        wide_frame(&self.series)
+    }
+
+    /// Converts the series into a long Polars [`DataFrame`].
+    ///
+    /// See [`CodeResponse::to_polars_long`] for the layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolarsError`] when a value is not a number.
+    pub fn to_polars_long(&self) -> PolarsResult<DataFrame> {
+        long_frame(&self.series)
+    }
+
+    /// Returns one row per series with its labels.
+    ///
+    /// See [`CodeResponse::to_polars_metadata`] for the layout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PolarsError`] when the frame cannot be built.
+    pub fn to_polars_metadata(&self) -> PolarsResult<DataFrame> {
+        metadata_frame(&self.series)
+    }
+}
+
+fn wide_frame<T: TimeSeries>(series: &[T]) -> PolarsResult<DataFrame> {
+    let periods = series
+        .iter()
+        .flat_map(|series| series.points())
+        .map(|point| point.survey_date.as_str())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    let rows = periods
+        .iter()
+        .enumerate()
+        .map(|(row, period)| (*period, row))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut columns = Vec::with_capacity(series.len() + 1);
+    columns.push(Column::new("period".into(), &periods));
+    for series in series {
+        let mut values = vec![None; periods.len()];
+        for point in series.points() {
+            values[rows[point.survey_date.as_str()]] = parse_value(series.series_code(), point)?;
+        }
+        columns.push(Column::new(series.series_code().into(), values));
+    }
+    DataFrame::new(periods.len(), columns)
+}
+
+fn long_frame<T: TimeSeries>(series: &[T]) -> PolarsResult<DataFrame> {
+    let mut codes = Vec::new();
+    let mut periods = Vec::new();
+    let mut values = Vec::new();
+    for series in series {
+        for point in series.points() {
+            codes.push(series.series_code());
+            periods.push(point.survey_date.as_str());
+            values.push(parse_value(series.series_code(), point)?);
+        }
+    }
+
+    let height = codes.len();
+    DataFrame::new(
+        height,
+        vec![
+            Column::new("series_code".into(), codes),
+            Column::new("period".into(), periods),
+            Column::new("value".into(), values),
+        ],
+    )
+}
+
+fn metadata_frame<T: TimeSeries>(series: &[T]) -> PolarsResult<DataFrame> {
+    let text = |name: &str, field: fn(&T) -> Option<&str>| {
+        Column::new(name.into(), series.iter().map(field).collect::<Vec<_>>())
+    };
+    DataFrame::new(
+        series.len(),
+        vec![
+            Column::new(
+                "series_code".into(),
+                series.iter().map(T::series_code).collect::<Vec<_>>(),
+            ),
+            text("name_of_time_series_j", T::name_j),
+            text("name_of_time_series", T::name),
+            text("unit_j", T::unit_j),
+            text("unit", T::unit),
+            text("frequency", T::frequency),
+            text("category_j", T::category_j),
+            text("category", T::category),
+            text("last_update", T::last_update),
+        ],
+    )
+}
+
+fn parse_value(series_code: &str, point: &DataPoint) -> PolarsResult<Option<f64>> {
+    point
+        .numeric_value()
+        .map_err(|error| PolarsError::ComputeError(format!("series {series_code}: {error}").into()))
+}
@@ -0,0 +1,3 @@
+mod convert;
+
+pub use polars::prelude::{DataFrame, PolarsError};
@@ -1,3 +1,6 @@
+use serde::Serialize;
+
+use crate::client::build_url;
 use crate::error::BojError;
 
 use super::options::{CsvEncoding, Format, Language};
@@ -25,14 +28,22 @@ use super::validation::{validate_code, validate_date_generic, validate_date_orde
 ///     .with_end_date("202402")?;
 /// # Ok::<(), boj_client::error::BojError>(())
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// It serializes to JSON with the parameter names used by the MCP
+/// `boj_get_data_code` tool, so a query can be saved or handed over as is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct CodeQuery {
     db: String,
     codes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     format: Option<Format>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     lang: Option<Language>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     start_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     end_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     start_position: Option<u32>,
 }
 
@@ -189,10 +200,43 @@ impl CodeQuery {
         Ok(self)
     }
 
+    /// Returns the `getDataCode` request URL of this query on `base_url`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boj_client::query::{CodeQuery, Format};
+    ///
+    /// let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])?.with_format(Format::Json);
+    /// assert_eq!(
+    ///     query.to_url("https://www.stat-search.boj.or.jp"),
+    ///     "https://www.stat-search.boj.or.jp/api/v1/getDataCode?format=json&db=FM08&code=FXERD01"
+    /// );
+    /// # Ok::<(), boj_client::error::BojError>(())
+    /// ```
+    pub fn to_url(&self, base_url: &str) -> String {
+        build_url(base_url, self.endpoint(), &self.query_pairs())
+    }
+
     pub(crate) fn endpoint(&self) -> &'static str {
         "/api/v1/getDataCode"
     }
 
+    #[cfg_attr(not(feature = "store"), allow(dead_code))]
+    pub(crate) fn db(&self) -> &str {
+        &self.db
+    }
+
+    #[cfg_attr(not(feature = "store"), allow(dead_code))]
+    pub(crate) fn codes(&self) -> &[String] {
+        &self.codes
+    }
+
+    #[cfg_attr(not(feature = "store"), allow(dead_code))]
+    pub(crate) fn date_range(&self) -> (Option<&str>, Option<&str>) {
+        (self.start_date.as_deref(), self.end_date.as_deref())
+    }
+
     pub(crate) fn query_pairs(&self) -> Vec<(String, String)> {
         let mut pairs = Vec::new();
         if let Some(format) = self.format {
@@ -1,28 +1,11 @@
 use crate::error::BojError;
+use crate::model::LayerSelector;
 
 use super::options::{CsvEncoding, Format, Frequency, Language};
 use super::validation::{
     parse_layer_value, validate_date_for_frequency, validate_date_order, validate_db,
 };
 
-/// A layer selector value for `getDataLayer`.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub(super) enum LayerValue {
-    /// Select all values in the layer (`*`).
-    Wildcard,
-    /// Select a specific positive layer index.
-    Index(u32),
-}
-
-impl LayerValue {
-    fn as_query_value(&self) -> String {
-        match self {
-            Self::Wildcard => "*".to_string(),
-            Self::Index(value) => value.to_string(),
-        }
-    }
-}
-
 /// Query builder for the `getDataLayer` endpoint.
 ///
 /// Constraints enforced at build time:
@@ -53,7 +36,7 @@ impl LayerValue {
 pub struct LayerQuery {
     db: String,
     frequency: Frequency,
-    layer: Vec<LayerValue>,
+    layer: Vec<LayerSelector>,
     format: Option<Format>,
     lang: Option<Language>,
     start_date: Option<String>,
@@ -239,7 +222,7 @@ impl LayerQuery {
             "layer".to_string(),
             self.layer
                 .iter()
-                .map(LayerValue::as_query_value)
+                .map(ToString::to_string)
                 .collect::<Vec<_>>()
                 .join(","),
         ));
@@ -9,6 +9,7 @@ pub use layer::LayerQuery;
 pub use metadata::MetadataQuery;
 pub(crate) use options::CsvEncoding;
 pub use options::{Format, Frequency, Language};
+pub(crate) use validation::parse_layer_value;
 
 #[cfg(test)]
 mod tests {
@@ -58,7 +58,7 @@ impl Language {
 }
 
 /// Frequency selector used by the `getDataLayer` endpoint.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Frequency {
     /// Calendar year frequency (`CY`).
     Cy,
@@ -101,6 +101,65 @@ impl Frequency {
             Self::D => "D",
         }
     }
+
+    /// Returns the number of periods in a year, or `None` for daily series
+    /// whose observations follow business days.
+    ///
+    /// Weekly series count 52 weeks a year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boj_client::query::Frequency;
+    ///
+    /// assert_eq!(Frequency::Q.periods_per_year(), Some(4));
+    /// assert_eq!(Frequency::Fh.periods_per_year(), Some(2));
+    /// assert_eq!(Frequency::D.periods_per_year(), None);
+    /// ```
+    pub fn periods_per_year(self) -> Option<u32> {
+        match self {
+            Self::Cy | Self::Fy => Some(1),
+            Self::Ch | Self::Fh => Some(2),
+            Self::Q => Some(4),
+            Self::M => Some(12),
+            Self::W => Some(52),
+            Self::D => None,
+        }
+    }
+
+    /// Parses a frequency label reported in response `FREQUENCY` fields.
+    ///
+    /// Accepts both the long labels used in response payloads (`MONTHLY`,
+    /// `ANNUAL(MAR)`, `WEEKLY(MONDAY)`, ...) and the short query values
+    /// (`M`, `FY`, ...), ignoring ASCII case and surrounding whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boj_client::query::Frequency;
+    ///
+    /// assert_eq!(Frequency::from_label("QUARTERLY"), Some(Frequency::Q));
+    /// assert_eq!(Frequency::from_label("ANNUAL(MAR)"), Some(Frequency::Fy));
+    /// assert_eq!(Frequency::from_label("SEMIANNUAL(SEP)"), Some(Frequency::Fh));
+    /// assert_eq!(Frequency::from_label("weekly(friday)"), Some(Frequency::W));
+    /// assert_eq!(Frequency::from_label("unknown"), None);
+    /// ```
+    pub fn from_label(label: &str) -> Option<Self> {
+        let label = label.trim().to_ascii_uppercase();
+        let frequency = match label.as_str() {
+            "CY" | "ANNUAL" => Self::Cy,
+            "FY" | "ANNUAL(MAR)" => Self::Fy,
+            "CH" | "SEMIANNUAL" => Self::Ch,
+            "FH" | "SEMIANNUAL(SEP)" => Self::Fh,
+            "Q" | "QUARTERLY" => Self::Q,
+            "M" | "MONTHLY" => Self::M,
+            "D" | "DAILY" => Self::D,
+            "W" => Self::W,
+            _ if label.starts_with("WEEKLY") => Self::W,
+            _ => return None,
+        };
+        Some(frequency)
+    }
 }
 
 /// Expected CSV character encoding used by decoders.
@@ -1,6 +1,6 @@
 use crate::error::BojError;
+use crate::model::LayerSelector;
 
-use super::layer::LayerValue;
 use super::options::Frequency;
 
 const FORBIDDEN_ASCII_CHARS: [char; 8] = ['<', '>', '!', '|', '\\', ';', '\'', '"'];
@@ -23,10 +23,10 @@ pub(super) fn validate_code(value: &str) -> Result<(), BojError> {
     Ok(())
 }
 
-pub(super) fn parse_layer_value(value: &str) -> Result<LayerValue, BojError> {
+pub(crate) fn parse_layer_value(value: &str) -> Result<LayerSelector, BojError> {
     validate_ascii_parameter("LAYER", value)?;
     if value == "*" {
-        return Ok(LayerValue::Wildcard);
+        return Ok(LayerSelector::Wildcard);
     }
     let number = value
         .parse::<u32>()
@@ -36,7 +36,7 @@ pub(super) fn parse_layer_value(value: &str) -> Result<LayerValue, BojError> {
             "LAYER value must be '*' or a positive integer",
         ));
     }
-    Ok(LayerValue::Index(number))
+    Ok(LayerSelector::Index(number))
 }
 
 fn validate_ascii_parameter(name: &str, value: &str) -> Result<(), BojError> {
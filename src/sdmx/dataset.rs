@@ -0,0 +1,265 @@
+use crate::model::{CodeResponse, LayerResponse, MetadataResponse, TimeSeries};
+use crate::query::Frequency;
+
+use super::error::SdmxError;
+use super::structure::SERIES_ATTRIBUTES;
+
+/// One observation of an [`SdmxSeries`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SdmxObservation {
+    /// BOJ period label (`202401`, `20240104`, ...).
+    pub period: String,
+    /// Observed value; `None` when BOJ omits the value.
+    pub value: Option<f64>,
+}
+
+/// One BOJ series with the labels carried as SDMX series attributes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SdmxSeries {
+    /// Time-series code (`SERIES_CODE` dimension).
+    pub series_code: String,
+    /// Frequency (`FREQ` dimension).
+    pub frequency: Frequency,
+    /// Japanese time-series name (`NAME_J`).
+    pub name_j: Option<String>,
+    /// English time-series name (`NAME`).
+    pub name: Option<String>,
+    /// Japanese unit label (`UNIT_J`).
+    pub unit_j: Option<String>,
+    /// English unit label (`UNIT`).
+    pub unit: Option<String>,
+    /// Japanese category label (`CATEGORY_J`).
+    pub category_j: Option<String>,
+    /// English category label (`CATEGORY`).
+    pub category: Option<String>,
+    /// Last update from BOJ (`LAST_UPDATE`).
+    pub last_update: Option<String>,
+    /// Japanese notes from `getMetadata` (`NOTES_J`).
+    pub notes_j: Option<String>,
+    /// English notes from `getMetadata` (`NOTES`).
+    pub notes: Option<String>,
+    /// Observations in BOJ period order.
+    pub observations: Vec<SdmxObservation>,
+}
+
+impl SdmxSeries {
+    /// Returns the series attributes in [`SERIES_ATTRIBUTES`] order.
+    pub(super) fn attributes(&self) -> impl Iterator<Item = (&'static str, Option<&str>)> {
+        let values = [
+            self.name_j.as_deref(),
+            self.name.as_deref(),
+            self.unit_j.as_deref(),
+            self.unit.as_deref(),
+            self.category_j.as_deref(),
+            self.category.as_deref(),
+            self.last_update.as_deref(),
+            self.notes_j.as_deref(),
+            self.notes.as_deref(),
+        ];
+        SERIES_ATTRIBUTES.iter().map(|(id, _)| *id).zip(values)
+    }
+
+    /// Returns the field holding attribute `id`, if it is one of
+    /// [`SERIES_ATTRIBUTES`].
+    pub(super) fn attribute_mut(&mut self, id: &str) -> Option<&mut Option<String>> {
+        let field = match id {
+            "NAME_J" => &mut self.name_j,
+            "NAME" => &mut self.name,
+            "UNIT_J" => &mut self.unit_j,
+            "UNIT" => &mut self.unit,
+            "CATEGORY_J" => &mut self.category_j,
+            "CATEGORY" => &mut self.category,
+            "LAST_UPDATE" => &mut self.last_update,
+            "NOTES_J" => &mut self.notes_j,
+            "NOTES" => &mut self.notes,
+            _ => return None,
+        };
+        Some(field)
+    }
+
+    pub(super) fn empty(series_code: String, frequency: Frequency) -> Self {
+        Self {
+            series_code,
+            frequency,
+            name_j: None,
+            name: None,
+            unit_j: None,
+            unit: None,
+            category_j: None,
+            category: None,
+            last_update: None,
+            notes_j: None,
+            notes: None,
+            observations: Vec::new(),
+        }
+    }
+}
+
+/// BOJ series of one DB prepared for SDMX data messages.
+///
+/// Build it from a code or layer response, optionally enrich it with notes
+/// from `getMetadata`, then write it with
+/// [`write_sdmx_json`](Self::write_sdmx_json) or
+/// [`write_sdmx_ml`](Self::write_sdmx_ml). The matching data structure
+/// definition is written by [`write_structure`](super::write_structure).
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::query::{CodeQuery, MetadataQuery};
+/// use boj_client::sdmx::SdmxDataSet;
+///
+/// let client = BojClient::new()?;
+/// let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])?;
+/// let metadata = client.get_metadata(&MetadataQuery::new("FM08")?)?;
+///
+/// let dataset = SdmxDataSet::from_code_response(&client.get_data_code_all(&query)?)?
+///     .with_metadata(&metadata);
+/// let file = std::fs::File::create("fm08.xml")?;
+/// dataset.write_sdmx_ml(file)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SdmxDataSet {
+    /// BOJ DB code (`DB` dimension).
+    pub db: String,
+    /// Preparation timestamp written to the message header.
+    pub prepared: Option<String>,
+    /// Series in output order.
+    pub series: Vec<SdmxSeries>,
+}
+
+impl SdmxDataSet {
+    /// Collects `series` of `db`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when a series has no recognizable frequency or a
+    /// value is not a number.
+    pub fn from_series<'a, T: TimeSeries + 'a>(
+        db: impl Into<String>,
+        series: impl IntoIterator<Item = &'a T>,
+    ) -> Result<Self, SdmxError> {
+        let series = series
+            .into_iter()
+            .map(|series| {
+                let frequency = series
+                    .frequency()
+                    .and_then(Frequency::from_label)
+                    .ok_or_else(|| SdmxError::UnknownFrequency {
+                        series_code: series.series_code().to_string(),
+                    })?;
+                let observations = series
+                    .points()
+                    .iter()
+                    .map(|point| {
+                        let value =
+                            point
+                                .numeric_value()
+                                .map_err(|error| SdmxError::InvalidValue {
+                                    series_code: series.series_code().to_string(),
+                                    message: error.to_string(),
+                                })?;
+                        Ok(SdmxObservation {
+                            period: point.survey_date.clone(),
+                            value,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, SdmxError>>()?;
+
+                let owned = |value: Option<&str>| value.map(str::to_string);
+                Ok(SdmxSeries {
+                    name_j: owned(series.name_j()),
+                    name: owned(series.name()),
+                    unit_j: owned(series.unit_j()),
+                    unit: owned(series.unit()),
+                    category_j: owned(series.category_j()),
+                    category: owned(series.category()),
+                    last_update: owned(series.last_update()),
+                    observations,
+                    ..SdmxSeries::empty(series.series_code().to_string(), frequency)
+                })
+            })
+            .collect::<Result<Vec<_>, SdmxError>>()?;
+
+        Ok(Self {
+            db: db.into(),
+            prepared: None,
+            series,
+        })
+    }
+
+    /// Collects the series of a `getDataCode` response, using its echoed DB
+    /// and response date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when the response does not echo its DB, a series
+    /// has no recognizable frequency, or a value is not a number.
+    pub fn from_code_response(response: &CodeResponse) -> Result<Self, SdmxError> {
+        let db = echoed_db(response.parameter.db.as_deref())?;
+        let dataset = Self::from_series(db, &response.series)?;
+        Ok(dataset.with_prepared_date(response.meta.date.as_deref()))
+    }
+
+    /// Collects the series of a `getDataLayer` response, using its echoed DB
+    /// and response date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when the response does not echo its DB, a series
+    /// has no recognizable frequency, or a value is not a number.
+    pub fn from_layer_response(response: &LayerResponse) -> Result<Self, SdmxError> {
+        let db = echoed_db(response.parameter.db.as_deref())?;
+        let dataset = Self::from_series(db, &response.series)?;
+        Ok(dataset.with_prepared_date(response.meta.date.as_deref()))
+    }
+
+    /// Sets the preparation timestamp written to the message header.
+    pub fn with_prepared(mut self, prepared: impl Into<String>) -> Self {
+        self.prepared = Some(prepared.into());
+        self
+    }
+
+    /// Fills notes, and labels the data response left empty, from the
+    /// `getMetadata` rows with the same series codes.
+    pub fn with_metadata(mut self, metadata: &MetadataResponse) -> Self {
+        for series in &mut self.series {
+            let Some(entry) = metadata
+                .entries
+                .iter()
+                .find(|entry| entry.series_code.as_deref() == Some(series.series_code.as_str()))
+            else {
+                continue;
+            };
+            let labels = [
+                (&mut series.name_j, &entry.name_of_time_series_j),
+                (&mut series.name, &entry.name_of_time_series),
+                (&mut series.unit_j, &entry.unit_j),
+                (&mut series.unit, &entry.unit),
+                (&mut series.category_j, &entry.category_j),
+                (&mut series.category, &entry.category),
+                (&mut series.last_update, &entry.last_update),
+                (&mut series.notes_j, &entry.notes_j),
+                (&mut series.notes, &entry.notes),
+            ];
+            for (field, value) in labels {
+                if field.is_none() {
+                    field.clone_from(value);
+                }
+            }
+        }
+        self
+    }
+
+    fn with_prepared_date(mut self, date: Option<&str>) -> Self {
+        self.prepared = date.map(str::to_string);
+        self
+    }
+}
+
+fn echoed_db(db: Option<&str>) -> Result<&str, SdmxError> {
+    db.filter(|db| !db.is_empty())
+        .ok_or_else(|| SdmxError::invalid("response does not echo a DB code"))
+}
@@ -0,0 +1,51 @@
+use std::io;
+
+use thiserror::Error;
+
+/// Error returned by SDMX writers and readers.
+#[derive(Debug, Error)]
+pub enum SdmxError {
+    /// Writing to the destination failed.
+    #[error("SDMX I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// SDMX-JSON could not be serialized or parsed.
+    #[error("SDMX-JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// SDMX-ML could not be parsed.
+    #[error("SDMX-ML error: {0}")]
+    Xml(String),
+
+    /// A series value is present but not a number.
+    #[error("invalid value in series {series_code}: {message}")]
+    InvalidValue {
+        /// Code of the series holding the value.
+        series_code: String,
+        /// Description of the rejected value.
+        message: String,
+    },
+
+    /// A series reports no frequency that maps to the `FREQ` codelist.
+    #[error("series {series_code} has no recognizable frequency")]
+    UnknownFrequency {
+        /// Code of the series.
+        series_code: String,
+    },
+
+    /// A message does not follow the layout written by this crate.
+    #[error("invalid SDMX message: {0}")]
+    InvalidMessage(String),
+}
+
+impl SdmxError {
+    pub(super) fn invalid(message: impl Into<String>) -> Self {
+        Self::InvalidMessage(message.into())
+    }
+}
+
+impl From<quick_xml::Error> for SdmxError {
+    fn from(error: quick_xml::Error) -> Self {
+        Self::Xml(error.to_string())
+    }
+}
@@ -0,0 +1,336 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+
+use serde_json::{Map, Value, json};
+
+use crate::catalog;
+use crate::query::Frequency;
+
+use super::AGENCY_ID;
+use super::dataset::{SdmxDataSet, SdmxObservation, SdmxSeries};
+use super::error::SdmxError;
+use super::ml::message_id;
+use super::structure::{
+    REPORTING_YEAR_START_DAY, SERIES_ATTRIBUTES, SERIES_DIMENSIONS, TIME_PERIOD, frequency_label,
+};
+use super::time::{from_sdmx_period, period_bounds, reporting_year_start_day, to_sdmx_period};
+
+impl SdmxDataSet {
+    /// Writes the series as an SDMX-JSON 1.0 data message.
+    ///
+    /// Series are keyed by `DB:SERIES_CODE:FREQ` value indices and
+    /// observations by `TIME_PERIOD` value index, as the SDMX-JSON format
+    /// prescribes; missing values are `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when writing fails.
+    pub fn write_sdmx_json<W: Write>(&self, writer: W) -> Result<(), SdmxError> {
+        serde_json::to_writer_pretty(writer, &self.sdmx_json())?;
+        Ok(())
+    }
+
+    /// Returns the SDMX-JSON message written by
+    /// [`write_sdmx_json`](Self::write_sdmx_json) as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when serialization fails.
+    pub fn to_sdmx_json(&self) -> Result<String, SdmxError> {
+        Ok(serde_json::to_string_pretty(&self.sdmx_json())?)
+    }
+
+    /// Reads an SDMX-JSON data message written by
+    /// [`write_sdmx_json`](Self::write_sdmx_json).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when the JSON is malformed or does not use the
+    /// `BOJ_TS` dimensions.
+    pub fn from_sdmx_json(json: &str) -> Result<Self, SdmxError> {
+        let message = serde_json::from_str::<Value>(json)?;
+        let structure = &message["structure"];
+        let dimensions = structure["dimensions"]["series"]
+            .as_array()
+            .ok_or_else(|| SdmxError::invalid("missing series dimensions"))?;
+        let position = |id: &str| {
+            dimensions
+                .iter()
+                .position(|dimension| dimension["id"] == id)
+                .ok_or_else(|| SdmxError::invalid(format!("missing dimension {id}")))
+        };
+        let (db_position, code_position, freq_position) =
+            (position("DB")?, position("SERIES_CODE")?, position("FREQ")?);
+        let dimension_values = dimensions
+            .iter()
+            .map(|dimension| value_texts(&dimension["values"], "id"))
+            .collect::<Result<Vec<_>, _>>()?;
+        let periods = structure["dimensions"]["observation"]
+            .as_array()
+            .and_then(|dimensions| {
+                dimensions
+                    .iter()
+                    .find(|dimension| dimension["id"] == TIME_PERIOD.0)
+            })
+            .ok_or_else(|| SdmxError::invalid("missing TIME_PERIOD dimension"))?;
+        let periods = value_texts(&periods["values"], "id")?;
+        let attributes = structure["attributes"]["series"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|attribute| {
+                let id = attribute["id"].as_str().unwrap_or_default().to_string();
+                Ok((id, value_texts(&attribute["values"], "name")?))
+            })
+            .collect::<Result<Vec<_>, SdmxError>>()?;
+
+        let mut db = None;
+        let mut series = BTreeMap::new();
+        let entries = message["dataSets"][0]["series"]
+            .as_object()
+            .ok_or_else(|| SdmxError::invalid("missing dataSets[0].series"))?;
+        for (key, body) in entries {
+            let indices = key
+                .split(':')
+                .map(str::parse::<usize>)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| SdmxError::invalid(format!("invalid series key {key:?}")))?;
+            let value = |position: usize| {
+                indices
+                    .get(position)
+                    .and_then(|index| dimension_values[position].get(*index))
+                    .ok_or_else(|| SdmxError::invalid(format!("series key {key:?} out of range")))
+            };
+            let series_db = value(db_position)?;
+            if db.get_or_insert(series_db) != &series_db {
+                return Err(SdmxError::invalid(format!(
+                    "series key {key:?} belongs to DB {series_db}"
+                )));
+            }
+            let series_code = value(code_position)?.clone();
+            let frequency = Frequency::from_label(value(freq_position)?).ok_or_else(|| {
+                SdmxError::UnknownFrequency {
+                    series_code: series_code.clone(),
+                }
+            })?;
+
+            let mut parsed = SdmxSeries::empty(series_code, frequency);
+            let attribute_indices = body["attributes"].as_array().map(Vec::as_slice);
+            for ((id, values), index) in
+                attributes.iter().zip(attribute_indices.unwrap_or_default())
+            {
+                let text = index.as_u64().and_then(|index| values.get(index as usize));
+                if let (Some(field), Some(text)) = (parsed.attribute_mut(id), text) {
+                    *field = Some(text.clone());
+                }
+            }
+            parsed.observations = read_observations(&body["observations"], &periods, frequency)?;
+            // Series are written in SERIES_CODE value order.
+            series.insert(indices[code_position], parsed);
+        }
+
+        Ok(Self {
+            db: db.cloned().unwrap_or_default(),
+            prepared: message["header"]["prepared"].as_str().map(str::to_string),
+            series: series.into_values().collect(),
+        })
+    }
+
+    fn sdmx_json(&self) -> Value {
+        let mut periods = BTreeMap::<String, Option<(String, String)>>::new();
+        let mut frequencies = Vec::<Frequency>::new();
+        for series in &self.series {
+            if !frequencies.contains(&series.frequency) {
+                frequencies.push(series.frequency);
+            }
+            for observation in &series.observations {
+                periods
+                    .entry(to_sdmx_period(&observation.period, series.frequency))
+                    .or_insert_with(|| period_bounds(&observation.period, series.frequency));
+            }
+        }
+        let period_index = periods
+            .keys()
+            .enumerate()
+            .map(|(index, period)| (period.clone(), index))
+            .collect::<BTreeMap<_, _>>();
+
+        let attribute_ids = std::iter::once(REPORTING_YEAR_START_DAY)
+            .chain(SERIES_ATTRIBUTES)
+            .collect::<Vec<_>>();
+        let mut attribute_values = vec![Vec::<&str>::new(); attribute_ids.len()];
+        let mut series_entries = Map::new();
+        for (index, series) in self.series.iter().enumerate() {
+            let texts = std::iter::once(reporting_year_start_day(series.frequency))
+                .chain(series.attributes().map(|(_, value)| value));
+            let attributes = texts
+                .zip(&mut attribute_values)
+                .map(|(text, values)| match text {
+                    Some(text) => json!(intern(values, text)),
+                    None => Value::Null,
+                })
+                .collect::<Vec<_>>();
+            let observations = series
+                .observations
+                .iter()
+                .map(|observation| {
+                    let period = to_sdmx_period(&observation.period, series.frequency);
+                    (
+                        period_index[&period].to_string(),
+                        json!([observation.value]),
+                    )
+                })
+                .collect::<Map<_, _>>();
+            let frequency = frequencies
+                .iter()
+                .position(|frequency| *frequency == series.frequency)
+                .unwrap_or_default();
+            series_entries.insert(
+                format!("0:{index}:{frequency}"),
+                json!({ "attributes": attributes, "observations": observations }),
+            );
+        }
+
+        let db_name = catalog::find_db(&self.db).map_or(self.db.as_str(), |entry| entry.name_ja);
+        let dimension_values = [
+            vec![json!({ "id": self.db, "name": db_name })],
+            self.series
+                .iter()
+                .map(|series| {
+                    let name = series
+                        .name_j
+                        .as_deref()
+                        .or(series.name.as_deref())
+                        .unwrap_or(&series.series_code);
+                    json!({ "id": series.series_code, "name": name })
+                })
+                .collect(),
+            frequencies
+                .iter()
+                .map(|frequency| {
+                    json!({ "id": frequency.as_query_value(), "name": frequency_label(*frequency) })
+                })
+                .collect(),
+        ];
+        let series_dimensions = SERIES_DIMENSIONS
+            .iter()
+            .zip(dimension_values)
+            .enumerate()
+            .map(|(position, ((id, name), values))| {
+                json!({ "id": id, "name": name, "keyPosition": position, "values": values })
+            })
+            .collect::<Vec<_>>();
+        let period_values = periods
+            .iter()
+            .map(|(period, bounds)| {
+                let mut value = json!({ "id": period, "name": period });
+                if let Some((start, end)) = bounds {
+                    value["start"] = json!(start);
+                    value["end"] = json!(end);
+                }
+                value
+            })
+            .collect::<Vec<_>>();
+        let series_attributes = attribute_ids
+            .iter()
+            .zip(&attribute_values)
+            .map(|((id, name), values)| {
+                let values = values
+                    .iter()
+                    .map(|value| json!({ "name": value }))
+                    .collect::<Vec<_>>();
+                json!({ "id": id, "name": name, "values": values })
+            })
+            .collect::<Vec<_>>();
+
+        let mut header = json!({
+            "id": message_id(&self.db),
+            "test": false,
+            "sender": { "id": AGENCY_ID },
+        });
+        if let Some(prepared) = &self.prepared {
+            header["prepared"] = json!(prepared);
+        }
+        json!({
+            "header": header,
+            "dataSets": [{ "action": "Information", "series": series_entries }],
+            "structure": {
+                "name": db_name,
+                "dimensions": {
+                    "dataSet": [],
+                    "series": series_dimensions,
+                    "observation": [{
+                        "id": TIME_PERIOD.0,
+                        "name": TIME_PERIOD.1,
+                        "keyPosition": SERIES_DIMENSIONS.len(),
+                        "role": "time",
+                        "values": period_values,
+                    }],
+                },
+                "attributes": {
+                    "dataSet": [],
+                    "series": series_attributes,
+                    "observation": [],
+                },
+            },
+        })
+    }
+}
+
+/// Returns the index of `text` in `values`, appending it when missing.
+fn intern<'a>(values: &mut Vec<&'a str>, text: &'a str) -> usize {
+    match values.iter().position(|value| *value == text) {
+        Some(index) => index,
+        None => {
+            values.push(text);
+            values.len() - 1
+        }
+    }
+}
+
+fn value_texts(values: &Value, field: &str) -> Result<Vec<String>, SdmxError> {
+    values
+        .as_array()
+        .ok_or_else(|| SdmxError::invalid("component without values"))?
+        .iter()
+        .map(|value| {
+            value[field]
+                .as_str()
+                .map(str::to_string)
+                .ok_or_else(|| SdmxError::invalid(format!("component value without {field}")))
+        })
+        .collect()
+}
+
+fn read_observations(
+    observations: &Value,
+    periods: &[String],
+    frequency: Frequency,
+) -> Result<Vec<SdmxObservation>, SdmxError> {
+    let Some(observations) = observations.as_object() else {
+        return Ok(Vec::new());
+    };
+    let mut parsed = BTreeMap::new();
+    for (key, body) in observations {
+        let period = key
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| Some((index, periods.get(index)?)))
+            .ok_or_else(|| SdmxError::invalid(format!("invalid observation key {key:?}")))?;
+        let value = match &body[0] {
+            Value::Null => None,
+            value => Some(value.as_f64().ok_or_else(|| {
+                SdmxError::invalid(format!("observation value {value} is not a number"))
+            })?),
+        };
+        parsed.insert(
+            period.0,
+            SdmxObservation {
+                period: from_sdmx_period(period.1, frequency),
+                value,
+            },
+        );
+    }
+    Ok(parsed.into_values().collect())
+}
@@ -0,0 +1,312 @@
+use std::borrow::Cow;
+use std::io::{self, Write};
+
+use quick_xml::escape::escape;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesDecl, BytesStart, BytesText, Event};
+use quick_xml::name::QName;
+use quick_xml::{Reader, Writer, XmlVersion};
+
+use crate::query::Frequency;
+
+use super::dataset::{SdmxDataSet, SdmxObservation, SdmxSeries};
+use super::error::SdmxError;
+use super::structure::{OBS_VALUE, REPORTING_YEAR_START_DAY, TIME_PERIOD};
+use super::time::{from_sdmx_period, reporting_year_start_day, to_sdmx_period};
+use super::{AGENCY_ID, DSD_ID, DSD_VERSION};
+
+pub(super) const MESSAGE_NS: &str = "http://www.sdmx.org/resources/sdmxml/schemas/v2_1/message";
+pub(super) const COMMON_NS: &str = "http://www.sdmx.org/resources/sdmxml/schemas/v2_1/common";
+pub(super) const STRUCTURE_NS: &str = "http://www.sdmx.org/resources/sdmxml/schemas/v2_1/structure";
+const STRUCTURE_SPECIFIC_NS: &str =
+    "http://www.sdmx.org/resources/sdmxml/schemas/v2_1/data/structurespecific";
+const XSI_NS: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Written for missing observation values, following SDMX practice.
+const MISSING_VALUE: &str = "NaN";
+
+impl SdmxDataSet {
+    /// Writes the series as an SDMX-ML 2.1 StructureSpecific data message.
+    ///
+    /// Each series is a `Series` element carrying the `DB`, `SERIES_CODE` and
+    /// `FREQ` dimensions and its labels as attributes; each observation is an
+    /// `Obs` element with `TIME_PERIOD` and `OBS_VALUE` (`NaN` when missing).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when writing fails.
+    pub fn write_sdmx_ml<W: Write>(&self, writer: W) -> Result<(), SdmxError> {
+        let mut xml = Writer::new_with_indent(writer, b' ', 2);
+        let namespace = data_structure_namespace();
+        xml.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+        xml.create_element("message:StructureSpecificData")
+            .with_attribute(("xmlns:message", MESSAGE_NS))
+            .with_attribute(("xmlns:common", COMMON_NS))
+            .with_attribute(("xmlns:ss", STRUCTURE_SPECIFIC_NS))
+            .with_attribute(("xmlns:xsi", XSI_NS))
+            .with_attribute(("xmlns:ns1", namespace.as_str()))
+            .write_inner_content(|xml| {
+                write_header(xml, &message_id(&self.db), self.prepared.as_deref(), true)?;
+                xml.create_element("message:DataSet")
+                    .with_attribute(("ss:dataScope", "DataStructure"))
+                    .with_attribute(("xsi:type", "ns1:DataSetType"))
+                    .with_attribute(("ss:structureRef", DSD_ID))
+                    .write_inner_content(|xml| {
+                        for series in &self.series {
+                            self.write_series(xml, series)?;
+                        }
+                        Ok(())
+                    })?;
+                Ok(())
+            })?;
+        xml.into_inner().flush()?;
+        Ok(())
+    }
+
+    /// Returns the SDMX-ML message written by
+    /// [`write_sdmx_ml`](Self::write_sdmx_ml) as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when writing fails.
+    pub fn to_sdmx_ml(&self) -> Result<String, SdmxError> {
+        let mut output = Vec::new();
+        self.write_sdmx_ml(&mut output)?;
+        String::from_utf8(output).map_err(|error| SdmxError::Xml(error.to_string()))
+    }
+
+    /// Reads an SDMX-ML StructureSpecific data message written by
+    /// [`write_sdmx_ml`](Self::write_sdmx_ml).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SdmxError`] when the XML is malformed or a series lacks its
+    /// dimensions.
+    pub fn from_sdmx_ml(xml: &str) -> Result<Self, SdmxError> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut db = None;
+        let mut prepared = None;
+        let mut in_prepared = false;
+        let mut series = Vec::<SdmxSeries>::new();
+        loop {
+            match reader.read_event()? {
+                Event::Start(element) | Event::Empty(element) => {
+                    match element.local_name().as_ref() {
+                        "Prepared" => in_prepared = true,
+                        "Series" => {
+                            let (series_db, parsed) = read_series(&element)?;
+                            match &db {
+                                Some(db) if *db != series_db => {
+                                    return Err(SdmxError::invalid(format!(
+                                        "series {} belongs to DB {series_db}, expected {db}",
+                                        parsed.series_code
+                                    )));
+                                }
+                                Some(_) => {}
+                                None => db = Some(series_db),
+                            }
+                            series.push(parsed);
+                        }
+                        "Obs" => {
+                            let current = series
+                                .last_mut()
+                                .ok_or_else(|| SdmxError::invalid("Obs outside of Series"))?;
+                            let observation = read_observation(&element, current.frequency)?;
+                            current.observations.push(observation);
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Text(text) if in_prepared => {
+                    prepared = Some(text.xml_content(XmlVersion::Implicit1_0).into_owned());
+                }
+                Event::End(element) if element.local_name().as_ref() == "Prepared" => {
+                    in_prepared = false;
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            db: db.unwrap_or_default(),
+            prepared,
+            series,
+        })
+    }
+
+    fn write_series<W: Write>(&self, xml: &mut Writer<W>, series: &SdmxSeries) -> io::Result<()> {
+        let mut element = xml
+            .create_element("Series")
+            .with_attribute(attribute("DB", &self.db))
+            .with_attribute(attribute("SERIES_CODE", &series.series_code))
+            .with_attribute(attribute("FREQ", series.frequency.as_query_value()));
+        if let Some(start) = reporting_year_start_day(series.frequency) {
+            element = element.with_attribute(attribute(REPORTING_YEAR_START_DAY.0, start));
+        }
+        for (id, value) in series.attributes() {
+            if let Some(value) = value {
+                element = element.with_attribute(attribute(id, value));
+            }
+        }
+        element.write_inner_content(|xml| {
+            for observation in &series.observations {
+                let value = observation
+                    .value
+                    .map_or_else(|| MISSING_VALUE.to_string(), |value| value.to_string());
+                xml.create_element("Obs")
+                    .with_attribute(attribute(
+                        TIME_PERIOD.0,
+                        &to_sdmx_period(&observation.period, series.frequency),
+                    ))
+                    .with_attribute(attribute(OBS_VALUE.0, &value))
+                    .write_empty()?;
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+}
+
+/// Writes the message header shared by data and structure messages. Data
+/// messages also reference the data structure definition.
+pub(super) fn write_header<W: Write>(
+    xml: &mut Writer<W>,
+    id: &str,
+    prepared: Option<&str>,
+    data_message: bool,
+) -> io::Result<()> {
+    xml.create_element("message:Header")
+        .write_inner_content(|xml| {
+            xml.create_element("message:ID")
+                .write_text_content(BytesText::new(id))?;
+            xml.create_element("message:Test")
+                .write_text_content(BytesText::new("false"))?;
+            if let Some(prepared) = prepared {
+                xml.create_element("message:Prepared")
+                    .write_text_content(BytesText::new(prepared))?;
+            }
+            xml.create_element("message:Sender")
+                .with_attribute(("id", AGENCY_ID))
+                .write_empty()?;
+            if data_message {
+                xml.create_element("message:Structure")
+                    .with_attribute(("structureID", DSD_ID))
+                    .with_attribute(("namespace", data_structure_namespace().as_str()))
+                    .with_attribute(("dimensionAtObservation", TIME_PERIOD.0))
+                    .write_inner_content(|xml| {
+                        xml.create_element("common:Structure")
+                            .write_inner_content(|xml| {
+                                xml.create_element("Ref")
+                                    .with_attribute(("agencyID", AGENCY_ID))
+                                    .with_attribute(("id", DSD_ID))
+                                    .with_attribute(("version", DSD_VERSION))
+                                    .write_empty()?;
+                                Ok(())
+                            })?;
+                        Ok(())
+                    })?;
+            }
+            Ok(())
+        })?;
+    Ok(())
+}
+
+/// Returns the header `ID` of the data message for `db`.
+pub(super) fn message_id(db: &str) -> String {
+    format!("{AGENCY_ID}_{db}")
+}
+
+fn data_structure_namespace() -> String {
+    format!(
+        "urn:sdmx:org.sdmx.infomodel.datastructure.DataStructure={AGENCY_ID}:{DSD_ID}({DSD_VERSION}):ObsLevelDim:{}",
+        TIME_PERIOD.0
+    )
+}
+
+/// Builds an attribute whose line breaks and tabs survive attribute value
+/// normalization.
+fn attribute<'a>(key: &'a str, value: &str) -> Attribute<'a> {
+    let value = escape(value)
+        .replace('\r', "&#13;")
+        .replace('\n', "&#10;")
+        .replace('\t', "&#9;");
+    Attribute {
+        key: QName(key),
+        value: Cow::Owned(value),
+    }
+}
+
+fn attributes(element: &BytesStart<'_>) -> Result<Vec<(String, String)>, SdmxError> {
+    element
+        .attributes()
+        .map(|attribute| {
+            let attribute = attribute.map_err(|error| SdmxError::Xml(error.to_string()))?;
+            let value = attribute.normalized_value(XmlVersion::Implicit1_0)?;
+            Ok((
+                attribute.key.local_name().as_ref().to_string(),
+                value.into_owned(),
+            ))
+        })
+        .collect()
+}
+
+fn read_series(element: &BytesStart<'_>) -> Result<(String, SdmxSeries), SdmxError> {
+    let mut db = None;
+    let mut series_code = None;
+    let mut frequency = None;
+    let mut labels = Vec::new();
+    for (key, value) in attributes(element)? {
+        match key.as_str() {
+            "DB" => db = Some(value),
+            "SERIES_CODE" => series_code = Some(value),
+            "FREQ" => frequency = Some(value),
+            _ => labels.push((key, value)),
+        }
+    }
+
+    let series_code =
+        series_code.ok_or_else(|| SdmxError::invalid("Series without SERIES_CODE"))?;
+    let frequency = frequency
+        .as_deref()
+        .and_then(Frequency::from_label)
+        .ok_or_else(|| SdmxError::UnknownFrequency {
+            series_code: series_code.clone(),
+        })?;
+    let db = db.ok_or_else(|| SdmxError::invalid(format!("series {series_code} without DB")))?;
+
+    let mut series = SdmxSeries::empty(series_code, frequency);
+    for (key, value) in labels {
+        if let Some(field) = series.attribute_mut(&key) {
+            *field = Some(value);
+        }
+    }
+    Ok((db, series))
+}
+
+fn read_observation(
+    element: &BytesStart<'_>,
+    frequency: Frequency,
+) -> Result<SdmxObservation, SdmxError> {
+    let mut period = None;
+    let mut value = None;
+    for (key, text) in attributes(element)? {
+        match key.as_str() {
+            "TIME_PERIOD" => period = Some(text),
+            "OBS_VALUE" if text != MISSING_VALUE => {
+                let number = text.parse::<f64>().map_err(|_| {
+                    SdmxError::invalid(format!("OBS_VALUE {text:?} is not a number"))
+                })?;
+                value = Some(number);
+            }
+            _ => {}
+        }
+    }
+    let period = period.ok_or_else(|| SdmxError::invalid("Obs without TIME_PERIOD"))?;
+    Ok(SdmxObservation {
+        period: from_sdmx_period(&period, frequency),
+        value,
+    })
+}
@@ -0,0 +1,17 @@
+mod dataset;
+mod error;
+mod json;
+mod ml;
+mod structure;
+mod time;
+
+pub use dataset::{SdmxDataSet, SdmxObservation, SdmxSeries};
+pub use error::SdmxError;
+pub use structure::{Code, Codelist, db_codelist, frequency_codelist, write_structure};
+
+/// Agency ID of every generated SDMX artefact.
+pub const AGENCY_ID: &str = "BOJ";
+/// ID of the generated data structure definition.
+pub const DSD_ID: &str = "BOJ_TS";
+/// Version of the generated data structure definition.
+pub const DSD_VERSION: &str = "1.0";
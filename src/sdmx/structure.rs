@@ -0,0 +1,355 @@
+use std::io::{self, Write};
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesText, Event};
+
+use crate::catalog;
+use crate::query::Frequency;
+
+use super::error::SdmxError;
+use super::ml::{COMMON_NS, MESSAGE_NS, STRUCTURE_NS, write_header};
+use super::{AGENCY_ID, DSD_ID, DSD_VERSION};
+
+pub(super) const DB_CODELIST_ID: &str = "CL_BOJ_DB";
+pub(super) const FREQ_CODELIST_ID: &str = "CL_BOJ_FREQ";
+const CONCEPT_SCHEME_ID: &str = "CS_BOJ_TS";
+
+/// Series dimensions in key order, with concept names.
+pub(super) const SERIES_DIMENSIONS: [(&str, &str); 3] = [
+    ("DB", "BOJ database"),
+    ("SERIES_CODE", "BOJ series code"),
+    ("FREQ", "Frequency"),
+];
+
+/// Uncoded series attributes, with concept names.
+pub(super) const SERIES_ATTRIBUTES: [(&str, &str); 9] = [
+    ("NAME_J", "Series name (Japanese)"),
+    ("NAME", "Series name (English)"),
+    ("UNIT_J", "Unit (Japanese)"),
+    ("UNIT", "Unit (English)"),
+    ("CATEGORY_J", "Category (Japanese)"),
+    ("CATEGORY", "Category (English)"),
+    ("LAST_UPDATE", "Last update"),
+    ("NOTES_J", "Notes (Japanese)"),
+    ("NOTES", "Notes (English)"),
+];
+
+pub(super) const REPORTING_YEAR_START_DAY: (&str, &str) =
+    ("REPORTING_YEAR_START_DAY", "Reporting year start day");
+pub(super) const TIME_PERIOD: (&str, &str) = ("TIME_PERIOD", "Time period");
+pub(super) const OBS_VALUE: (&str, &str) = ("OBS_VALUE", "Observation value");
+
+/// One code of a [`Codelist`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Code {
+    /// Code ID.
+    pub id: &'static str,
+    /// Code name.
+    pub name: &'static str,
+    /// Optional description.
+    pub description: Option<&'static str>,
+}
+
+/// Codelist referenced by the generated data structure definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Codelist {
+    /// Codelist ID.
+    pub id: &'static str,
+    /// Codelist name.
+    pub name: &'static str,
+    /// `xml:lang` of names and descriptions.
+    pub language: &'static str,
+    /// Codes in catalog order.
+    pub codes: Vec<Code>,
+}
+
+/// Returns the `DB` codelist built from [`catalog::databases`], with the
+/// statistics category as description.
+pub fn db_codelist() -> Codelist {
+    Codelist {
+        id: DB_CODELIST_ID,
+        name: "日本銀行 時系列統計データベース",
+        language: "ja",
+        codes: catalog::databases()
+            .iter()
+            .map(|entry| Code {
+                id: entry.code,
+                name: entry.name_ja,
+                description: Some(entry.category_ja),
+            })
+            .collect(),
+    }
+}
+
+/// Returns the `FREQ` codelist built from [`catalog::frequency_codes`], named
+/// with the frequency labels BOJ reports on series.
+pub fn frequency_codelist() -> Codelist {
+    Codelist {
+        id: FREQ_CODELIST_ID,
+        name: "BOJ frequency",
+        language: "en",
+        codes: catalog::frequency_codes()
+            .iter()
+            .filter_map(|code| Frequency::from_label(code))
+            .map(|frequency| Code {
+                id: frequency.as_query_value(),
+                name: frequency_label(frequency),
+                description: None,
+            })
+            .collect(),
+    }
+}
+
+/// Returns the label BOJ reports for `frequency` in series `FREQUENCY` fields.
+pub(super) fn frequency_label(frequency: Frequency) -> &'static str {
+    match frequency {
+        Frequency::Cy => "ANNUAL",
+        Frequency::Fy => "ANNUAL(MAR)",
+        Frequency::Ch => "SEMIANNUAL",
+        Frequency::Fh => "SEMIANNUAL(SEP)",
+        Frequency::Q => "QUARTERLY",
+        Frequency::M => "MONTHLY",
+        Frequency::W => "WEEKLY",
+        Frequency::D => "DAILY",
+    }
+}
+
+/// Writes an SDMX-ML 2.1 structure message with the `BOJ_TS` data structure
+/// definition, its concept scheme and the [`db_codelist`] and
+/// [`frequency_codelist`] codelists.
+///
+/// Data messages written by [`SdmxDataSet`](super::SdmxDataSet) reference this
+/// definition.
+///
+/// # Errors
+///
+/// Returns [`SdmxError`] when writing fails.
+pub fn write_structure<W: Write>(writer: W) -> Result<(), SdmxError> {
+    let mut xml = Writer::new_with_indent(writer, b' ', 2);
+    xml.write_event(Event::Decl(BytesDecl::new("1.0", Some("UTF-8"), None)))?;
+    xml.create_element("message:Structure")
+        .with_attribute(("xmlns:message", MESSAGE_NS))
+        .with_attribute(("xmlns:common", COMMON_NS))
+        .with_attribute(("xmlns:structure", STRUCTURE_NS))
+        .write_inner_content(|xml| {
+            write_header(
+                xml,
+                &format!("{DSD_ID}_STRUCTURE"),
+                Some(catalog::snapshot().source_date),
+                false,
+            )?;
+            xml.create_element("message:Structures")
+                .write_inner_content(|xml| {
+                    xml.create_element("structure:Codelists")
+                        .write_inner_content(|xml| {
+                            write_codelist(xml, &db_codelist())?;
+                            write_codelist(xml, &frequency_codelist())
+                        })?;
+                    xml.create_element("structure:Concepts")
+                        .write_inner_content(write_concepts)?;
+                    xml.create_element("structure:DataStructures")
+                        .write_inner_content(write_data_structure)?;
+                    Ok(())
+                })?;
+            Ok(())
+        })?;
+    xml.into_inner().flush()?;
+    Ok(())
+}
+
+fn write_codelist<W: Write>(xml: &mut Writer<W>, codelist: &Codelist) -> io::Result<()> {
+    maintainable(xml, "structure:Codelist", codelist.id).write_inner_content(|xml| {
+        write_name(xml, "common:Name", codelist.language, codelist.name)?;
+        for code in &codelist.codes {
+            xml.create_element("structure:Code")
+                .with_attribute(("id", code.id))
+                .write_inner_content(|xml| {
+                    write_name(xml, "common:Name", codelist.language, code.name)?;
+                    if let Some(description) = code.description {
+                        write_name(xml, "common:Description", codelist.language, description)?;
+                    }
+                    Ok(())
+                })?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn write_concepts<W: Write>(xml: &mut Writer<W>) -> io::Result<()> {
+    maintainable(xml, "structure:ConceptScheme", CONCEPT_SCHEME_ID).write_inner_content(|xml| {
+        write_name(xml, "common:Name", "en", "BOJ time-series concepts")?;
+        let concepts = SERIES_DIMENSIONS
+            .iter()
+            .chain([&TIME_PERIOD, &OBS_VALUE, &REPORTING_YEAR_START_DAY])
+            .chain(SERIES_ATTRIBUTES.iter());
+        for (id, name) in concepts {
+            xml.create_element("structure:Concept")
+                .with_attribute(("id", *id))
+                .write_inner_content(|xml| write_name(xml, "common:Name", "en", name))?;
+        }
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn write_data_structure<W: Write>(xml: &mut Writer<W>) -> io::Result<()> {
+    maintainable(xml, "structure:DataStructure", DSD_ID).write_inner_content(|xml| {
+        write_name(xml, "common:Name", "en", "BOJ time-series statistics")?;
+        xml.create_element("structure:DataStructureComponents")
+            .write_inner_content(|xml| {
+                xml.create_element("structure:DimensionList")
+                    .with_attribute(("id", "DimensionDescriptor"))
+                    .write_inner_content(|xml| {
+                        for (position, (id, _)) in SERIES_DIMENSIONS.iter().enumerate() {
+                            let codelist = match *id {
+                                "DB" => Some(DB_CODELIST_ID),
+                                "FREQ" => Some(FREQ_CODELIST_ID),
+                                _ => None,
+                            };
+                            xml.create_element("structure:Dimension")
+                                .with_attribute(("id", *id))
+                                .with_attribute(("position", (position + 1).to_string().as_str()))
+                                .write_inner_content(|xml| {
+                                    write_concept_identity(xml, id)?;
+                                    write_representation(xml, codelist)
+                                })?;
+                        }
+                        xml.create_element("structure:TimeDimension")
+                            .with_attribute(("id", TIME_PERIOD.0))
+                            .with_attribute((
+                                "position",
+                                (SERIES_DIMENSIONS.len() + 1).to_string().as_str(),
+                            ))
+                            .write_inner_content(|xml| {
+                                write_concept_identity(xml, TIME_PERIOD.0)?;
+                                write_text_format(xml, "ObservationalTimePeriod")
+                            })?;
+                        Ok(())
+                    })?;
+                xml.create_element("structure:AttributeList")
+                    .with_attribute(("id", "AttributeDescriptor"))
+                    .write_inner_content(|xml| {
+                        let attributes = std::iter::once(&REPORTING_YEAR_START_DAY)
+                            .chain(SERIES_ATTRIBUTES.iter());
+                        for (id, _) in attributes {
+                            xml.create_element("structure:Attribute")
+                                .with_attribute(("id", *id))
+                                .with_attribute(("assignmentStatus", "Conditional"))
+                                .write_inner_content(|xml| {
+                                    write_concept_identity(xml, id)?;
+                                    write_representation(xml, None)?;
+                                    xml.create_element("structure:AttributeRelationship")
+                                        .write_inner_content(|xml| {
+                                            for (dimension, _) in SERIES_DIMENSIONS {
+                                                xml.create_element("structure:Dimension")
+                                                    .write_inner_content(|xml| {
+                                                        xml.create_element("Ref")
+                                                            .with_attribute(("id", dimension))
+                                                            .write_empty()?;
+                                                        Ok(())
+                                                    })?;
+                                            }
+                                            Ok(())
+                                        })?;
+                                    Ok(())
+                                })?;
+                        }
+                        Ok(())
+                    })?;
+                xml.create_element("structure:MeasureList")
+                    .with_attribute(("id", "MeasureDescriptor"))
+                    .write_inner_content(|xml| {
+                        xml.create_element("structure:PrimaryMeasure")
+                            .with_attribute(("id", OBS_VALUE.0))
+                            .write_inner_content(|xml| {
+                                write_concept_identity(xml, OBS_VALUE.0)?;
+                                write_text_format(xml, "Double")
+                            })?;
+                        Ok(())
+                    })?;
+                Ok(())
+            })?;
+        Ok(())
+    })?;
+    Ok(())
+}
+
+fn maintainable<'a, W: Write>(
+    xml: &'a mut Writer<W>,
+    element: &'a str,
+    id: &'a str,
+) -> quick_xml::ElementWriter<'a, W> {
+    xml.create_element(element)
+        .with_attribute(("agencyID", AGENCY_ID))
+        .with_attribute(("id", id))
+        .with_attribute(("version", DSD_VERSION))
+}
+
+fn write_name<W: Write>(
+    xml: &mut Writer<W>,
+    element: &str,
+    language: &str,
+    text: &str,
+) -> io::Result<()> {
+    xml.create_element(element)
+        .with_attribute(("xml:lang", language))
+        .write_text_content(BytesText::new(text))?;
+    Ok(())
+}
+
+fn write_concept_identity<W: Write>(xml: &mut Writer<W>, id: &str) -> io::Result<()> {
+    xml.create_element("structure:ConceptIdentity")
+        .write_inner_content(|xml| {
+            maintainable_ref(xml, CONCEPT_SCHEME_ID)
+                .with_attribute(("maintainableParentVersion", DSD_VERSION))
+                .with_attribute(("id", id))
+                .with_attribute(("class", "Concept"))
+                .write_empty()?;
+            Ok(())
+        })?;
+    Ok(())
+}
+
+fn write_representation<W: Write>(xml: &mut Writer<W>, codelist: Option<&str>) -> io::Result<()> {
+    match codelist {
+        Some(codelist) => {
+            xml.create_element("structure:LocalRepresentation")
+                .write_inner_content(|xml| {
+                    xml.create_element("structure:Enumeration")
+                        .write_inner_content(|xml| {
+                            xml.create_element("Ref")
+                                .with_attribute(("agencyID", AGENCY_ID))
+                                .with_attribute(("id", codelist))
+                                .with_attribute(("version", DSD_VERSION))
+                                .with_attribute(("class", "Codelist"))
+                                .write_empty()?;
+                            Ok(())
+                        })?;
+                    Ok(())
+                })?;
+            Ok(())
+        }
+        None => write_text_format(xml, "String"),
+    }
+}
+
+fn write_text_format<W: Write>(xml: &mut Writer<W>, text_type: &str) -> io::Result<()> {
+    xml.create_element("structure:LocalRepresentation")
+        .write_inner_content(|xml| {
+            xml.create_element("structure:TextFormat")
+                .with_attribute(("textType", text_type))
+                .write_empty()?;
+            Ok(())
+        })?;
+    Ok(())
+}
+
+fn maintainable_ref<'a, W: Write>(
+    xml: &'a mut Writer<W>,
+    parent: &'a str,
+) -> quick_xml::ElementWriter<'a, W> {
+    xml.create_element("Ref")
+        .with_attribute(("agencyID", AGENCY_ID))
+        .with_attribute(("maintainableParentID", parent))
+}
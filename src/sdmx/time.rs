@@ -0,0 +1,105 @@
+use crate::period::{iso_date, period_span};
+use crate::query::Frequency;
+
+/// Converts a BOJ period label into an SDMX `TIME_PERIOD` value.
+///
+/// Calendar periods use the SDMX basic formats (`2024`, `2024-S1`,
+/// `2024-Q3`, `2024-01`, `2024-01-04`). Fiscal years and fiscal half-years use
+/// reporting periods (`2024-A1`, `2024-S2`) whose year starts on the day given
+/// by the `REPORTING_YEAR_START_DAY` attribute. Labels that do not match the
+/// frequency are passed through unchanged.
+pub(super) fn to_sdmx_period(label: &str, frequency: Frequency) -> String {
+    let label = label.trim();
+    if period_span(label, Some(frequency)).is_none() {
+        return label.to_string();
+    }
+    let (year, sub) = (&label[..4], label.get(4..6));
+    match (frequency, label.len(), sub) {
+        (Frequency::Cy, 4, _) => year.to_string(),
+        (Frequency::Fy, 4, _) => format!("{year}-A1"),
+        (Frequency::Ch | Frequency::Fh, 6, Some(half)) => format!("{year}-S{}", &half[1..]),
+        (Frequency::Q, 6, Some(quarter)) => format!("{year}-Q{}", &quarter[1..]),
+        (Frequency::M, 6, Some(month)) => format!("{year}-{month}"),
+        (Frequency::W | Frequency::D, 8, Some(month)) => {
+            format!("{year}-{month}-{}", &label[6..8])
+        }
+        _ => label.to_string(),
+    }
+}
+
+/// Converts an SDMX `TIME_PERIOD` value back into a BOJ period label.
+///
+/// Values that [`to_sdmx_period`] would not produce for `frequency` are
+/// passed through unchanged.
+pub(super) fn from_sdmx_period(period: &str, frequency: Frequency) -> String {
+    let label = match frequency {
+        Frequency::Cy => Some(period.to_string()),
+        Frequency::Fy => period.strip_suffix("-A1").map(str::to_string),
+        Frequency::Ch | Frequency::Fh => split_period(period, "-S"),
+        Frequency::Q => split_period(period, "-Q"),
+        Frequency::M | Frequency::W | Frequency::D => Some(period.replace('-', "")),
+    };
+    match label {
+        Some(label) if to_sdmx_period(&label, frequency) == period => label,
+        _ => period.to_string(),
+    }
+}
+
+/// Returns the first and last instant of a BOJ period as ISO 8601 date-times.
+pub(super) fn period_bounds(label: &str, frequency: Frequency) -> Option<(String, String)> {
+    let span = period_span(label, Some(frequency))?;
+    Some((
+        format!("{}T00:00:00", iso_date(span.first)),
+        format!("{}T23:59:59", iso_date(span.last)),
+    ))
+}
+
+/// Returns the `REPORTING_YEAR_START_DAY` of fiscal frequencies, whose
+/// years start in April.
+pub(super) fn reporting_year_start_day(frequency: Frequency) -> Option<&'static str> {
+    matches!(frequency, Frequency::Fy | Frequency::Fh).then_some("--04-01")
+}
+
+fn split_period(period: &str, separator: &str) -> Option<String> {
+    let (year, index) = period.split_once(separator)?;
+    let index = index.parse::<u32>().ok()?;
+    Some(format!("{year}{index:02}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_sdmx_period, period_bounds, to_sdmx_period};
+    use crate::query::Frequency;
+
+    #[test]
+    fn converts_periods_both_ways() {
+        let cases = [
+            ("2024", Frequency::Cy, "2024"),
+            ("2024", Frequency::Fy, "2024-A1"),
+            ("202402", Frequency::Ch, "2024-S2"),
+            ("202401", Frequency::Fh, "2024-S1"),
+            ("202503", Frequency::Q, "2025-Q3"),
+            ("202401", Frequency::M, "2024-01"),
+            ("20240105", Frequency::W, "2024-01-05"),
+            ("20240104", Frequency::D, "2024-01-04"),
+        ];
+        for (label, frequency, period) in cases {
+            assert_eq!(to_sdmx_period(label, frequency), period);
+            assert_eq!(from_sdmx_period(period, frequency), label);
+        }
+
+        assert_eq!(to_sdmx_period("202405", Frequency::Q), "202405");
+        assert_eq!(from_sdmx_period("2024-Q5", Frequency::Q), "2024-Q5");
+    }
+
+    #[test]
+    fn resolves_fiscal_period_bounds() {
+        assert_eq!(
+            period_bounds("2024", Frequency::Fy),
+            Some((
+                "2024-04-01T00:00:00".to_string(),
+                "2025-03-31T23:59:59".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::model::{MetadataEntry, MetadataResponse};
+
+use super::normalize::{index_tokens, tokenize};
+use super::query::SearchQuery;
+
+const INDEX_FORMAT_VERSION: u32 = 1;
+
+const CODE_WEIGHT: u8 = 5;
+const NAME_WEIGHT: u8 = 3;
+const CATEGORY_WEIGHT: u8 = 2;
+const UNIT_WEIGHT: u8 = 1;
+const NOTES_WEIGHT: u8 = 1;
+
+/// Full-text search index over series rows of `getMetadata` responses.
+///
+/// Japanese and English names, categories, units and notes are searchable.
+/// Text is normalized before indexing (full-width ASCII to half-width,
+/// half-width katakana to full-width, ASCII lowercase), and Japanese text is
+/// indexed as character bigrams and unigrams so queries do not depend on word
+/// boundaries and single characters such as `円` match inside words.
+///
+/// Only rows with a series code are indexed; hierarchy header rows are
+/// skipped. Adding a response for a series that is already indexed replaces
+/// the stored row, so refreshed metadata can be merged into an existing index.
+/// The index can be written to disk with [`SearchIndex::save`] and read back
+/// with [`SearchIndex::load`] instead of being rebuilt.
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::query::{Frequency, MetadataQuery};
+/// use boj_client::search::{SearchIndex, SearchQuery};
+///
+/// let client = BojClient::new()?;
+/// let mut index = SearchIndex::new();
+/// for db in ["FM01", "FM08"] {
+///     index.add_response(&client.get_metadata(&MetadataQuery::new(db)?)?);
+/// }
+///
+/// let query = SearchQuery::new("ドル 円")
+///     .with_frequency(Frequency::D)
+///     .with_coverage("2000", "2025")?;
+/// for hit in index.search(&query) {
+///     println!("{} {} {:.2}", hit.db, hit.series_code, hit.score);
+/// }
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchIndex {
+    version: u32,
+    documents: Vec<IndexedSeries>,
+    postings: BTreeMap<String, Vec<Posting>>,
+    /// Document index by DB and series code; rebuilt when loading.
+    #[serde(skip)]
+    positions: HashMap<(String, String), usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct IndexedSeries {
+    db: String,
+    entry: MetadataEntry,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+struct Posting {
+    document: u32,
+    weight: u8,
+}
+
+/// One ranked result returned by [`SearchIndex::search`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit<'a> {
+    /// Relevance score; higher is better.
+    pub score: f64,
+    /// Database code the series belongs to.
+    pub db: &'a str,
+    /// Series code, ready to be passed to `CodeQuery`.
+    pub series_code: &'a str,
+    /// Indexed metadata row.
+    pub entry: &'a MetadataEntry,
+}
+
+impl Default for SearchIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchIndex {
+    /// Creates an empty index.
+    pub fn new() -> Self {
+        Self {
+            version: INDEX_FORMAT_VERSION,
+            documents: Vec::new(),
+            postings: BTreeMap::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Builds an index from several metadata responses.
+    pub fn from_responses<'a>(responses: impl IntoIterator<Item = &'a MetadataResponse>) -> Self {
+        let mut index = Self::new();
+        for response in responses {
+            index.add_response(response);
+        }
+        index
+    }
+
+    /// Adds the series rows of `response`, replacing rows already indexed
+    /// under the same DB and series code.
+    pub fn add_response(&mut self, response: &MetadataResponse) {
+        for entry in &response.entries {
+            let Some(series_code) = entry.series_code.as_deref() else {
+                continue;
+            };
+            let document = IndexedSeries {
+                db: response.db.clone(),
+                entry: entry.clone(),
+            };
+            let key = (response.db.clone(), series_code.to_string());
+            match self.positions.get(&key) {
+                Some(&index) => {
+                    self.remove_postings(index);
+                    self.documents[index] = document;
+                    self.add_postings(index);
+                }
+                None => {
+                    let index = self.documents.len();
+                    self.documents.push(document);
+                    self.positions.insert(key, index);
+                    self.add_postings(index);
+                }
+            }
+        }
+    }
+
+    /// Returns the number of indexed series.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Returns `true` when no series is indexed.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Returns the DB codes present in the index, sorted.
+    pub fn dbs(&self) -> Vec<&str> {
+        self.documents
+            .iter()
+            .map(|document| document.db.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// Runs `query` and returns hits ordered by descending score, then DB and
+    /// series code.
+    ///
+    /// Every query token must match (AND semantics). ASCII tokens also match
+    /// indexed words they are a prefix of, at a reduced weight, so partial
+    /// series codes such as `fxerd` find `FXERD01`.
+    pub fn search(&self, query: &SearchQuery) -> Vec<SearchHit<'_>> {
+        let candidates = (0..self.documents.len())
+            .filter(|index| self.passes_filters(&self.documents[*index], query));
+
+        let tokens = tokenize(&query.text).into_iter().collect::<BTreeSet<_>>();
+        let mut hits = if tokens.is_empty() {
+            candidates
+                .map(|index| self.hit(index, 0.0))
+                .collect::<Vec<_>>()
+        } else {
+            let scores = tokens
+                .iter()
+                .map(|token| self.token_scores(token))
+                .collect::<Vec<_>>();
+            candidates
+                .filter_map(|index| {
+                    let mut total = 0.0;
+                    for token_scores in &scores {
+                        total += token_scores.get(&index)?;
+                    }
+                    Some(self.hit(index, total))
+                })
+                .collect()
+        };
+
+        hits.sort_by(|left, right| {
+            right
+                .score
+                .total_cmp(&left.score)
+                .then_with(|| left.db.cmp(right.db))
+                .then_with(|| left.series_code.cmp(right.series_code))
+        });
+        if let Some(limit) = query.limit {
+            hits.truncate(limit);
+        }
+        hits
+    }
+
+    /// Writes the index as JSON to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error when writing or serialization fails.
+    pub fn write_to<W: Write>(&self, writer: W) -> io::Result<()> {
+        serde_json::to_writer(writer, self).map_err(io::Error::from)
+    }
+
+    /// Reads an index previously written with [`SearchIndex::write_to`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error when reading fails, the data is not a serialized
+    /// index, or it was written by an incompatible index format.
+    pub fn read_from<R: Read>(reader: R) -> io::Result<Self> {
+        let mut index: Self = serde_json::from_reader(reader).map_err(io::Error::from)?;
+        if index.version != INDEX_FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "unsupported search index format version {} (expected {INDEX_FORMAT_VERSION})",
+                    index.version
+                ),
+            ));
+        }
+        index.positions = index
+            .documents
+            .iter()
+            .enumerate()
+            .filter_map(|(position, document)| {
+                let series_code = document.entry.series_code.clone()?;
+                Some(((document.db.clone(), series_code), position))
+            })
+            .collect();
+        Ok(index)
+    }
+
+    /// Saves the index to `path`, replacing any existing file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error when the file cannot be written.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+
+    /// Loads an index saved with [`SearchIndex::save`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error when the file cannot be read or is not a
+    /// compatible index.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read_from(BufReader::new(File::open(path)?))
+    }
+
+    /// Adds the postings of the document at `index`, keeping each posting
+    /// list ordered by document.
+    fn add_postings(&mut self, index: usize) {
+        let document = index as u32;
+        for (token, weight) in document_tokens(&self.documents[index].entry) {
+            let postings = self.postings.entry(token).or_default();
+            let position = postings.partition_point(|posting| posting.document < document);
+            postings.insert(position, Posting { document, weight });
+        }
+    }
+
+    /// Removes the postings of the document at `index`.
+    fn remove_postings(&mut self, index: usize) {
+        let document = index as u32;
+        for token in document_tokens(&self.documents[index].entry).into_keys() {
+            let Some(postings) = self.postings.get_mut(&token) else {
+                continue;
+            };
+            postings.retain(|posting| posting.document != document);
+            if postings.is_empty() {
+                self.postings.remove(&token);
+            }
+        }
+    }
+
+    /// Scores every document matching `token`, keyed by document index.
+    fn token_scores(&self, token: &str) -> HashMap<usize, f64> {
+        let mut scores = HashMap::new();
+        let mut add = |postings: &[Posting], factor: f64| {
+            let idf = (1.0 + self.documents.len() as f64 / postings.len() as f64).ln();
+            for posting in postings {
+                let score = idf * f64::from(posting.weight) * factor;
+                let best = scores.entry(posting.document as usize).or_insert(score);
+                *best = f64::max(*best, score);
+            }
+        };
+
+        if let Some(postings) = self.postings.get(token) {
+            add(postings, 1.0);
+        }
+        if token.is_ascii() {
+            let prefixed = self
+                .postings
+                .range::<str, _>((std::ops::Bound::Excluded(token), std::ops::Bound::Unbounded))
+                .take_while(|(indexed, _)| indexed.starts_with(token));
+            for (_, postings) in prefixed {
+                add(postings, 0.5);
+            }
+        }
+        scores
+    }
+
+    fn passes_filters(&self, document: &IndexedSeries, query: &SearchQuery) -> bool {
+        if !query.dbs.is_empty()
+            && !query
+                .dbs
+                .iter()
+                .any(|db| db.eq_ignore_ascii_case(&document.db))
+        {
+            return false;
+        }
+
+        query.filter.matches(&document.entry)
+    }
+
+    fn hit(&self, index: usize, score: f64) -> SearchHit<'_> {
+        let document = &self.documents[index];
+        SearchHit {
+            score,
+            db: &document.db,
+            series_code: document.entry.series_code.as_deref().unwrap_or_default(),
+            entry: &document.entry,
+        }
+    }
+}
+
+/// Returns the tokens of the searchable fields of `entry` with the highest
+/// field weight each appears in.
+fn document_tokens(entry: &MetadataEntry) -> BTreeMap<String, u8> {
+    let fields = [
+        (&entry.series_code, CODE_WEIGHT),
+        (&entry.name_of_time_series_j, NAME_WEIGHT),
+        (&entry.name_of_time_series, NAME_WEIGHT),
+        (&entry.category_j, CATEGORY_WEIGHT),
+        (&entry.category, CATEGORY_WEIGHT),
+        (&entry.unit_j, UNIT_WEIGHT),
+        (&entry.unit, UNIT_WEIGHT),
+        (&entry.notes_j, NOTES_WEIGHT),
+        (&entry.notes, NOTES_WEIGHT),
+    ];
+    let mut tokens = BTreeMap::new();
+    for (text, weight) in fields {
+        let Some(text) = text else {
+            continue;
+        };
+        for token in index_tokens(text) {
+            let best = tokens.entry(token).or_insert(weight);
+            *best = (*best).max(weight);
+        }
+    }
+    tokens
+}
@@ -0,0 +1,6 @@
+mod index;
+mod normalize;
+mod query;
+
+pub use index::{SearchHit, SearchIndex};
+pub use query::SearchQuery;
@@ -0,0 +1,152 @@
+/// Half-width katakana (`U+FF66`..`U+FF9D`) mapped to full-width katakana.
+const HALF_WIDTH_KATAKANA: [char; 56] = [
+    'ヲ', 'ァ', 'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ッ', 'ー', 'ア', 'イ', 'ウ', 'エ', 'オ',
+    'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', 'タ', 'チ', 'ツ', 'テ', 'ト', 'ナ',
+    'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', 'ミ', 'ム', 'メ', 'モ', 'ヤ', 'ユ',
+    'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ン',
+];
+
+/// Normalizes text for indexing and querying.
+///
+/// - Full-width ASCII variants and the ideographic space become ASCII.
+/// - Half-width katakana become full-width katakana, combining voiced and
+///   semi-voiced sound marks with the preceding character.
+/// - ASCII letters are lowercased.
+pub(super) fn normalize_text(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '\u{3000}' => normalized.push(' '),
+            '\u{ff01}'..='\u{ff5e}' => {
+                let ascii = char::from_u32(ch as u32 - 0xfee0).unwrap_or(ch);
+                normalized.push(ascii.to_ascii_lowercase());
+            }
+            '\u{ff61}' => normalized.push('。'),
+            '\u{ff62}' => normalized.push('「'),
+            '\u{ff63}' => normalized.push('」'),
+            '\u{ff64}' => normalized.push('、'),
+            '\u{ff65}' => normalized.push('・'),
+            '\u{ff66}'..='\u{ff9d}' => {
+                normalized.push(HALF_WIDTH_KATAKANA[(ch as u32 - 0xff66) as usize]);
+            }
+            '\u{ff9e}' | '\u{ff9f}' => {
+                let voiced = ch == '\u{ff9e}';
+                let combined = normalized
+                    .chars()
+                    .next_back()
+                    .and_then(|last| combine_sound_mark(last, voiced));
+                match combined {
+                    Some(combined) => {
+                        normalized.pop();
+                        normalized.push(combined);
+                    }
+                    None => normalized.push(if voiced { '゛' } else { '゜' }),
+                }
+            }
+            _ => normalized.push(ch.to_ascii_lowercase()),
+        }
+    }
+    normalized
+}
+
+fn combine_sound_mark(base: char, voiced: bool) -> Option<char> {
+    let code = base as u32;
+    let combined = if voiced {
+        match base {
+            'ウ' => 'ヴ',
+            'カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ' | 'タ' | 'チ'
+            | 'ツ' | 'テ' | 'ト' | 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => {
+                char::from_u32(code + 1)?
+            }
+            _ => return None,
+        }
+    } else {
+        match base {
+            'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => char::from_u32(code + 2)?,
+            _ => return None,
+        }
+    };
+    Some(combined)
+}
+
+/// Splits normalized query text into search tokens.
+///
+/// ASCII alphanumeric runs become whole-word tokens. Other alphanumeric runs
+/// (kanji, kana, ...) become character bigrams, or a single unigram when the
+/// run is one character long. Everything else separates tokens.
+pub(super) fn tokenize(text: &str) -> Vec<String> {
+    split(text, false)
+}
+
+/// Splits normalized document text into indexed tokens: the tokens of
+/// [`tokenize`] plus a unigram for each character of longer non-ASCII runs,
+/// so one-character queries such as `円` match inside words.
+pub(super) fn index_tokens(text: &str) -> Vec<String> {
+    split(text, true)
+}
+
+fn split(text: &str, unigrams: bool) -> Vec<String> {
+    let normalized = normalize_text(text);
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut run = Vec::<char>::new();
+
+    for ch in normalized.chars() {
+        if ch.is_ascii_alphanumeric() {
+            flush_run(&mut run, unigrams, &mut tokens);
+            word.push(ch);
+        } else if ch.is_alphanumeric() || ch == 'ー' || ch == '々' {
+            flush_word(&mut word, &mut tokens);
+            run.push(ch);
+        } else {
+            flush_word(&mut word, &mut tokens);
+            flush_run(&mut run, unigrams, &mut tokens);
+        }
+    }
+    flush_word(&mut word, &mut tokens);
+    flush_run(&mut run, unigrams, &mut tokens);
+
+    tokens
+}
+
+fn flush_word(word: &mut String, tokens: &mut Vec<String>) {
+    if !word.is_empty() {
+        tokens.push(std::mem::take(word));
+    }
+}
+
+fn flush_run(run: &mut Vec<char>, unigrams: bool, tokens: &mut Vec<String>) {
+    match run.len() {
+        0 => {}
+        1 => tokens.push(run[0].to_string()),
+        _ => {
+            tokens.extend(run.windows(2).map(|pair| pair.iter().collect()));
+            if unigrams {
+                tokens.extend(run.iter().map(char::to_string));
+            }
+        }
+    }
+    run.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{index_tokens, normalize_text, tokenize};
+
+    #[test]
+    fn normalizes_full_and_half_width_text() {
+        assert_eq!(normalize_text("ＦＭ０１　Ｏ／Ｎ"), "fm01 o/n");
+        assert_eq!(normalize_text("ｺｰﾙﾚｰﾄ"), "コールレート");
+        assert_eq!(normalize_text("ﾄﾞﾙ･ﾊﾟﾈﾙ"), "ドル・パネル");
+    }
+
+    #[test]
+    fn tokenizes_japanese_as_bigrams_and_ascii_as_words() {
+        assert_eq!(
+            tokenize("無担保コール O/N"),
+            vec!["無担", "担保", "保コ", "コー", "ール", "o", "n"]
+        );
+        assert_eq!(tokenize("円"), vec!["円"]);
+        assert_eq!(index_tokens("億円"), vec!["億円", "億", "円"]);
+    }
+}
@@ -0,0 +1,67 @@
+use crate::error::BojError;
+use crate::model::MetadataFilter;
+use crate::query::Frequency;
+
+/// Search text and filters for [`SearchIndex::search`](super::SearchIndex::search).
+///
+/// An empty text matches every indexed series, which lets the filters be used
+/// on their own.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    pub(super) text: String,
+    pub(super) dbs: Vec<String>,
+    pub(super) filter: MetadataFilter,
+    pub(super) limit: Option<usize>,
+}
+
+impl SearchQuery {
+    /// Creates a query for `text`.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Restricts results to `db`. Calling this several times allows several DBs.
+    pub fn with_db(mut self, db: impl Into<String>) -> Self {
+        self.dbs.push(db.into());
+        self
+    }
+
+    /// Restricts results to series reported with `frequency`.
+    pub fn with_frequency(mut self, frequency: Frequency) -> Self {
+        self.filter = self.filter.with_frequency(frequency);
+        self
+    }
+
+    /// Restricts results to series whose observations cover `from` to `to`.
+    ///
+    /// See [`MetadataFilter::with_coverage`] for how bounds are interpreted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError`] when a bound is not a valid period or `from` is
+    /// after `to`.
+    pub fn with_coverage(
+        mut self,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+    ) -> Result<Self, BojError> {
+        self.filter = self.filter.with_coverage(from, to)?;
+        Ok(self)
+    }
+
+    /// Restricts results to series matching `filter`, replacing the
+    /// frequency and coverage filters set so far.
+    pub fn with_filter(mut self, filter: MetadataFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Limits the number of returned hits.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
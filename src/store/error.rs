@@ -0,0 +1,28 @@
+use thiserror::Error;
+
+use crate::error::BojError;
+
+/// Error returned by [`SqliteStore`](super::SqliteStore).
+#[derive(Debug, Error)]
+pub enum StoreError {
+    /// The SQLite database could not be opened, read or written.
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    /// Fetching from the BOJ API failed during a sync.
+    #[error(transparent)]
+    Api(#[from] BojError),
+
+    /// The database was written by a newer schema than this crate knows.
+    #[error("unsupported store schema version {found} (expected at most {supported})")]
+    UnsupportedSchema {
+        /// Schema version recorded in the database.
+        found: i64,
+        /// Latest schema version known to this crate.
+        supported: i64,
+    },
+
+    /// A response does not echo the DB its series belong to.
+    #[error("response does not echo a DB code")]
+    MissingDb,
+}
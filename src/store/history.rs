@@ -0,0 +1,174 @@
+use rusqlite::{OptionalExtension, Row, params};
+use serde::{Deserialize, Serialize};
+
+use crate::model::{CodeSeries, DataPoint};
+
+use super::error::StoreError;
+use super::sqlite::SqliteStore;
+
+const REVISION_COLUMNS: &str = "revisions.series_code, revisions.survey_date, \
+     fetches.retrieved_at, revisions.value, revisions.initial, revisions.previous_value";
+
+/// Condition keeping fetches retrieved on or before `?as_of`.
+///
+/// `retrieved_at` is the BOJ response `DATE` (`2026-02-19T12:38:18.251+09:00`),
+/// so comparing its prefix of the same length applies a date
+/// (`2026-02-19`) or a timestamp (`2026-02-19T12:00`) inclusively.
+const KNOWN_AS_OF: &str = "substr(fetches.retrieved_at, 1, length(?3)) <= ?3";
+
+/// One recorded value of an observation: its first appearance or a later
+/// change.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revision {
+    /// Time-series code.
+    pub series_code: String,
+    /// Survey date of the observation.
+    pub survey_date: String,
+    /// Response `DATE` of the fetch that returned this value. Empty for
+    /// values stored before revision history was kept.
+    pub retrieved_at: String,
+    /// Value from that fetch; `None` when BOJ omitted it.
+    pub value: Option<String>,
+    /// `true` when this is the first value recorded for the observation.
+    pub initial: bool,
+    /// Value replaced by this revision; always `None` when
+    /// [`initial`](Self::initial) is `true`.
+    pub previous_value: Option<String>,
+}
+
+impl SqliteStore {
+    /// Returns every recorded value of one observation, oldest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn history(
+        &self,
+        db: &str,
+        code: &str,
+        survey_date: &str,
+    ) -> Result<Vec<Revision>, StoreError> {
+        let mut statement = self.connection.prepare_cached(&format!(
+            "SELECT {REVISION_COLUMNS} FROM revisions \
+             JOIN fetches ON fetches.id = revisions.fetch_id \
+             WHERE revisions.db = ?1 AND revisions.series_code = ?2 \
+             AND revisions.survey_date = ?3 \
+             ORDER BY revisions.fetch_id"
+        ))?;
+        let revisions = statement
+            .query_map(
+                params![db.to_ascii_uppercase(), code, survey_date],
+                revision_from_row,
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(revisions)
+    }
+
+    /// Returns the value of one observation as known on `as_of`, or `None`
+    /// when no fetch on or before `as_of` returned it.
+    ///
+    /// `as_of` is a date (`2026-02-19`) or timestamp prefix
+    /// (`2026-02-19T12:00`) compared with the BOJ response `DATE`, which BOJ
+    /// reports in JST; every fetch during that day or minute counts. The
+    /// latest response `DATE` wins, so loading an older response after a
+    /// newer one does not replace the newer value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn vintage(
+        &self,
+        db: &str,
+        code: &str,
+        survey_date: &str,
+        as_of: &str,
+    ) -> Result<Option<Revision>, StoreError> {
+        let revision = self
+            .connection
+            .query_row(
+                &format!(
+                    "SELECT {REVISION_COLUMNS} FROM revisions \
+                     JOIN fetches ON fetches.id = revisions.fetch_id \
+                     WHERE revisions.db = ?1 AND revisions.series_code = ?2 \
+                     AND {KNOWN_AS_OF} AND revisions.survey_date = ?4 \
+                     ORDER BY fetches.retrieved_at DESC, revisions.fetch_id DESC LIMIT 1"
+                ),
+                params![db.to_ascii_uppercase(), code, as_of, survey_date],
+                revision_from_row,
+            )
+            .optional()?;
+        Ok(revision)
+    }
+
+    /// Returns series `code` of `db` with the observations as known on
+    /// `as_of` (see [`vintage`](Self::vintage)), or `None` when the series is
+    /// not in the store. Labels are the current ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn series_as_of(
+        &self,
+        db: &str,
+        code: &str,
+        as_of: &str,
+    ) -> Result<Option<CodeSeries>, StoreError> {
+        let Some(mut series) = self.series(db, code)? else {
+            return Ok(None);
+        };
+        let mut statement = self.connection.prepare_cached(&format!(
+            "SELECT survey_date, value FROM ( \
+                 SELECT revisions.survey_date, revisions.value, row_number() OVER ( \
+                     PARTITION BY revisions.survey_date \
+                     ORDER BY fetches.retrieved_at DESC, revisions.fetch_id DESC \
+                 ) AS latest \
+                 FROM revisions JOIN fetches ON fetches.id = revisions.fetch_id \
+                 WHERE revisions.db = ?1 AND revisions.series_code = ?2 AND {KNOWN_AS_OF} \
+             ) WHERE latest = 1 ORDER BY survey_date"
+        ))?;
+        series.points = statement
+            .query_map(params![db.to_ascii_uppercase(), code, as_of], |row| {
+                Ok(DataPoint {
+                    survey_date: row.get(0)?,
+                    value: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(series))
+    }
+
+    /// Returns the changes to already stored values of `db` fetched on or
+    /// after `since`, in fetch order. First appearances of observations are
+    /// not revisions and are left out.
+    ///
+    /// `since` is a date or timestamp prefix as in
+    /// [`vintage`](Self::vintage).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn revisions_since(&self, db: &str, since: &str) -> Result<Vec<Revision>, StoreError> {
+        let mut statement = self.connection.prepare_cached(&format!(
+            "SELECT {REVISION_COLUMNS} FROM revisions \
+             JOIN fetches ON fetches.id = revisions.fetch_id \
+             WHERE revisions.db = ?1 AND NOT revisions.initial \
+             AND substr(fetches.retrieved_at, 1, length(?2)) >= ?2 \
+             ORDER BY revisions.fetch_id, revisions.series_code, revisions.survey_date"
+        ))?;
+        let revisions = statement
+            .query_map(params![db.to_ascii_uppercase(), since], revision_from_row)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(revisions)
+    }
+}
+
+fn revision_from_row(row: &Row<'_>) -> rusqlite::Result<Revision> {
+    Ok(Revision {
+        series_code: row.get(0)?,
+        survey_date: row.get(1)?,
+        retrieved_at: row.get(2)?,
+        value: row.get(3)?,
+        initial: row.get(4)?,
+        previous_value: row.get(5)?,
+    })
+}
@@ -0,0 +1,10 @@
+mod error;
+mod history;
+mod schema;
+mod sqlite;
+mod sync;
+
+pub use error::StoreError;
+pub use history::Revision;
+pub use sqlite::SqliteStore;
+pub use sync::SyncReport;
@@ -0,0 +1,98 @@
+use rusqlite::Connection;
+
+use super::error::StoreError;
+
+/// Schema version recorded in `PRAGMA user_version`.
+pub(super) const SCHEMA_VERSION: i64 = MIGRATIONS.len() as i64;
+
+/// Migration `i` upgrades a database from schema version `i` to `i + 1`.
+const MIGRATIONS: [&str; 2] = [SERIES_AND_OBSERVATIONS, REVISION_HISTORY];
+
+/// `series` holds one `getMetadata` row per series plus the labels of data
+/// responses; `synced_last_update` is the `last_update` whose observations
+/// are stored, so a sync only refetches series whose metadata moved on.
+/// `observations` keeps BOJ survey dates and values as reported.
+const SERIES_AND_OBSERVATIONS: &str = "
+CREATE TABLE series (
+    db TEXT NOT NULL,
+    series_code TEXT NOT NULL,
+    name_of_time_series_j TEXT,
+    name_of_time_series TEXT,
+    unit_j TEXT,
+    unit TEXT,
+    frequency TEXT,
+    category_j TEXT,
+    category TEXT,
+    layer1 INTEGER,
+    layer2 INTEGER,
+    layer3 INTEGER,
+    layer4 INTEGER,
+    layer5 INTEGER,
+    start_of_the_time_series TEXT,
+    end_of_the_time_series TEXT,
+    last_update TEXT,
+    notes_j TEXT,
+    notes TEXT,
+    synced_last_update TEXT,
+    PRIMARY KEY (db, series_code)
+);
+
+CREATE TABLE observations (
+    db TEXT NOT NULL,
+    series_code TEXT NOT NULL,
+    survey_date TEXT NOT NULL,
+    value TEXT,
+    PRIMARY KEY (db, series_code, survey_date)
+);
+";
+
+/// `fetches` records every stored data response with its `DATE`;
+/// `revisions` keeps one row per observation whose value was new or changed
+/// in a fetch. Observations stored before history existed are seeded under a
+/// fetch with an empty timestamp, which sorts before every date.
+const REVISION_HISTORY: &str = "
+CREATE TABLE fetches (
+    id INTEGER PRIMARY KEY,
+    db TEXT NOT NULL,
+    retrieved_at TEXT NOT NULL
+);
+
+CREATE TABLE revisions (
+    db TEXT NOT NULL,
+    series_code TEXT NOT NULL,
+    survey_date TEXT NOT NULL,
+    fetch_id INTEGER NOT NULL REFERENCES fetches (id),
+    value TEXT,
+    initial INTEGER NOT NULL,
+    previous_value TEXT,
+    PRIMARY KEY (db, series_code, survey_date, fetch_id)
+);
+
+CREATE INDEX revisions_by_fetch ON revisions (fetch_id);
+
+INSERT INTO fetches (db, retrieved_at) SELECT DISTINCT db, '' FROM observations;
+
+INSERT INTO revisions (db, series_code, survey_date, fetch_id, value, initial)
+SELECT observations.db, series_code, survey_date, fetches.id, value, 1
+FROM observations JOIN fetches ON fetches.db = observations.db;
+";
+
+/// Brings the database up to [`SCHEMA_VERSION`], one migration per
+/// transaction, and rejects databases written by a newer schema.
+pub(super) fn migrate(connection: &Connection) -> Result<(), StoreError> {
+    let version =
+        connection.pragma_query_value(None, "user_version", |row| row.get::<_, i64>(0))?;
+    if version > SCHEMA_VERSION {
+        return Err(StoreError::UnsupportedSchema {
+            found: version,
+            supported: SCHEMA_VERSION,
+        });
+    }
+    for (from, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        connection.execute_batch(&format!(
+            "BEGIN; {migration} PRAGMA user_version = {}; COMMIT;",
+            from + 1
+        ))?;
+    }
+    Ok(())
+}
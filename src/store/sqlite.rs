@@ -0,0 +1,402 @@
+use std::path::Path;
+
+use rusqlite::{Connection, OptionalExtension, Row, Transaction, params};
+
+use crate::model::{CodeResponse, CodeSeries, DataPoint, MetadataEntry, MetadataResponse};
+use crate::query::CodeQuery;
+
+use super::error::StoreError;
+use super::schema::migrate;
+
+const SERIES_COLUMNS: &str = "series_code, name_of_time_series_j, name_of_time_series, unit_j, \
+     unit, frequency, category_j, category, last_update";
+
+/// Local SQLite mirror of BOJ series metadata and observations.
+///
+/// [`sync`](Self::sync) keeps one DB up to date from `getMetadata`, fetching
+/// observations only for series whose `last_update` changed since the
+/// previous sync. Reads return the same [`CodeSeries`] model as
+/// `getDataCode`, so applications can work offline against the mirror.
+///
+/// # Examples
+///
+/// ```no_run
+/// use boj_client::client::BojClient;
+/// use boj_client::query::CodeQuery;
+/// use boj_client::store::SqliteStore;
+///
+/// let client = BojClient::new()?;
+/// let mut store = SqliteStore::open("boj.sqlite")?;
+/// let report = store.sync(&client, "FM08")?;
+/// println!("updated {} series", report.updated.len());
+///
+/// let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])?
+///     .with_start_date("202401")?;
+/// for series in store.query_code(&query)? {
+///     println!("{}: {} points", series.series_code, series.points.len());
+/// }
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct SqliteStore {
+    pub(super) connection: Connection,
+}
+
+impl SqliteStore {
+    /// Opens the store at `path`, creating the database file and its tables
+    /// when missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when the file cannot be opened or was written by
+    /// a newer schema.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, StoreError> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Opens a store held in memory, discarded when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when SQLite cannot create the database.
+    pub fn open_in_memory() -> Result<Self, StoreError> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(connection: Connection) -> Result<Self, StoreError> {
+        migrate(&connection)?;
+        Ok(Self { connection })
+    }
+
+    /// Inserts or replaces the series rows of a `getMetadata` response and
+    /// returns how many were written. Layer heading rows without a series
+    /// code are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when writing fails.
+    pub fn upsert_metadata(&mut self, response: &MetadataResponse) -> Result<usize, StoreError> {
+        let transaction = self.connection.transaction()?;
+        let written = write_metadata(&transaction, response)?;
+        transaction.commit()?;
+        Ok(written)
+    }
+
+    /// Upserts the series labels and observations of a `getDataCode`
+    /// response and returns how many observations were written.
+    ///
+    /// Existing observations for the same survey dates are overwritten, and
+    /// changed values are kept in the revision history under the response
+    /// `DATE`.
+    /// Series written this way are not marked as synced, so the next
+    /// [`sync`](Self::sync) still checks them against `getMetadata`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when the response does not echo its DB or
+    /// writing fails.
+    pub fn upsert_code_response(&mut self, response: &CodeResponse) -> Result<usize, StoreError> {
+        let db = response
+            .parameter
+            .db
+            .as_deref()
+            .filter(|db| !db.is_empty())
+            .ok_or(StoreError::MissingDb)?;
+        let transaction = self.connection.transaction()?;
+        let written = write_series(
+            &transaction,
+            db,
+            response.meta.date.as_deref(),
+            &response.series,
+        )?;
+        transaction.commit()?;
+        Ok(written.observations)
+    }
+
+    /// Returns the stored series `code` of `db` with all its observations,
+    /// or `None` when the series is not in the store.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn series(&self, db: &str, code: &str) -> Result<Option<CodeSeries>, StoreError> {
+        self.read_series(db, code, (None, None))
+    }
+
+    /// Answers `query` from the store the way `getDataCode` would: series in
+    /// the requested code order, with observations limited to the query's
+    /// start and end periods. Codes missing from the store are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn query_code(&self, query: &CodeQuery) -> Result<Vec<CodeSeries>, StoreError> {
+        let mut series = Vec::new();
+        for code in query.codes() {
+            if let Some(found) = self.read_series(query.db(), code, query.date_range())? {
+                series.push(found);
+            }
+        }
+        Ok(series)
+    }
+
+    /// Returns the stored metadata rows of `db` in series code order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn metadata(&self, db: &str) -> Result<Vec<MetadataEntry>, StoreError> {
+        let mut statement = self.connection.prepare(
+            "SELECT series_code, name_of_time_series_j, name_of_time_series, unit_j, unit, \
+             frequency, category_j, category, layer1, layer2, layer3, layer4, layer5, \
+             start_of_the_time_series, end_of_the_time_series, last_update, notes_j, notes \
+             FROM series WHERE db = ?1 ORDER BY series_code",
+        )?;
+        let entries = statement
+            .query_map([db.to_ascii_uppercase()], |row| {
+                Ok(MetadataEntry {
+                    series_code: row.get(0)?,
+                    name_of_time_series_j: row.get(1)?,
+                    name_of_time_series: row.get(2)?,
+                    unit_j: row.get(3)?,
+                    unit: row.get(4)?,
+                    frequency: row.get(5)?,
+                    category_j: row.get(6)?,
+                    category: row.get(7)?,
+                    layer1: row.get(8)?,
+                    layer2: row.get(9)?,
+                    layer3: row.get(10)?,
+                    layer4: row.get(11)?,
+                    layer5: row.get(12)?,
+                    start_of_the_time_series: row.get(13)?,
+                    end_of_the_time_series: row.get(14)?,
+                    last_update: row.get(15)?,
+                    notes_j: row.get(16)?,
+                    notes: row.get(17)?,
+                    extras: Default::default(),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(entries)
+    }
+
+    /// Returns the series codes of `db` in the store, in code order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when reading fails.
+    pub fn series_codes(&self, db: &str) -> Result<Vec<String>, StoreError> {
+        let mut statement = self
+            .connection
+            .prepare("SELECT series_code FROM series WHERE db = ?1 ORDER BY series_code")?;
+        let codes = statement
+            .query_map([db.to_ascii_uppercase()], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(codes)
+    }
+
+    fn read_series(
+        &self,
+        db: &str,
+        code: &str,
+        (start, end): (Option<&str>, Option<&str>),
+    ) -> Result<Option<CodeSeries>, StoreError> {
+        let db = db.to_ascii_uppercase();
+        let series = self
+            .connection
+            .query_row(
+                &format!("SELECT {SERIES_COLUMNS} FROM series WHERE db = ?1 AND series_code = ?2"),
+                params![db, code],
+                series_from_row,
+            )
+            .optional()?;
+        let Some(mut series) = series else {
+            return Ok(None);
+        };
+
+        // Survey dates share the leading digits of the start/end period
+        // format (`2024`, `202401`, `20240104`), so a prefix comparison
+        // applies BOJ's inclusive range for every frequency.
+        let mut statement = self.connection.prepare_cached(
+            "SELECT survey_date, value FROM observations \
+             WHERE db = ?1 AND series_code = ?2 \
+             AND (?3 IS NULL OR substr(survey_date, 1, length(?3)) >= ?3) \
+             AND (?4 IS NULL OR substr(survey_date, 1, length(?4)) <= ?4) \
+             ORDER BY survey_date",
+        )?;
+        series.points = statement
+            .query_map(params![db, code, start, end], |row| {
+                Ok(DataPoint {
+                    survey_date: row.get(0)?,
+                    value: row.get(1)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Some(series))
+    }
+}
+
+pub(super) fn write_metadata(
+    transaction: &Transaction<'_>,
+    response: &MetadataResponse,
+) -> Result<usize, StoreError> {
+    let mut statement = transaction.prepare_cached(
+        "INSERT INTO series (db, series_code, name_of_time_series_j, name_of_time_series, \
+         unit_j, unit, frequency, category_j, category, layer1, layer2, layer3, layer4, layer5, \
+         start_of_the_time_series, end_of_the_time_series, last_update, notes_j, notes) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19) \
+         ON CONFLICT (db, series_code) DO UPDATE SET \
+         name_of_time_series_j = excluded.name_of_time_series_j, \
+         name_of_time_series = excluded.name_of_time_series, \
+         unit_j = excluded.unit_j, unit = excluded.unit, frequency = excluded.frequency, \
+         category_j = excluded.category_j, category = excluded.category, \
+         layer1 = excluded.layer1, layer2 = excluded.layer2, layer3 = excluded.layer3, \
+         layer4 = excluded.layer4, layer5 = excluded.layer5, \
+         start_of_the_time_series = excluded.start_of_the_time_series, \
+         end_of_the_time_series = excluded.end_of_the_time_series, \
+         last_update = excluded.last_update, notes_j = excluded.notes_j, notes = excluded.notes",
+    )?;
+    let db = response.db.to_ascii_uppercase();
+    let mut written = 0;
+    for entry in &response.entries {
+        let Some(code) = entry.series_code.as_deref().filter(|code| !code.is_empty()) else {
+            continue;
+        };
+        statement.execute(params![
+            db,
+            code,
+            entry.name_of_time_series_j,
+            entry.name_of_time_series,
+            entry.unit_j,
+            entry.unit,
+            entry.frequency,
+            entry.category_j,
+            entry.category,
+            entry.layer1,
+            entry.layer2,
+            entry.layer3,
+            entry.layer4,
+            entry.layer5,
+            entry.start_of_the_time_series,
+            entry.end_of_the_time_series,
+            entry.last_update,
+            entry.notes_j,
+            entry.notes,
+        ])?;
+        written += 1;
+    }
+    Ok(written)
+}
+
+/// Observations and revisions written by one [`write_series`] call.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct Written {
+    pub(super) observations: usize,
+    pub(super) revisions: usize,
+}
+
+/// Records a fetch retrieved at `retrieved_at` (the response `DATE`, or now
+/// in JST when BOJ sent none), upserts series labels, keeping stored labels
+/// the data response leaves empty, and upserts their observations. Values
+/// that are new or differ from the stored ones are added to the revision
+/// history.
+pub(super) fn write_series(
+    transaction: &Transaction<'_>,
+    db: &str,
+    retrieved_at: Option<&str>,
+    series: &[CodeSeries],
+) -> Result<Written, StoreError> {
+    let db = db.to_ascii_uppercase();
+    transaction.execute(
+        "INSERT INTO fetches (db, retrieved_at) \
+         VALUES (?1, coalesce(?2, strftime('%Y-%m-%dT%H:%M:%f+09:00', 'now', '+9 hours')))",
+        params![db, retrieved_at],
+    )?;
+    let fetch_id = transaction.last_insert_rowid();
+
+    let mut labels = transaction.prepare_cached(&format!(
+        "INSERT INTO series (db, {SERIES_COLUMNS}) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10) \
+         ON CONFLICT (db, series_code) DO UPDATE SET \
+         name_of_time_series_j = coalesce(excluded.name_of_time_series_j, name_of_time_series_j), \
+         name_of_time_series = coalesce(excluded.name_of_time_series, name_of_time_series), \
+         unit_j = coalesce(excluded.unit_j, unit_j), unit = coalesce(excluded.unit, unit), \
+         frequency = coalesce(excluded.frequency, frequency), \
+         category_j = coalesce(excluded.category_j, category_j), \
+         category = coalesce(excluded.category, category), \
+         last_update = coalesce(excluded.last_update, last_update)"
+    ))?;
+    let mut stored = transaction.prepare_cached(
+        "SELECT value FROM observations WHERE db = ?1 AND series_code = ?2 AND survey_date = ?3",
+    )?;
+    let mut revisions = transaction.prepare_cached(
+        "INSERT INTO revisions \
+         (db, series_code, survey_date, fetch_id, value, initial, previous_value) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7) \
+         ON CONFLICT (db, series_code, survey_date, fetch_id) DO UPDATE SET value = excluded.value",
+    )?;
+    let mut observations = transaction.prepare_cached(
+        "INSERT INTO observations (db, series_code, survey_date, value) \
+         VALUES (?1, ?2, ?3, ?4) \
+         ON CONFLICT (db, series_code, survey_date) DO UPDATE SET value = excluded.value",
+    )?;
+    let mut written = Written::default();
+    for series in series {
+        labels.execute(params![
+            db,
+            series.series_code,
+            series.name_of_time_series_j,
+            series.name_of_time_series,
+            series.unit_j,
+            series.unit,
+            series.frequency,
+            series.category_j,
+            series.category,
+            series.last_update,
+        ])?;
+        for point in &series.points {
+            let key = params![db, series.series_code, point.survey_date];
+            let previous = stored
+                .query_row(key, |row| row.get::<_, Option<String>>(0))
+                .optional()?;
+            if previous.as_ref() != Some(&point.value) {
+                revisions.execute(params![
+                    db,
+                    series.series_code,
+                    point.survey_date,
+                    fetch_id,
+                    point.value,
+                    previous.is_none(),
+                    previous.clone().flatten(),
+                ])?;
+                if previous.is_some() {
+                    written.revisions += 1;
+                }
+            }
+            observations.execute(params![
+                db,
+                series.series_code,
+                point.survey_date,
+                point.value
+            ])?;
+            written.observations += 1;
+        }
+    }
+    Ok(written)
+}
+
+fn series_from_row(row: &Row<'_>) -> rusqlite::Result<CodeSeries> {
+    Ok(CodeSeries {
+        series_code: row.get(0)?,
+        name_of_time_series_j: row.get(1)?,
+        name_of_time_series: row.get(2)?,
+        unit_j: row.get(3)?,
+        unit: row.get(4)?,
+        frequency: row.get(5)?,
+        category_j: row.get(6)?,
+        category: row.get(7)?,
+        last_update: row.get(8)?,
+        points: Vec::new(),
+        extras: Default::default(),
+    })
+}
@@ -0,0 +1,126 @@
+use crate::client::BojClient;
+use crate::query::{CodeQuery, Format, MetadataQuery};
+
+use super::error::StoreError;
+use super::sqlite::{SqliteStore, write_metadata, write_series};
+
+/// Most series codes `getDataCode` accepts in one request.
+const MAX_CODES_PER_REQUEST: usize = 1250;
+
+/// Code and metadata `last_update` of a series due for a fetch.
+type StaleSeries = (String, Option<String>);
+
+/// Outcome of one [`SqliteStore::sync`] run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncReport {
+    /// DB that was synced.
+    pub db: String,
+    /// Number of series listed by `getMetadata`.
+    pub checked: usize,
+    /// Codes of the series whose observations were refetched, in request
+    /// order.
+    pub updated: Vec<String>,
+    /// Number of observations upserted.
+    pub observations: usize,
+    /// Number of stored observations whose value changed; see
+    /// [`SqliteStore::revisions_since`].
+    pub revisions: usize,
+}
+
+impl SqliteStore {
+    /// Brings `db` up to date with the BOJ API.
+    ///
+    /// The sync fetches `getMetadata` for `db`, stores its rows, and then
+    /// refetches observations only for series whose `last_update` differs
+    /// from the one recorded at their previous sync (or that were never
+    /// synced). Changed series are requested with `getDataCode`, grouped by
+    /// frequency as the API requires and following `NEXTPOSITION` until
+    /// every page is merged; their observations are upserted and changed
+    /// values recorded in the revision history.
+    ///
+    /// Each request batch is committed on its own, so a failed sync keeps the
+    /// batches already written and the next sync resumes with the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError`] when a BOJ request fails or writing fails.
+    pub fn sync(&mut self, client: &BojClient, db: &str) -> Result<SyncReport, StoreError> {
+        let metadata = client.get_metadata(&MetadataQuery::new(db)?.with_format(Format::Json))?;
+        let db = metadata.db.to_ascii_uppercase();
+
+        let transaction = self.connection.transaction()?;
+        let checked = write_metadata(&transaction, &metadata)?;
+        transaction.commit()?;
+
+        let mut report = SyncReport {
+            db,
+            checked,
+            updated: Vec::new(),
+            observations: 0,
+            revisions: 0,
+        };
+        for group in self.stale_series(&report.db)? {
+            for batch in group.chunks(MAX_CODES_PER_REQUEST) {
+                let codes = batch
+                    .iter()
+                    .map(|(code, _)| code.clone())
+                    .collect::<Vec<_>>();
+                let query =
+                    CodeQuery::new(report.db.as_str(), codes.clone())?.with_format(Format::Json);
+                let response = client.get_data_code_all(&query)?;
+
+                let transaction = self.connection.transaction()?;
+                let written = write_series(
+                    &transaction,
+                    &report.db,
+                    response.meta.date.as_deref(),
+                    &response.series,
+                )?;
+                report.observations += written.observations;
+                report.revisions += written.revisions;
+                // Record the metadata `last_update` the batch was fetched
+                // against, including series the response returned empty; it
+                // stays authoritative over the data response's own value.
+                {
+                    let mut synced = transaction.prepare_cached(
+                        "UPDATE series SET last_update = ?3, synced_last_update = ?3 \
+                         WHERE db = ?1 AND series_code = ?2",
+                    )?;
+                    for (code, last_update) in batch {
+                        synced.execute((&report.db, code, last_update))?;
+                    }
+                }
+                transaction.commit()?;
+                report.updated.extend(codes);
+            }
+        }
+        Ok(report)
+    }
+
+    /// Returns the codes and metadata `last_update` of series needing a
+    /// fetch, grouped by frequency.
+    fn stale_series(&self, db: &str) -> Result<Vec<Vec<StaleSeries>>, StoreError> {
+        let mut statement = self.connection.prepare(
+            "SELECT frequency, series_code, last_update FROM series \
+             WHERE db = ?1 AND coalesce(frequency, '') <> '' \
+             AND (synced_last_update IS NULL OR synced_last_update IS NOT last_update) \
+             ORDER BY frequency, series_code",
+        )?;
+        let rows = statement.query_map([db], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                (row.get::<_, String>(1)?, row.get::<_, Option<String>>(2)?),
+            ))
+        })?;
+
+        let mut groups = Vec::<(String, Vec<StaleSeries>)>::new();
+        for row in rows {
+            let (frequency, series) = row?;
+            match groups.last_mut() {
+                Some((current, members)) if *current == frequency => members.push(series),
+                _ => groups.push((frequency, vec![series])),
+            }
+        }
+        Ok(groups.into_iter().map(|(_, members)| members).collect())
+    }
+}
@@ -0,0 +1,315 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+
+use crate::error::BojError;
+use crate::query::Frequency;
+
+use super::period::Period;
+use super::resample::{ResampleOptions, nests_in};
+use super::series::NumericSeries;
+
+/// Which periods an [`AlignedTable`] keeps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Join {
+    /// Periods where every series has a value.
+    #[default]
+    Inner,
+    /// Periods where any series has a value.
+    Outer,
+}
+
+/// How an outer join fills periods a series has no value for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FillPolicy {
+    /// Leaves the value missing.
+    #[default]
+    Na,
+    /// Repeats the last earlier value of the series, e.g. over market
+    /// holidays of one series. Periods before the first or after the last
+    /// value of the series stay missing.
+    ForwardFill,
+}
+
+/// Options for [`align`].
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::transform::{AlignOptions, Aggregation, FillPolicy, Join, ResampleOptions};
+///
+/// let options = AlignOptions::new(Join::Outer)
+///     .with_fill(FillPolicy::ForwardFill)
+///     .with_conversion(ResampleOptions::new(Aggregation::Mean));
+/// assert_eq!(options.fill(), FillPolicy::ForwardFill);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlignOptions {
+    join: Join,
+    fill: FillPolicy,
+    conversion: Option<ResampleOptions>,
+}
+
+impl AlignOptions {
+    /// Creates options for `join` without filling or frequency conversion.
+    pub fn new(join: Join) -> Self {
+        Self {
+            join,
+            fill: FillPolicy::default(),
+            conversion: None,
+        }
+    }
+
+    /// Sets how missing values are filled.
+    pub fn with_fill(mut self, fill: FillPolicy) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Converts series of different frequencies to a common one with
+    /// [`NumericSeries::resample`] and `options`.
+    pub fn with_conversion(mut self, options: ResampleOptions) -> Self {
+        self.conversion = Some(options);
+        self
+    }
+
+    /// Returns the join.
+    pub fn join(&self) -> Join {
+        self.join
+    }
+
+    /// Returns the fill policy.
+    pub fn fill(&self) -> FillPolicy {
+        self.fill
+    }
+
+    /// Returns the frequency conversion options, if enabled.
+    pub fn conversion(&self) -> Option<ResampleOptions> {
+        self.conversion
+    }
+}
+
+/// Several series on one period index, built by [`align`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AlignedTable {
+    /// Frequency of every period.
+    pub frequency: Frequency,
+    /// Row periods in order.
+    pub periods: Vec<Period>,
+    /// One column per input series, in input order.
+    pub columns: Vec<AlignedColumn>,
+    /// Periods observed by every series, or `None` when they do not overlap.
+    pub common_range: Option<CommonRange>,
+}
+
+impl AlignedTable {
+    /// Returns the first column of `series_code`.
+    pub fn column(&self, series_code: &str) -> Option<&AlignedColumn> {
+        self.columns
+            .iter()
+            .find(|column| column.series_code == series_code)
+    }
+}
+
+/// Values of one series in an [`AlignedTable`], one per row period.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AlignedColumn {
+    /// Time-series code.
+    pub series_code: String,
+    /// Value of each row period.
+    pub values: Vec<Option<f64>>,
+}
+
+/// Range between the latest first value and the earliest last value of the
+/// aligned series, with the series that set each bound.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CommonRange {
+    /// First period every series has reached.
+    pub first: Period,
+    /// Last period before any series ends.
+    pub last: Period,
+    /// Series whose first value is at `first`.
+    pub first_constrained_by: Vec<String>,
+    /// Series whose last value is at `last`.
+    pub last_constrained_by: Vec<String>,
+}
+
+/// Aligns `series` on a common period index.
+///
+/// All series must have the same frequency unless
+/// [`with_conversion`](AlignOptions::with_conversion) is set, in which case
+/// they are resampled to the highest frequency all of them convert to, such
+/// as quarters for monthly and quarterly series or fiscal years for monthly
+/// and fiscal-year series.
+///
+/// # Errors
+///
+/// Returns [`BojError::ValidationError`] when `series` is empty, the
+/// frequencies differ without conversion, or no common frequency exists,
+/// e.g. for calendar and fiscal halves.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::query::Frequency;
+/// use boj_client::transform::{AlignOptions, Join, NumericSeries, Observation, Period, align};
+///
+/// let monthly = |code: &str, start: &str, values: &[f64]| {
+///     let start = Period::parse(start, Frequency::M)?;
+///     let observations = values
+///         .iter()
+///         .enumerate()
+///         .map(|(index, &value)| Observation { period: start.offset(index as i32), value: Some(value) })
+///         .collect();
+///     NumericSeries::new(code, Frequency::M, observations)
+/// };
+/// let prices = monthly("PRICES", "202401", &[100.0, 101.0, 102.0])?;
+/// let money = monthly("MONEY", "202402", &[5.0, 6.0, 7.0])?;
+///
+/// let table = align(&[prices, money], AlignOptions::new(Join::Inner))?;
+/// assert_eq!(table.periods.len(), 2);
+/// let range = table.common_range.unwrap();
+/// assert_eq!(range.first_constrained_by, ["MONEY"]);
+/// assert_eq!(range.last_constrained_by, ["PRICES"]);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+pub fn align(series: &[NumericSeries], options: AlignOptions) -> Result<AlignedTable, BojError> {
+    if series.is_empty() {
+        return Err(BojError::validation("align needs at least one series"));
+    }
+    let frequency = common_frequency(series, options.conversion.is_some())?;
+    let series = series
+        .iter()
+        .map(|series| match options.conversion {
+            Some(conversion) if series.frequency != frequency => {
+                series.resample(frequency, conversion).map(Cow::Owned)
+            }
+            _ => Ok(Cow::Borrowed(series)),
+        })
+        .collect::<Result<Vec<_>, BojError>>()?;
+
+    let observed = series
+        .iter()
+        .map(|series| {
+            series
+                .observations
+                .iter()
+                .filter(|observation| observation.value.is_some())
+                .map(|observation| observation.period)
+                .collect::<BTreeSet<_>>()
+        })
+        .collect::<Vec<_>>();
+    let periods = match options.join {
+        Join::Inner => observed[0]
+            .iter()
+            .filter(|period| observed.iter().all(|periods| periods.contains(period)))
+            .copied()
+            .collect::<Vec<_>>(),
+        Join::Outer => observed
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect(),
+    };
+
+    let columns = series
+        .iter()
+        .map(|series| AlignedColumn {
+            series_code: series.series_code.clone(),
+            values: column_values(series, &periods, options.fill),
+        })
+        .collect();
+    Ok(AlignedTable {
+        frequency,
+        periods,
+        columns,
+        common_range: common_range(&series),
+    })
+}
+
+/// Frequency of the aligned table: the shared frequency, or with
+/// `convert` the highest frequency every series resamples to.
+fn common_frequency(series: &[NumericSeries], convert: bool) -> Result<Frequency, BojError> {
+    let mut frequencies = Vec::new();
+    for series in series {
+        if !frequencies.contains(&series.frequency) {
+            frequencies.push(series.frequency);
+        }
+    }
+    if let [frequency] = frequencies[..] {
+        return Ok(frequency);
+    }
+    let labels = frequencies
+        .iter()
+        .map(|frequency| frequency.as_query_value())
+        .collect::<Vec<_>>()
+        .join(", ");
+    if !convert {
+        return Err(BojError::validation(format!(
+            "series have different frequencies ({labels}); enable conversion to align them"
+        )));
+    }
+    [
+        Frequency::M,
+        Frequency::Q,
+        Frequency::Ch,
+        Frequency::Fh,
+        Frequency::Cy,
+        Frequency::Fy,
+    ]
+    .into_iter()
+    .find(|&target| {
+        frequencies
+            .iter()
+            .all(|&source| source == target || nests_in(source, target))
+    })
+    .ok_or_else(|| {
+        BojError::validation(format!(
+            "series frequencies ({labels}) have no common lower frequency"
+        ))
+    })
+}
+
+fn column_values(series: &NumericSeries, periods: &[Period], fill: FillPolicy) -> Vec<Option<f64>> {
+    let last_observed = series.observed_range().map(|(_, last)| last);
+    let mut carried = None;
+    periods
+        .iter()
+        .map(|&period| match series.value_at(period) {
+            Some(value) => {
+                carried = Some(value);
+                Some(value)
+            }
+            None if fill == FillPolicy::ForwardFill && Some(period) <= last_observed => carried,
+            None => None,
+        })
+        .collect()
+}
+
+fn common_range(series: &[Cow<'_, NumericSeries>]) -> Option<CommonRange> {
+    let ranges = series
+        .iter()
+        .map(|series| Some((series.series_code.as_str(), series.observed_range()?)))
+        .collect::<Option<Vec<_>>>()?;
+    let first = ranges.iter().map(|(_, (first, _))| *first).max()?;
+    let last = ranges.iter().map(|(_, (_, last))| *last).min()?;
+    if first > last {
+        return None;
+    }
+    let constrained_by = |bound: &dyn Fn(&(Period, Period)) -> bool| {
+        ranges
+            .iter()
+            .filter(|(_, range)| bound(range))
+            .map(|(code, _)| code.to_string())
+            .collect()
+    };
+    Some(CommonRange {
+        first,
+        last,
+        first_constrained_by: constrained_by(&|range| range.0 == first),
+        last_constrained_by: constrained_by(&|range| range.1 == last),
+    })
+}
@@ -0,0 +1,133 @@
+use crate::error::BojError;
+use crate::query::Frequency;
+
+use super::period::Period;
+use super::series::NumericSeries;
+
+impl NumericSeries {
+    /// Percent change from the previous period (MoM, QoQ, ...).
+    ///
+    /// Daily series compare with the previous observation, since BOJ daily
+    /// series only have business days.
+    pub fn pct_change(&self) -> NumericSeries {
+        self.combine_lagged(1, pct_change)
+    }
+
+    /// Percent change from the same period a year earlier (YoY).
+    ///
+    /// The lag is [`Frequency::periods_per_year`]: 12 months, 4 quarters,
+    /// 2 half-years, 1 year or 52 weeks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] for daily series, which have no
+    /// fixed number of periods per year.
+    pub fn yoy_pct_change(&self) -> Result<NumericSeries, BojError> {
+        let lag = self.periods_per_year("year-over-year change")?;
+        Ok(self.combine_lagged(lag, pct_change))
+    }
+
+    /// Difference from the previous period.
+    pub fn diff(&self) -> NumericSeries {
+        self.combine_lagged(1, |current, previous| Some(current - previous))
+    }
+
+    /// Difference from the same period a year earlier.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] for daily series.
+    pub fn yoy_diff(&self) -> Result<NumericSeries, BojError> {
+        let lag = self.periods_per_year("year-over-year difference")?;
+        Ok(self.combine_lagged(lag, |current, previous| Some(current - previous)))
+    }
+
+    /// Natural log difference from the previous period, missing when either
+    /// value is not positive.
+    pub fn log_diff(&self) -> NumericSeries {
+        self.combine_lagged(1, |current, previous| {
+            (current > 0.0 && previous > 0.0).then(|| current.ln() - previous.ln())
+        })
+    }
+
+    /// Percent change from the previous period compounded to an annual rate,
+    /// `((x[t] / x[t-1]) ^ periods_per_year - 1) * 100`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] for daily series.
+    pub fn annualized_rate(&self) -> Result<NumericSeries, BojError> {
+        let periods = self.periods_per_year("annualized rate")?;
+        Ok(self.combine_lagged(1, |current, previous| {
+            (previous != 0.0).then(|| ((current / previous).powi(periods as i32) - 1.0) * 100.0)
+        }))
+    }
+
+    /// Rescales the series so the value of `base` becomes 100.
+    ///
+    /// `base` is either a period of this series or a longer period that
+    /// contains several of them, such as the calendar year `2020` of a
+    /// monthly index, in which case the base is their mean.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `base` covers no
+    /// observation of the series, an observation in it is missing, or the
+    /// base value is zero.
+    pub fn rebase(&self, base: Period) -> Result<NumericSeries, BojError> {
+        let in_base = self
+            .observations
+            .iter()
+            .filter(|observation| base.contains(observation.period))
+            .map(|observation| observation.value)
+            .collect::<Vec<_>>();
+        let invalid = |reason: &str| {
+            BojError::validation(format!(
+                "cannot rebase {} to {} {base}: {reason}",
+                self.series_code,
+                base.frequency().as_query_value()
+            ))
+        };
+        if in_base.is_empty() {
+            return Err(invalid("no observation in the base period"));
+        }
+        let values = in_base
+            .iter()
+            .copied()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid("the base period has missing values"))?;
+        let base_value = values.iter().sum::<f64>() / values.len() as f64;
+        if base_value == 0.0 {
+            return Err(invalid("the base value is zero"));
+        }
+        Ok(self.map_values(|_, observation| Some(observation.value? / base_value * 100.0)))
+    }
+
+    fn periods_per_year(&self, operation: &str) -> Result<u32, BojError> {
+        self.frequency.periods_per_year().ok_or_else(|| {
+            BojError::validation(format!(
+                "{operation} needs a fixed number of periods per year; {} is {}",
+                self.series_code,
+                self.frequency.as_query_value()
+            ))
+        })
+    }
+
+    /// Combines each value with the value `lag` periods earlier; missing
+    /// values on either side give a missing result.
+    fn combine_lagged(&self, lag: u32, combine: impl Fn(f64, f64) -> Option<f64>) -> NumericSeries {
+        let lag = lag as usize;
+        self.map_values(|index, observation| {
+            let previous = if self.frequency == Frequency::D {
+                self.observations.get(index.checked_sub(lag)?)?.value?
+            } else {
+                self.value_at(observation.period.offset(-(lag as i32)))?
+            };
+            combine(observation.value?, previous)
+        })
+    }
+}
+
+fn pct_change(current: f64, previous: f64) -> Option<f64> {
+    (previous != 0.0).then(|| (current / previous - 1.0) * 100.0)
+}
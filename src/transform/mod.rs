@@ -0,0 +1,13 @@
+mod align;
+mod change;
+mod period;
+mod resample;
+mod rolling;
+mod seasonal;
+mod series;
+
+pub use align::{AlignOptions, AlignedColumn, AlignedTable, CommonRange, FillPolicy, Join, align};
+pub use period::Period;
+pub use resample::{Aggregation, ResampleOptions};
+pub use seasonal::{Decomposition, DecompositionModel};
+pub use series::{NumericSeries, Observation};
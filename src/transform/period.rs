@@ -0,0 +1,214 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use crate::error::BojError;
+use crate::period::{date_from_days, days_since_epoch, period_span};
+use crate::query::Frequency;
+
+/// One BOJ period of a given frequency, such as the month `202401` or the
+/// fiscal year `2023`.
+///
+/// Periods of the same frequency are ordered in time and can be shifted with
+/// [`offset`](Self::offset). Labels follow the BOJ `SURVEY_DATES` format:
+///
+/// | Frequency | Label | Example |
+/// | --- | --- | --- |
+/// | `CY`, `FY` | `YYYY` | `2023` (FY: April 2023 to March 2024) |
+/// | `CH`, `FH` | `YYYYHH` | `202302` (FH: October 2023 to March 2024) |
+/// | `Q` | `YYYYQQ` | `202304` (October to December) |
+/// | `M` | `YYYYMM` | `202312` |
+/// | `W`, `D` | `YYYYMMDD` | `20231229` |
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::query::Frequency;
+/// use boj_client::transform::Period;
+///
+/// let march = Period::parse("202403", Frequency::M)?;
+/// assert_eq!(march.offset(-12).to_string(), "202303");
+/// assert_eq!(march.first_day(), 20240301);
+/// assert_eq!(Period::parse("202302", Frequency::Fh)?.last_day(), 20240331);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Period {
+    frequency: Frequency,
+    /// Years for `CY`/`FY`, halves, quarters or months since year 0, or days
+    /// since 1970-01-01 for `W`/`D`.
+    index: i32,
+}
+
+impl Period {
+    /// Parses a BOJ period label of `frequency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `label` is not a valid
+    /// period of `frequency`.
+    pub fn parse(label: &str, frequency: Frequency) -> Result<Self, BojError> {
+        Self::from_label(label, frequency).ok_or_else(|| {
+            BojError::validation(format!(
+                "invalid {} period label {label:?}",
+                frequency.as_query_value()
+            ))
+        })
+    }
+
+    pub(crate) fn from_label(label: &str, frequency: Frequency) -> Option<Self> {
+        let label = label.trim();
+        let expected_len = match frequency {
+            Frequency::Cy | Frequency::Fy => 4,
+            Frequency::W | Frequency::D => 8,
+            _ => 6,
+        };
+        if label.len() != expected_len {
+            return None;
+        }
+        let span = period_span(label, Some(frequency))?;
+        let year = (span.first / 10_000) as i32;
+        let month = (span.first / 100 % 100) as i32;
+        let index = match frequency {
+            Frequency::Cy | Frequency::Fy => label[..4].parse().ok()?,
+            Frequency::Ch | Frequency::Fh => {
+                label[..4].parse::<i32>().ok()? * 2 + label[4..].parse::<i32>().ok()? - 1
+            }
+            Frequency::Q => year * 4 + (month - 1) / 3,
+            Frequency::M => year * 12 + month - 1,
+            Frequency::W | Frequency::D => days_since_epoch(span.first),
+        };
+        Some(Self { frequency, index })
+    }
+
+    /// Returns the period containing the calendar day `date` (`YYYYMMDD`).
+    ///
+    /// For `W` this is the day itself, since BOJ labels weeks by a date
+    /// whose weekday depends on the series.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `date` is not a valid day.
+    pub fn containing(date: u32, frequency: Frequency) -> Result<Self, BojError> {
+        let day = Self::parse(&date.to_string(), Frequency::D)?;
+        let (year, month) = ((date / 10_000) as i32, (date / 100 % 100) as i32);
+        // Fiscal years start in April: January to March belong to the
+        // previous fiscal year.
+        let fiscal_year = if month >= 4 { year } else { year - 1 };
+        let index = match frequency {
+            Frequency::Cy => year,
+            Frequency::Fy => fiscal_year,
+            Frequency::Ch => year * 2 + i32::from(month > 6),
+            Frequency::Fh => fiscal_year * 2 + i32::from(!(4..=9).contains(&month)),
+            Frequency::Q => year * 4 + (month - 1) / 3,
+            Frequency::M => year * 12 + month - 1,
+            Frequency::W | Frequency::D => day.index,
+        };
+        Ok(Self { frequency, index })
+    }
+
+    /// Frequency of this period.
+    pub fn frequency(self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns the period `count` periods later (earlier when negative).
+    ///
+    /// Weekly periods move by seven days.
+    pub fn offset(self, count: i32) -> Self {
+        let step = if self.frequency == Frequency::W { 7 } else { 1 };
+        Self {
+            frequency: self.frequency,
+            index: self.index + count * step,
+        }
+    }
+
+    /// Number of periods from `earlier` to `self`, or `None` when the
+    /// frequencies differ.
+    pub fn periods_since(self, earlier: Self) -> Option<i32> {
+        if self.frequency != earlier.frequency {
+            return None;
+        }
+        let step = if self.frequency == Frequency::W { 7 } else { 1 };
+        Some((self.index - earlier.index).div_euclid(step))
+    }
+
+    /// First calendar day of the period as a `YYYYMMDD` integer.
+    pub fn first_day(self) -> u32 {
+        self.day_span().0
+    }
+
+    /// Last calendar day of the period as a `YYYYMMDD` integer.
+    ///
+    /// Weekly periods are single days, since BOJ labels each week by one
+    /// date.
+    pub fn last_day(self) -> u32 {
+        self.day_span().1
+    }
+
+    /// Returns `true` when every day of `other` falls inside this period.
+    pub fn contains(self, other: Self) -> bool {
+        let (first, last) = self.day_span();
+        let (other_first, other_last) = other.day_span();
+        first <= other_first && other_last <= last
+    }
+
+    fn day_span(self) -> (u32, u32) {
+        let label = self.to_string();
+        match period_span(&label, Some(self.frequency)) {
+            Some(span) => (span.first, span.last),
+            None => (0, 0),
+        }
+    }
+}
+
+impl fmt::Display for Period {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let index = self.index;
+        match self.frequency {
+            Frequency::Cy | Frequency::Fy => write!(f, "{index:04}"),
+            Frequency::Ch | Frequency::Fh => {
+                write!(
+                    f,
+                    "{:04}{:02}",
+                    index.div_euclid(2),
+                    index.rem_euclid(2) + 1
+                )
+            }
+            Frequency::Q => write!(
+                f,
+                "{:04}{:02}",
+                index.div_euclid(4),
+                index.rem_euclid(4) + 1
+            ),
+            Frequency::M => write!(
+                f,
+                "{:04}{:02}",
+                index.div_euclid(12),
+                index.rem_euclid(12) + 1
+            ),
+            Frequency::W | Frequency::D => write!(f, "{}", date_from_days(index)),
+        }
+    }
+}
+
+impl PartialOrd for Period {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Period {
+    /// Orders by frequency first, then in time.
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.frequency as u8, self.index).cmp(&(other.frequency as u8, other.index))
+    }
+}
+
+/// Serializes as the BOJ label; the frequency is carried by the series.
+impl Serialize for Period {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
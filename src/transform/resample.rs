@@ -0,0 +1,229 @@
+use crate::calendar::business_days;
+use crate::error::BojError;
+use crate::period::{days_since_epoch, weekday};
+use crate::query::Frequency;
+
+use super::period::Period;
+use super::series::{NumericSeries, Observation};
+
+/// How [`NumericSeries::resample`] combines the values inside each target
+/// period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregation {
+    /// Arithmetic mean, e.g. monthly averages of daily rates.
+    Mean,
+    /// Sum, e.g. quarterly totals of monthly flows.
+    Sum,
+    /// First observed value.
+    First,
+    /// Last observed value, e.g. end-of-period stocks.
+    Last,
+    /// Smallest value.
+    Min,
+    /// Largest value.
+    Max,
+}
+
+impl Aggregation {
+    fn apply(self, values: &[f64]) -> f64 {
+        match self {
+            Self::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Self::Sum => values.iter().sum(),
+            Self::First => values[0],
+            Self::Last => values[values.len() - 1],
+            Self::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Self::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+/// Options for [`NumericSeries::resample`].
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::transform::{Aggregation, ResampleOptions};
+///
+/// let options = ResampleOptions::new(Aggregation::Mean).with_min_coverage(0.8)?;
+/// assert_eq!(options.min_coverage(), 0.8);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResampleOptions {
+    aggregation: Aggregation,
+    min_coverage: f64,
+}
+
+impl ResampleOptions {
+    /// Creates options for `aggregation` that require every source period
+    /// to have a value.
+    pub fn new(aggregation: Aggregation) -> Self {
+        Self {
+            aggregation,
+            min_coverage: 1.0,
+        }
+    }
+
+    /// Sets the share of expected source periods that must have a value for
+    /// a target period to get one.
+    ///
+    /// Daily sources expect every Tokyo
+    /// [business day](crate::calendar::is_business_day), so a share below 1
+    /// tolerates market closures outside the holiday calendar.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] unless `share` is greater than 0
+    /// and at most 1.
+    pub fn with_min_coverage(mut self, share: f64) -> Result<Self, BojError> {
+        if !(share > 0.0 && share <= 1.0) {
+            return Err(BojError::validation(format!(
+                "min coverage must be greater than 0 and at most 1, got {share}"
+            )));
+        }
+        self.min_coverage = share;
+        Ok(self)
+    }
+
+    /// Returns the aggregation.
+    pub fn aggregation(&self) -> Aggregation {
+        self.aggregation
+    }
+
+    /// Returns the required share of source periods with a value.
+    pub fn min_coverage(&self) -> f64 {
+        self.min_coverage
+    }
+}
+
+impl NumericSeries {
+    /// Converts the series to the lower frequency `target`.
+    ///
+    /// Each observation goes to the target period containing it; weekly
+    /// observations go by their label date. Fiscal targets (`FY`, `FH`) run
+    /// from April, so monthly and quarterly series can be converted to them,
+    /// while calendar halves only convert to calendar years and fiscal halves
+    /// to fiscal years.
+    ///
+    /// The result covers every target period from the first to the last
+    /// observation. A target period is missing when fewer than
+    /// [`min_coverage`](ResampleOptions::min_coverage) of its expected source
+    /// periods have a value: every business day for daily sources, every week on
+    /// the same weekday for weekly sources, and otherwise the number of
+    /// source periods per target period (3 months per quarter, 12 per year).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `target` is not a lower
+    /// frequency whose periods are made of whole source periods, such as
+    /// converting to `W` or from `CH` to `FY`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boj_client::query::Frequency;
+    /// use boj_client::transform::{Aggregation, NumericSeries, Observation, Period, ResampleOptions};
+    ///
+    /// let first = Period::parse("202401", Frequency::M)?;
+    /// let observations = [1.0, 2.0, 3.0, 4.0]
+    ///     .into_iter()
+    ///     .enumerate()
+    ///     .map(|(index, value)| Observation { period: first.offset(index as i32), value: Some(value) })
+    ///     .collect();
+    /// let monthly = NumericSeries::new("TEST", Frequency::M, observations)?;
+    ///
+    /// let quarterly = monthly.resample(Frequency::Q, ResampleOptions::new(Aggregation::Sum))?;
+    /// assert_eq!(quarterly.observations[0].value, Some(6.0));
+    /// // Only one month of the second quarter is available.
+    /// assert_eq!(quarterly.observations[1].value, None);
+    /// # Ok::<(), boj_client::error::BojError>(())
+    /// ```
+    pub fn resample(
+        &self,
+        target: Frequency,
+        options: ResampleOptions,
+    ) -> Result<NumericSeries, BojError> {
+        if !nests_in(self.frequency, target) {
+            return Err(BojError::validation(format!(
+                "cannot resample {} from {} to {}",
+                self.series_code,
+                self.frequency.as_query_value(),
+                target.as_query_value()
+            )));
+        }
+        let (Some(first), Some(last)) = (self.observations.first(), self.observations.last())
+        else {
+            return NumericSeries::new(self.series_code.clone(), target, Vec::new());
+        };
+        let last_target = Period::containing(last.period.first_day(), target)?;
+        let label_weekday = weekday(days_since_epoch(first.period.first_day()));
+
+        let mut observations = Vec::new();
+        let mut rest = self.observations.as_slice();
+        let mut period = Period::containing(first.period.first_day(), target)?;
+        while period <= last_target {
+            let count = rest
+                .iter()
+                .take_while(|observation| period.contains(observation.period))
+                .count();
+            let (group, tail) = rest.split_at(count);
+            rest = tail;
+            let values = group
+                .iter()
+                .filter_map(|observation| observation.value)
+                .collect::<Vec<_>>();
+            let required =
+                (options.min_coverage * self.expected_count(period, label_weekday) as f64 - 1e-9)
+                    .ceil()
+                    .max(1.0) as usize;
+            observations.push(Observation {
+                period,
+                value: (values.len() >= required).then(|| options.aggregation.apply(&values)),
+            });
+            period = period.offset(1);
+        }
+        Ok(NumericSeries {
+            series_code: self.series_code.clone(),
+            frequency: target,
+            observations,
+        })
+    }
+
+    /// Number of source periods `target` should contain; `label_weekday` is
+    /// the day of the week of weekly labels.
+    fn expected_count(&self, target: Period, label_weekday: u32) -> usize {
+        let (first, last) = (target.first_day(), target.last_day());
+        match self.frequency {
+            Frequency::D => business_days(first, last).map_or(0, Iterator::count),
+            Frequency::W => (days_since_epoch(first)..=days_since_epoch(last))
+                .filter(|&day| weekday(day) == label_weekday)
+                .count(),
+            source => match (
+                source.periods_per_year(),
+                target.frequency().periods_per_year(),
+            ) {
+                (Some(source), Some(target)) => (source / target) as usize,
+                _ => 1,
+            },
+        }
+    }
+}
+
+/// Returns `true` when every period of `target` is made of whole periods of
+/// `source`.
+pub(super) fn nests_in(source: Frequency, target: Frequency) -> bool {
+    let years_or_halves = matches!(
+        target,
+        Frequency::Ch | Frequency::Fh | Frequency::Cy | Frequency::Fy
+    );
+    match source {
+        Frequency::D | Frequency::W => {
+            years_or_halves || matches!(target, Frequency::Q | Frequency::M)
+        }
+        Frequency::M => years_or_halves || target == Frequency::Q,
+        Frequency::Q => years_or_halves,
+        Frequency::Ch => target == Frequency::Cy,
+        Frequency::Fh => target == Frequency::Fy,
+        Frequency::Cy | Frequency::Fy => false,
+    }
+}
@@ -0,0 +1,79 @@
+use crate::error::BojError;
+use crate::query::Frequency;
+
+use super::series::NumericSeries;
+
+impl NumericSeries {
+    /// Mean of the last `window` periods, including the current one.
+    ///
+    /// A window with a missing value or a period absent from the series is
+    /// missing, as are the first `window - 1` results. Daily series use the
+    /// last `window` observations instead, since BOJ daily series only have
+    /// business days.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `window` is zero.
+    pub fn rolling_mean(&self, window: usize) -> Result<NumericSeries, BojError> {
+        self.rolling(window, |values| {
+            Some(values.iter().sum::<f64>() / values.len() as f64)
+        })
+    }
+
+    /// Sum of the last `window` periods, including the current one, with
+    /// windows built as in [`NumericSeries::rolling_mean`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `window` is zero.
+    pub fn rolling_sum(&self, window: usize) -> Result<NumericSeries, BojError> {
+        self.rolling(window, |values| Some(values.iter().sum()))
+    }
+
+    /// Sample standard deviation (`n - 1` denominator) of the last `window`
+    /// periods, so a window of one gives missing values. Windows are built as
+    /// in [`NumericSeries::rolling_mean`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when `window` is zero.
+    pub fn rolling_std(&self, window: usize) -> Result<NumericSeries, BojError> {
+        self.rolling(window, |values| {
+            let count = values.len() as f64;
+            if values.len() < 2 {
+                return None;
+            }
+            let mean = values.iter().sum::<f64>() / count;
+            let squares = values
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>();
+            Some((squares / (count - 1.0)).sqrt())
+        })
+    }
+
+    fn rolling(
+        &self,
+        window: usize,
+        statistic: impl Fn(&[f64]) -> Option<f64>,
+    ) -> Result<NumericSeries, BojError> {
+        if window == 0 {
+            return Err(BojError::validation("rolling window must be at least 1"));
+        }
+        let mut values = Vec::with_capacity(window);
+        Ok(self.map_values(|index, observation| {
+            values.clear();
+            if self.frequency == Frequency::D {
+                let start = (index + 1).checked_sub(window)?;
+                for observation in &self.observations[start..=index] {
+                    values.push(observation.value?);
+                }
+            } else {
+                for lag in (0..window).rev() {
+                    values.push(self.value_at(observation.period.offset(-(lag as i32)))?);
+                }
+            }
+            statistic(&values)
+        }))
+    }
+}
@@ -0,0 +1,206 @@
+use serde::Serialize;
+
+use crate::error::BojError;
+use crate::query::Frequency;
+
+use super::series::NumericSeries;
+
+/// How the components of a [`Decomposition`] combine into the series.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecompositionModel {
+    /// `value = trend + seasonal + residual`, for series whose seasonal
+    /// swings do not grow with their level.
+    #[default]
+    Additive,
+    /// `value = trend * seasonal * residual`, for positive series whose
+    /// seasonal swings are proportional to their level.
+    Multiplicative,
+}
+
+impl DecompositionModel {
+    /// Removes `component` from `value`.
+    fn remove(self, value: f64, component: f64) -> f64 {
+        match self {
+            Self::Additive => value - component,
+            Self::Multiplicative => value / component,
+        }
+    }
+}
+
+/// Components of a series returned by [`NumericSeries::decompose`], each with
+/// the periods of the original series.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Decomposition {
+    /// Centered moving average over one year; missing for the first and last
+    /// half year.
+    pub trend: NumericSeries,
+    /// Seasonal factor of each period's month or quarter, summing to 0 over
+    /// a year (additive) or averaging 1 (multiplicative).
+    pub seasonal: NumericSeries,
+    /// What remains after removing trend and seasonal components.
+    pub residual: NumericSeries,
+    /// The series with the seasonal component removed.
+    pub seasonally_adjusted: NumericSeries,
+}
+
+impl NumericSeries {
+    /// Splits a monthly or quarterly series into trend, seasonal and residual
+    /// components with classical moving-average decomposition.
+    ///
+    /// The trend is a centered 2×12 (monthly) or 2×4 (quarterly) moving
+    /// average, missing when its window has a missing value. The seasonal
+    /// factor of each month or quarter is the mean of the detrended values
+    /// of that season, normalized over the year. The factors are fixed over
+    /// the whole series, so use a span where the seasonal pattern is stable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when the series is not monthly
+    /// or quarterly, has fewer than two years of values, leaves a season
+    /// without any detrended value, or has a non-positive value under the
+    /// multiplicative model.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boj_client::query::Frequency;
+    /// use boj_client::transform::{DecompositionModel, NumericSeries, Observation, Period};
+    ///
+    /// // A rising series that is 2 higher every fourth quarter.
+    /// let first = Period::parse("202001", Frequency::Q)?;
+    /// let observations = (0..12)
+    ///     .map(|index| Observation {
+    ///         period: first.offset(index),
+    ///         value: Some(100.0 + f64::from(index) + if index % 4 == 3 { 2.0 } else { 0.0 }),
+    ///     })
+    ///     .collect();
+    /// let series = NumericSeries::new("TEST", Frequency::Q, observations)?;
+    ///
+    /// let decomposition = series.decompose(DecompositionModel::Additive)?;
+    /// let fourth_quarter = decomposition.seasonal.observations[3].value.unwrap();
+    /// assert!((fourth_quarter - 1.5).abs() < 1e-9);
+    /// let adjusted = decomposition.seasonally_adjusted.observations[3].value.unwrap();
+    /// assert!((adjusted - 103.5).abs() < 1e-9);
+    /// # Ok::<(), boj_client::error::BojError>(())
+    /// ```
+    pub fn decompose(&self, model: DecompositionModel) -> Result<Decomposition, BojError> {
+        let cycle = match self.frequency {
+            Frequency::M => 12,
+            Frequency::Q => 4,
+            other => {
+                return Err(BojError::validation(format!(
+                    "seasonal decomposition needs a monthly or quarterly series; {} is {}",
+                    self.series_code,
+                    other.as_query_value()
+                )));
+            }
+        };
+        let invalid = |reason: &str| {
+            BojError::validation(format!("cannot decompose {}: {reason}", self.series_code))
+        };
+        let observed = self
+            .observations
+            .iter()
+            .filter(|observation| observation.value.is_some())
+            .count();
+        if observed < 2 * cycle {
+            return Err(invalid("at least two years of values are needed"));
+        }
+        if model == DecompositionModel::Multiplicative
+            && self
+                .observations
+                .iter()
+                .any(|observation| observation.value.is_some_and(|value| value <= 0.0))
+        {
+            return Err(invalid("the multiplicative model needs positive values"));
+        }
+
+        // Values on a gapless period index, so positions are periods.
+        let first = self.observations[0].period;
+        let positions = self
+            .observations
+            .iter()
+            .map(|observation| {
+                observation
+                    .period
+                    .periods_since(first)
+                    .map(|position| position as usize)
+                    .ok_or_else(|| invalid("periods have mixed frequencies"))
+            })
+            .collect::<Result<Vec<_>, BojError>>()?;
+        let mut values = vec![None; positions[positions.len() - 1] + 1];
+        for (observation, &position) in self.observations.iter().zip(&positions) {
+            values[position] = observation.value;
+        }
+
+        let trend = centered_moving_average(&values, cycle);
+
+        let first_month = first.first_day() / 100 % 100;
+        let first_season = (first_month as usize - 1) / (12 / cycle);
+        let season = |index: usize| (first_season + index) % cycle;
+        let mut sums = vec![0.0; cycle];
+        let mut counts = vec![0_usize; cycle];
+        for (index, (value, trend)) in values.iter().zip(&trend).enumerate() {
+            if let (Some(value), Some(trend)) = (value, trend) {
+                sums[season(index)] += model.remove(*value, *trend);
+                counts[season(index)] += 1;
+            }
+        }
+        if counts.contains(&0) {
+            return Err(invalid("a season has no value with a defined trend"));
+        }
+        let raw = sums
+            .iter()
+            .zip(&counts)
+            .map(|(sum, &count)| sum / count as f64)
+            .collect::<Vec<_>>();
+        let mean = raw.iter().sum::<f64>() / cycle as f64;
+        let factors = raw
+            .iter()
+            .map(|factor| match model {
+                DecompositionModel::Additive => factor - mean,
+                DecompositionModel::Multiplicative => factor / mean,
+            })
+            .collect::<Vec<_>>();
+
+        let component = |value: &dyn Fn(usize) -> Option<f64>| {
+            self.map_values(|index, _| value(positions[index]))
+        };
+        Ok(Decomposition {
+            trend: component(&|index| trend[index]),
+            seasonal: component(&|index| Some(factors[season(index)])),
+            residual: component(&|index| {
+                let detrended = model.remove(values[index]?, trend[index]?);
+                Some(model.remove(detrended, factors[season(index)]))
+            }),
+            seasonally_adjusted: component(&|index| {
+                Some(model.remove(values[index]?, factors[season(index)]))
+            }),
+        })
+    }
+}
+
+/// Centered moving average over `cycle` periods with half weights on both
+/// ends (a 2×`cycle` average), missing near the ends and where the window
+/// has a missing value.
+fn centered_moving_average(values: &[Option<f64>], cycle: usize) -> Vec<Option<f64>> {
+    let half = cycle / 2;
+    (0..values.len())
+        .map(|index| {
+            if index < half || index + half >= values.len() {
+                return None;
+            }
+            let window = &values[index - half..=index + half];
+            let mut sum = 0.0;
+            for (offset, value) in window.iter().enumerate() {
+                let weight = if offset == 0 || offset == cycle {
+                    0.5
+                } else {
+                    1.0
+                };
+                sum += weight * (*value)?;
+            }
+            Some(sum / cycle as f64)
+        })
+        .collect()
+}
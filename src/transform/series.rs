@@ -0,0 +1,215 @@
+use serde::Serialize;
+
+use crate::error::BojError;
+use crate::model::{DataPoint, TimeSeries};
+use crate::query::Frequency;
+
+use super::period::Period;
+
+/// One observation of a [`NumericSeries`]; `value` is `None` when BOJ
+/// reports no value or a transformation has no result for the period.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Observation {
+    /// Period of the observation.
+    pub period: Period,
+    /// Observed or computed value.
+    pub value: Option<f64>,
+}
+
+/// A series with parsed periods and numeric values, ordered by period.
+///
+/// Build it from a response series with
+/// [`from_series`](Self::from_series); the transformations in this module
+/// return new `NumericSeries` with the same periods, so they can be chained.
+///
+/// # Examples
+///
+/// ```
+/// use boj_client::model::{CodeSeries, DataPoint};
+/// use boj_client::transform::NumericSeries;
+///
+/// let series = CodeSeries {
+///     series_code: "PRCG20_2200000000".to_string(),
+///     name_of_time_series_j: None,
+///     name_of_time_series: None,
+///     unit_j: None,
+///     unit: None,
+///     frequency: Some("MONTHLY".to_string()),
+///     category_j: None,
+///     category: None,
+///     last_update: None,
+///     points: vec![
+///         DataPoint { survey_date: "202401".to_string(), value: Some("100".to_string()) },
+///         DataPoint { survey_date: "202402".to_string(), value: Some("102".to_string()) },
+///     ],
+///     extras: Default::default(),
+/// };
+///
+/// let series = NumericSeries::from_series(&series)?;
+/// let change = series.pct_change();
+/// assert_eq!(change.observations[0].value, None);
+/// assert!((change.observations[1].value.unwrap() - 2.0).abs() < 1e-9);
+/// # Ok::<(), boj_client::error::BojError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NumericSeries {
+    /// Time-series code.
+    pub series_code: String,
+    /// Frequency of every period in `observations`.
+    pub frequency: Frequency,
+    /// Observations in period order, at most one per period.
+    pub observations: Vec<Observation>,
+}
+
+impl NumericSeries {
+    /// Creates a series, sorting `observations` by period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::ValidationError`] when an observation has a
+    /// different frequency or two observations share a period.
+    pub fn new(
+        series_code: impl Into<String>,
+        frequency: Frequency,
+        mut observations: Vec<Observation>,
+    ) -> Result<Self, BojError> {
+        let series_code = series_code.into();
+        if let Some(observation) = observations
+            .iter()
+            .find(|observation| observation.period.frequency() != frequency)
+        {
+            return Err(BojError::validation(format!(
+                "period {} of {series_code} is not {}",
+                observation.period,
+                frequency.as_query_value()
+            )));
+        }
+        observations.sort_by_key(|observation| observation.period);
+        if let Some(pair) = observations
+            .windows(2)
+            .find(|pair| pair[0].period == pair[1].period)
+        {
+            return Err(BojError::validation(format!(
+                "period {} of {series_code} appears more than once",
+                pair[0].period
+            )));
+        }
+        Ok(Self {
+            series_code,
+            frequency,
+            observations,
+        })
+    }
+
+    /// Parses the periods and values of a response series, taking the
+    /// frequency from its `FREQUENCY` label.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::DecodeError`] when the frequency label is missing
+    /// or unknown, a survey date is not a period of that frequency, or a
+    /// value is not a number.
+    pub fn from_series<T: TimeSeries>(series: &T) -> Result<Self, BojError> {
+        let frequency = series
+            .frequency()
+            .and_then(Frequency::from_label)
+            .ok_or_else(|| {
+                BojError::decode(format!(
+                    "series {} has no known frequency: {:?}",
+                    series.series_code(),
+                    series.frequency()
+                ))
+            })?;
+        Self::from_series_with_frequency(series, frequency)
+    }
+
+    /// Parses the periods and values of a response series of `frequency`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BojError::DecodeError`] when a survey date is not a period
+    /// of `frequency` or a value is not a number.
+    pub fn from_series_with_frequency<T: TimeSeries>(
+        series: &T,
+        frequency: Frequency,
+    ) -> Result<Self, BojError> {
+        let observations = series
+            .points()
+            .iter()
+            .map(|point| {
+                let period =
+                    Period::from_label(&point.survey_date, frequency).ok_or_else(|| {
+                        BojError::decode(format!(
+                            "survey date {:?} of {} is not a {} period",
+                            point.survey_date,
+                            series.series_code(),
+                            frequency.as_query_value()
+                        ))
+                    })?;
+                Ok(Observation {
+                    period,
+                    value: point.numeric_value()?,
+                })
+            })
+            .collect::<Result<Vec<_>, BojError>>()?;
+        Self::new(series.series_code(), frequency, observations)
+            .map_err(|error| BojError::decode(error.to_string()))
+    }
+
+    /// Returns the value observed in `period`, if any.
+    pub fn value_at(&self, period: Period) -> Option<f64> {
+        let index = self
+            .observations
+            .binary_search_by_key(&period, |observation| observation.period)
+            .ok()?;
+        self.observations[index].value
+    }
+
+    /// First and last period with a value, or `None` when every value is
+    /// missing.
+    pub fn observed_range(&self) -> Option<(Period, Period)> {
+        let mut observed = self
+            .observations
+            .iter()
+            .filter(|observation| observation.value.is_some());
+        let first = observed.next()?.period;
+        let last = observed
+            .next_back()
+            .map_or(first, |observation| observation.period);
+        Some((first, last))
+    }
+
+    /// Converts back into BOJ data points, formatting values with the
+    /// shortest representation that parses back to the same `f64`.
+    pub fn to_points(&self) -> Vec<DataPoint> {
+        self.observations
+            .iter()
+            .map(|observation| DataPoint {
+                survey_date: observation.period.to_string(),
+                value: observation.value.map(|value| value.to_string()),
+            })
+            .collect()
+    }
+
+    /// Returns a series with the same code, frequency and periods and the
+    /// values computed by `value`, which also receives the position of each
+    /// observation. Non-finite results become missing values.
+    pub(crate) fn map_values(
+        &self,
+        mut value: impl FnMut(usize, &Observation) -> Option<f64>,
+    ) -> Self {
+        Self {
+            series_code: self.series_code.clone(),
+            frequency: self.frequency,
+            observations: self
+                .observations
+                .iter()
+                .enumerate()
+                .map(|(index, observation)| Observation {
+                    period: observation.period,
+                    value: value(index, observation).filter(|value| value.is_finite()),
+                })
+                .collect(),
+        }
+    }
+}
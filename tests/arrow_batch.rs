@@ -0,0 +1,111 @@
+#![cfg(feature = "arrow")]
+
+mod common;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Float64Type;
+use boj_client::arrow::{DB_KEY, NAME_J_KEY, SERIES_KEY, UNIT_J_KEY};
+use boj_client::query::{Format, Frequency, LayerQuery};
+use common::{StubServer, client_for, fetch_fm08_pages, json_response};
+
+fn strings(batch: &arrow_array::RecordBatch, column: &str) -> Vec<String> {
+    batch
+        .column_by_name(column)
+        .unwrap()
+        .as_string::<i32>()
+        .iter()
+        .map(|value| value.unwrap().to_string())
+        .collect()
+}
+
+fn floats(batch: &arrow_array::RecordBatch, column: &str) -> Vec<Option<f64>> {
+    batch
+        .column_by_name(column)
+        .unwrap()
+        .as_primitive::<Float64Type>()
+        .iter()
+        .collect()
+}
+
+#[test]
+fn converts_merged_pages_to_long_batch() {
+    let batch = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"])
+        .to_long_record_batch()
+        .unwrap();
+
+    assert_eq!(batch.num_rows(), 5);
+    assert_eq!(
+        strings(&batch, "series_code"),
+        vec!["FXERD01", "FXERD01", "FXERD04", "FXERD04", "FXERD09"]
+    );
+    assert_eq!(
+        strings(&batch, "period"),
+        vec!["20240104", "20240105", "20240104", "20240105", "20240105"]
+    );
+    assert_eq!(
+        floats(&batch, "value"),
+        vec![Some(143.15), Some(144.62), Some(143.69), None, Some(145.97)]
+    );
+
+    let metadata = batch.schema().metadata().clone();
+    assert_eq!(metadata[DB_KEY], "FM08");
+    let series: serde_json::Value = serde_json::from_str(&metadata[SERIES_KEY]).unwrap();
+    assert_eq!(series["FXERD09"][UNIT_J_KEY], "円");
+    assert_eq!(
+        series["FXERD04"][NAME_J_KEY],
+        "東京市場 ドル・円 スポット 17時時点"
+    );
+}
+
+#[test]
+fn converts_merged_pages_to_wide_batch_with_column_metadata() {
+    let batch = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"])
+        .to_wide_record_batch()
+        .unwrap();
+
+    let schema = batch.schema();
+    let names = schema
+        .fields()
+        .iter()
+        .map(|field| field.name().as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["period", "FXERD01", "FXERD04", "FXERD09"]);
+    assert_eq!(strings(&batch, "period"), vec!["20240104", "20240105"]);
+    assert_eq!(floats(&batch, "FXERD04"), vec![Some(143.69), None]);
+    assert_eq!(floats(&batch, "FXERD09"), vec![None, Some(145.97)]);
+
+    let field = schema.field_with_name("FXERD01").unwrap();
+    assert_eq!(field.metadata()[UNIT_J_KEY], "円");
+    assert_eq!(field.metadata()["boj:frequency"], "DAILY");
+    assert_eq!(field.metadata()["boj:last_update"], "20260219");
+    assert!(!field.metadata().contains_key("boj:unit"));
+}
+
+#[test]
+fn converts_layer_response_and_reports_invalid_values() {
+    let server = StubServer::serve_once(json_response(
+        "tests/fixtures/json_success_layer_api_wildcard.json",
+    ));
+    let client = client_for(&server);
+    let query = LayerQuery::new("MD10", Frequency::Q, vec!["*".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+    let mut response = client.get_data_layer(&query).unwrap();
+    let _ = server.finish();
+
+    let long = response.to_long_record_batch().unwrap();
+    let wide = response.to_wide_record_batch().unwrap();
+    assert_eq!(wide.num_columns(), response.series.len() + 1);
+    assert_eq!(
+        long.num_rows(),
+        response
+            .series
+            .iter()
+            .map(|series| series.points.len())
+            .sum::<usize>()
+    );
+
+    response.series[0].points[0].value = Some("n/a".to_string());
+    let error = response.to_long_record_batch().unwrap_err();
+    assert!(error.to_string().contains("non-numeric value \"n/a\""));
+}
@@ -0,0 +1,249 @@
+use boj_client::calendar::{
+    QueryRange, business_days, expand, fiscal_quarter, holiday, holidays, is_bank_holiday,
+    is_business_day, missing_business_days, overlapping, roll_backward, roll_forward,
+    to_business_days, to_frequency,
+};
+use boj_client::error::BojError;
+use boj_client::query::{Frequency, LayerQuery};
+use boj_client::transform::{NumericSeries, Observation, Period};
+
+fn period(label: &str, frequency: Frequency) -> Period {
+    Period::parse(label, frequency).unwrap()
+}
+
+fn labels(periods: &[Period]) -> Vec<String> {
+    periods.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn maps_months_and_quarters_to_fiscal_periods() {
+    for (month, fiscal_year, fiscal_half, quarter) in [
+        ("202303", "2022", "202202", 4),
+        ("202304", "2023", "202301", 1),
+        ("202309", "2023", "202301", 2),
+        ("202310", "2023", "202302", 3),
+        ("202312", "2023", "202302", 3),
+    ] {
+        let month = period(month, Frequency::M);
+        assert_eq!(
+            to_frequency(month, Frequency::Fy).unwrap().to_string(),
+            fiscal_year
+        );
+        assert_eq!(
+            to_frequency(month, Frequency::Fh).unwrap().to_string(),
+            fiscal_half
+        );
+        assert_eq!(fiscal_quarter(month).unwrap(), quarter);
+    }
+
+    let fiscal_half = period("202302", Frequency::Fh);
+    assert_eq!(
+        to_frequency(fiscal_half, Frequency::Fy)
+            .unwrap()
+            .to_string(),
+        "2023"
+    );
+    assert!(matches!(
+        to_frequency(fiscal_half, Frequency::Cy),
+        Err(BojError::ValidationError(_))
+    ));
+    assert!(fiscal_quarter(fiscal_half).is_err());
+}
+
+#[test]
+fn expands_fiscal_periods_into_whole_calendar_periods() {
+    let fiscal_half = period("202302", Frequency::Fh);
+    assert_eq!(
+        labels(&expand(fiscal_half, Frequency::M).unwrap()),
+        ["202310", "202311", "202312", "202401", "202402", "202403"]
+    );
+    assert_eq!(
+        labels(&expand(fiscal_half, Frequency::Q).unwrap()),
+        ["202304", "202401"]
+    );
+    assert_eq!(
+        expand(period("2024", Frequency::Fy), Frequency::D)
+            .unwrap()
+            .len(),
+        365
+    );
+
+    assert!(expand(period("2023", Frequency::Fy), Frequency::Ch).is_err());
+    assert!(expand(period("2023", Frequency::Fy), Frequency::W).is_err());
+    assert_eq!(
+        labels(&overlapping(period("2023", Frequency::Fy), Frequency::Ch).unwrap()),
+        ["202301", "202302", "202401"]
+    );
+}
+
+#[test]
+fn converts_fiscal_ranges_to_query_dates_of_the_series_frequency() {
+    let fy2023 = period("2023", Frequency::Fy);
+    for (frequency, start, end) in [
+        (Frequency::M, "202304", "202403"),
+        (Frequency::D, "202304", "202403"),
+        (Frequency::Q, "202302", "202401"),
+        (Frequency::Fh, "202301", "202302"),
+        (Frequency::Ch, "202301", "202401"),
+        (Frequency::Cy, "2023", "2024"),
+        (Frequency::Fy, "2023", "2023"),
+    ] {
+        let range = QueryRange::for_period(fy2023, frequency).unwrap();
+        assert_eq!(
+            (range.start().to_string(), range.end().to_string()),
+            (start.to_string(), end.to_string()),
+            "{frequency:?}"
+        );
+    }
+
+    let range = QueryRange::between(
+        period("2022", Frequency::Fy),
+        period("2023", Frequency::Fy),
+        Frequency::Q,
+    )
+    .unwrap();
+    assert_eq!(range.start().to_string(), "202202");
+    assert_eq!(range.end().to_string(), "202401");
+    // Layer queries check dates against the frequency, so quarterly labels pass.
+    LayerQuery::new("CO", Frequency::Q, vec!["*".to_string()])
+        .unwrap()
+        .with_start_date(range.start().to_string())
+        .unwrap()
+        .with_end_date(range.end().to_string())
+        .unwrap();
+
+    assert!(QueryRange::between(fy2023, period("202203", Frequency::M), Frequency::M).is_err());
+}
+
+fn holiday_dates(year: u32) -> Vec<u32> {
+    holidays(year).iter().map(|holiday| holiday.date).collect()
+}
+
+#[test]
+fn holidays_match_cabinet_office_lists() {
+    assert_eq!(
+        holiday_dates(2019),
+        [
+            20190101, 20190114, 20190211, 20190321, 20190429, 20190430, 20190501, 20190502,
+            20190503, 20190504, 20190505, 20190506, 20190715, 20190811, 20190812, 20190916,
+            20190923, 20191014, 20191022, 20191103, 20191104, 20191123,
+        ]
+    );
+    assert_eq!(
+        holiday_dates(2020),
+        [
+            20200101, 20200113, 20200211, 20200223, 20200224, 20200320, 20200429, 20200503,
+            20200504, 20200505, 20200506, 20200723, 20200724, 20200810, 20200921, 20200922,
+            20201103, 20201123,
+        ]
+    );
+    assert_eq!(
+        holiday_dates(2024),
+        [
+            20240101, 20240108, 20240211, 20240212, 20240223, 20240320, 20240429, 20240503,
+            20240504, 20240505, 20240506, 20240715, 20240811, 20240812, 20240916, 20240922,
+            20240923, 20241014, 20241103, 20241104, 20241123,
+        ]
+    );
+    assert_eq!(
+        holiday_dates(2025),
+        [
+            20250101, 20250113, 20250211, 20250223, 20250224, 20250320, 20250429, 20250503,
+            20250504, 20250505, 20250506, 20250721, 20250811, 20250915, 20250923, 20251013,
+            20251103, 20251123, 20251124,
+        ]
+    );
+    // Equinoxes before 1980 use a different formula.
+    assert_eq!(
+        holiday_dates(1960),
+        [
+            19600101, 19600115, 19600320, 19600429, 19600503, 19600505, 19600923, 19601103,
+            19601123,
+        ]
+    );
+    assert_eq!(
+        holiday_dates(1978),
+        [
+            19780101, 19780102, 19780115, 19780116, 19780211, 19780321, 19780429, 19780503,
+            19780505, 19780915, 19780923, 19781010, 19781103, 19781123,
+        ]
+    );
+}
+
+#[test]
+fn applies_substitute_and_citizens_holiday_rules_of_each_era() {
+    // Since 2007 a substitute moves past following holidays.
+    assert_eq!(holiday(20080506).unwrap().name, "Substitute Holiday");
+    // Until 2006 May 4 was a citizens' holiday between two holidays.
+    assert_eq!(holiday(20050504).unwrap().name_j, "国民の休日");
+    assert_eq!(holiday(20090922).unwrap().name, "Citizens' Holiday");
+    assert_eq!(holiday(19890224).unwrap().name_j, "昭和天皇の大喪の礼");
+    assert_eq!(holiday(19881223), None);
+    assert_eq!(holiday(20181223).unwrap().name, "The Emperor's Birthday");
+    assert!(holidays(1948).is_empty());
+}
+
+#[test]
+fn iterates_business_days_around_bank_holidays() {
+    assert!(is_bank_holiday(20241231));
+    assert!(!is_bank_holiday(20240104));
+    assert!(!is_business_day(20240230));
+
+    let days = business_days(20241227, 20250110)
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(
+        days,
+        [
+            20241227, 20241230, 20250106, 20250107, 20250108, 20250109, 20250110
+        ]
+    );
+    assert_eq!(business_days(20240101, 20241231).unwrap().count(), 245);
+    assert_eq!(roll_forward(20240504).unwrap(), 20240507);
+    assert_eq!(roll_backward(20240506).unwrap(), 20240502);
+    assert!(matches!(
+        business_days(20240132, 20240201),
+        Err(BojError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn finds_and_fills_missing_business_days_of_daily_series() {
+    let observations = [
+        ("20240425", Some(0.07)),
+        ("20240426", Some(0.08)),
+        ("20240430", None),
+        ("20240502", Some(0.08)),
+        // A weekend observation is not a business day.
+        ("20240504", Some(1.0)),
+        ("20240507", Some(0.07)),
+    ]
+    .into_iter()
+    .map(|(label, value)| Observation {
+        period: period(label, Frequency::D),
+        value,
+    })
+    .collect();
+    let series = NumericSeries::new("FM01", Frequency::D, observations).unwrap();
+
+    let missing = missing_business_days(&series).unwrap();
+    assert_eq!(labels(&missing), ["20240430", "20240501"]);
+
+    let reindexed = to_business_days(&series).unwrap();
+    assert_eq!(
+        labels(
+            &reindexed
+                .observations
+                .iter()
+                .map(|observation| observation.period)
+                .collect::<Vec<_>>()
+        ),
+        [
+            "20240425", "20240426", "20240430", "20240501", "20240502", "20240507"
+        ]
+    );
+    assert_eq!(reindexed.value_at(period("20240501", Frequency::D)), None);
+
+    let monthly = NumericSeries::new("M", Frequency::M, Vec::new()).unwrap();
+    assert!(missing_business_days(&monthly).is_err());
+}
@@ -4,6 +4,7 @@ use std::io::Write;
 
 use boj_client::client::BojClient;
 use boj_client::error::BojError;
+use boj_client::model::BilingualMismatch;
 use boj_client::query::{CodeQuery, Format, Language, MetadataQuery};
 use common::{StubResponse, StubServer, fixture_bytes};
 use flate2::Compression;
@@ -94,6 +95,199 @@ fn metadata_response_is_typed() {
     assert!(!response.entries.is_empty());
 }
 
+#[test]
+fn bilingual_code_response_merges_labels_by_series_code() {
+    let server = StubServer::serve_sequence(vec![
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_code_api.json"),
+            "application/json",
+        ),
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_code_api_en.json"),
+            "application/json",
+        ),
+    ]);
+    let client = BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string());
+    let query = CodeQuery::new("CO", vec!["TK99F1000601GCQ01000".to_string()])
+        .unwrap()
+        .with_format(Format::Json)
+        .with_lang(Language::En);
+
+    let response = client.get_data_code_bilingual(&query).unwrap();
+    let requests = server.finish_all();
+
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].target.contains("lang=jp"));
+    assert!(requests[1].target.contains("lang=en"));
+    assert!(response.is_consistent());
+    let series = &response.merged.series[0];
+    assert_eq!(
+        series.name_of_time_series_j.as_deref(),
+        Some("D.I./業況/大企業/製造業/実績")
+    );
+    assert_eq!(
+        series.name_of_time_series.as_deref(),
+        Some("D.I./Business Conditions/Large Enterprises/Manufacturing/Actual result")
+    );
+    assert_eq!(series.unit_j.as_deref(), Some("%ポイント"));
+    assert_eq!(series.unit.as_deref(), Some("% points"));
+    assert_eq!(series.category.as_deref(), Some("TANKAN/Judgement Survey"));
+    assert_eq!(response.english_meta.message, "Successfully completed");
+}
+
+#[test]
+fn bilingual_metadata_response_fills_names_units_and_notes() {
+    let server = StubServer::serve_sequence(vec![
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_metadata_api_jp.json"),
+            "application/json",
+        ),
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/csv_success_metadata_en_utf8.csv"),
+            "text/csv",
+        ),
+    ]);
+    let client = BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string());
+    let query = MetadataQuery::new("FM08").unwrap();
+
+    let response = client.get_metadata_bilingual(&query).unwrap();
+    let _ = server.finish_all();
+
+    assert!(response.is_consistent());
+    let header = &response.merged.entries[0];
+    assert_eq!(
+        header.name_of_time_series_j.as_deref(),
+        Some("外国為替市況（日次）")
+    );
+    assert_eq!(
+        header.name_of_time_series.as_deref(),
+        Some("Foreign Exchange Rates (Daily)")
+    );
+    let entry = &response.merged.entries[1];
+    assert_eq!(entry.notes_j.as_deref(), Some("注釈"));
+    assert_eq!(entry.notes.as_deref(), Some("Central rates"));
+    assert_eq!(entry.unit_j.as_deref(), Some("円"));
+    assert_eq!(entry.unit.as_deref(), Some("Yen per U.S. Dollar"));
+}
+
+#[test]
+fn bilingual_code_response_reports_missing_series() {
+    let server = StubServer::serve_sequence(vec![
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_code_api.json"),
+            "application/json",
+        ),
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_no_data.json"),
+            "application/json",
+        ),
+    ]);
+    let client = BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string());
+    let query = CodeQuery::new("CO", vec!["TK99F1000601GCQ01000".to_string()]).unwrap();
+
+    let response = client.get_data_code_bilingual(&query).unwrap();
+    let _ = server.finish_all();
+
+    assert_eq!(
+        response.mismatches,
+        vec![BilingualMismatch::MissingSeries {
+            key: "TK99F1000601GCQ01000".to_string(),
+            missing_in: Language::En,
+        }]
+    );
+    assert_eq!(response.merged.series.len(), 1);
+}
+
+#[test]
+fn paginated_code_response_follows_next_position() {
+    let server = StubServer::serve_sequence(vec![
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_code_api_page1.json"),
+            "application/json",
+        ),
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_code_api_page2.json"),
+            "application/json",
+        ),
+    ]);
+    let client = BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string());
+    let codes = ["FXERD01", "FXERD04", "FXERD09"]
+        .iter()
+        .map(|code| code.to_string())
+        .collect();
+    let query = CodeQuery::new("FM08", codes)
+        .unwrap()
+        .with_format(Format::Json);
+
+    let response = client.get_data_code_all(&query).unwrap();
+    let requests = server.finish_all();
+
+    assert_eq!(requests.len(), 2);
+    assert!(!requests[0].target.contains("startPosition"));
+    assert!(requests[1].target.contains("startPosition=3"));
+    assert_eq!(response.next_position, None);
+    assert_eq!(
+        response.meta.date.as_deref(),
+        Some("2026-02-19T12:38:18.251+09:00")
+    );
+    let codes = response
+        .series
+        .iter()
+        .map(|series| series.series_code.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, vec!["FXERD01", "FXERD04", "FXERD09"]);
+    let dates = response.series[1]
+        .points
+        .iter()
+        .map(|point| point.survey_date.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(dates, vec!["20240104", "20240105"]);
+}
+
+#[test]
+fn paginated_code_response_rejects_non_advancing_next_position() {
+    let server = StubServer::serve_sequence(vec![
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_code_api_page1.json"),
+            "application/json",
+        ),
+        StubResponse::with_content_type(
+            200,
+            fixture_bytes("tests/fixtures/json_success_code_api_page1.json"),
+            "application/json",
+        ),
+    ]);
+    let client = BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string());
+    let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+
+    let error = client.get_data_code_all(&query).unwrap_err();
+    let _ = server.finish_all();
+
+    assert!(matches!(error, BojError::DecodeError(_)));
+    assert!(error.to_string().contains("NEXTPOSITION 3"));
+}
+
 #[test]
 fn transport_error_is_not_rewritten() {
     let client = BojClient::new()
@@ -6,6 +6,10 @@ use std::net::{TcpListener, TcpStream};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 
+use boj_client::client::BojClient;
+use boj_client::model::{CodeResponse, LayerResponse, MetadataResponse};
+use boj_client::query::{CodeQuery, Format, Frequency, LayerQuery, MetadataQuery};
+
 #[derive(Debug, Clone)]
 pub struct StubResponse {
     status_code: u16,
@@ -39,25 +43,32 @@ pub struct CapturedRequest {
 
 pub struct StubServer {
     base_url: String,
-    captured_request: Arc<Mutex<Option<CapturedRequest>>>,
+    captured_requests: Arc<Mutex<Vec<CapturedRequest>>>,
     handle: Option<JoinHandle<Result<(), String>>>,
 }
 
 impl StubServer {
     pub fn serve_once(response: StubResponse) -> Self {
+        Self::serve_sequence(vec![response])
+    }
+
+    pub fn serve_sequence(responses: Vec<StubResponse>) -> Self {
         let listener = TcpListener::bind("127.0.0.1:0").expect("bind stub server");
         let address = listener.local_addr().expect("stub local address");
-        let captured_request = Arc::new(Mutex::new(None));
-        let captured_request_clone = Arc::clone(&captured_request);
+        let captured_requests = Arc::new(Mutex::new(Vec::new()));
+        let captured_requests_clone = Arc::clone(&captured_requests);
 
         let handle = thread::spawn(move || {
-            let (stream, _) = listener.accept().map_err(|error| error.to_string())?;
-            handle_request(stream, response, captured_request_clone)
+            for response in responses {
+                let (stream, _) = listener.accept().map_err(|error| error.to_string())?;
+                handle_request(stream, response, Arc::clone(&captured_requests_clone))?;
+            }
+            Ok(())
         });
 
         Self {
             base_url: format!("http://{address}"),
-            captured_request,
+            captured_requests,
             handle: Some(handle),
         }
     }
@@ -66,11 +77,15 @@ impl StubServer {
         &self.base_url
     }
 
-    pub fn finish(mut self) -> Option<CapturedRequest> {
+    pub fn finish(self) -> Option<CapturedRequest> {
+        self.finish_all().into_iter().next()
+    }
+
+    pub fn finish_all(mut self) -> Vec<CapturedRequest> {
         let handle = self.handle.take().expect("stub server handle");
         let result = handle.join().expect("stub server thread panic");
         result.expect("stub server failed");
-        self.captured_request
+        self.captured_requests
             .lock()
             .expect("captured request mutex")
             .clone()
@@ -80,13 +95,14 @@ impl StubServer {
 fn handle_request(
     mut stream: TcpStream,
     response: StubResponse,
-    captured_request: Arc<Mutex<Option<CapturedRequest>>>,
+    captured_requests: Arc<Mutex<Vec<CapturedRequest>>>,
 ) -> Result<(), String> {
     let raw = read_http_request(&mut stream)?;
     let captured = parse_request(&raw)?;
-    *captured_request
+    captured_requests
         .lock()
-        .map_err(|_| "captured request mutex poisoned".to_string())? = Some(captured);
+        .map_err(|_| "captured request mutex poisoned".to_string())?
+        .push(captured);
 
     let mut response_head = format!(
         "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
@@ -179,3 +195,53 @@ pub fn fixture_bytes(path: &str) -> Vec<u8> {
 pub fn fixture_string(path: &str) -> String {
     std::fs::read_to_string(path).expect("fixture file")
 }
+
+pub fn json_response(fixture: &str) -> StubResponse {
+    StubResponse::with_content_type(200, fixture_bytes(fixture), "application/json")
+}
+
+pub fn client_for(server: &StubServer) -> BojClient {
+    BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string())
+}
+
+/// Fetches `codes` from FM08 through the two-page code API fixtures.
+pub fn fetch_fm08_pages(codes: &[&str]) -> CodeResponse {
+    let server = StubServer::serve_sequence(vec![
+        json_response("tests/fixtures/json_success_code_api_page1.json"),
+        json_response("tests/fixtures/json_success_code_api_page2.json"),
+    ]);
+    let codes = codes.iter().map(|code| code.to_string()).collect();
+    let query = CodeQuery::new("FM08", codes)
+        .unwrap()
+        .with_format(Format::Json);
+
+    let response = client_for(&server).get_data_code_all(&query).unwrap();
+    let _ = server.finish_all();
+    response
+}
+
+/// Fetches every layer of quarterly MD10 through the wildcard layer fixture.
+pub fn fetch_quarterly_layer() -> LayerResponse {
+    let server = StubServer::serve_once(json_response(
+        "tests/fixtures/json_success_layer_api_wildcard.json",
+    ));
+    let query = LayerQuery::new("MD10", Frequency::Q, vec!["*".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+
+    let response = client_for(&server).get_data_layer(&query).unwrap();
+    let _ = server.finish();
+    response
+}
+
+/// Fetches the metadata of `db` served from `fixture`.
+pub fn fetch_metadata(db: &str, fixture: &str) -> MetadataResponse {
+    let server = StubServer::serve_once(json_response(fixture));
+    let query = MetadataQuery::new(db).unwrap().with_format(Format::Json);
+
+    let response = client_for(&server).get_metadata(&query).unwrap();
+    let _ = server.finish();
+    response
+}
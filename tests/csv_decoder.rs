@@ -2,6 +2,7 @@ mod common;
 
 use boj_client::client::BojClient;
 use boj_client::error::BojError;
+use boj_client::model::LayerSelector;
 use boj_client::query::{CodeQuery, Format, Frequency, Language, LayerQuery, MetadataQuery};
 use common::{StubResponse, StubServer, fixture_bytes};
 
@@ -54,6 +55,32 @@ fn decodes_utf8_csv_layer_response() {
     assert_eq!(response.series[0].series_code, "TK99F1000601GCQ01000");
 }
 
+#[test]
+fn decodes_wildcard_layer_echo_from_csv() {
+    let server = StubServer::serve_once(StubResponse::with_content_type(
+        200,
+        fixture_bytes("tests/fixtures/csv_success_layer_wildcard_en_utf8.csv"),
+        "text/csv",
+    ));
+    let client = BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string());
+    let query = LayerQuery::new("BP01", Frequency::M, vec!["1".to_string(), "*".to_string()])
+        .unwrap()
+        .with_format(Format::Csv)
+        .with_lang(Language::En);
+
+    let response = client.get_data_layer(&query).unwrap();
+    let _ = server.finish();
+
+    assert_eq!(response.parameter.layer.as_deref(), Some("1,*"));
+    assert_eq!(response.parameter.layer1, Some(LayerSelector::Index(1)));
+    assert_eq!(response.parameter.layer2, Some(LayerSelector::Wildcard));
+    assert_eq!(response.parameter.layer3, None);
+    assert!(!response.parameter.extras.contains_key("LAYER"));
+    assert_eq!(response.series[0].points.len(), 2);
+}
+
 #[test]
 fn decodes_shift_jis_csv() {
     let server = StubServer::serve_once(StubResponse::with_content_type(
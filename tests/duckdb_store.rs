@@ -0,0 +1,127 @@
+#![cfg(feature = "duckdb")]
+
+mod common;
+
+use boj_client::catalog;
+use boj_client::duckdb::{DuckDbStore, Value};
+use common::{fetch_fm08_pages, fetch_metadata, fetch_quarterly_layer};
+
+fn single_value(store: &DuckDbStore, sql: &str) -> Value {
+    let result = store.query(sql).unwrap();
+    assert_eq!(result.rows.len(), 1, "{sql}");
+    result.rows[0][0].clone()
+}
+
+#[test]
+fn loads_catalog_databases_on_open() {
+    let store = DuckDbStore::open_in_memory().unwrap();
+    let count = single_value(&store, "SELECT count(*) FROM databases");
+    assert_eq!(count, Value::BigInt(catalog::databases().len() as i64));
+
+    let result = store
+        .query("SELECT db, name_ja FROM databases WHERE db = 'FM08'")
+        .unwrap();
+    assert_eq!(result.columns, ["db", "name_ja"]);
+    assert_eq!(result.rows[0][0], Value::Text("FM08".to_string()));
+}
+
+#[test]
+fn reloading_responses_upserts_rows() {
+    let store = DuckDbStore::open_in_memory().unwrap();
+    let mut response = fetch_fm08_pages(&["FXERD01"]);
+    assert_eq!(store.load_code_response(&response).unwrap(), 5);
+
+    response.series[0].points[1].value = Some("144.70".to_string());
+    store.load_code_response(&response).unwrap();
+    assert_eq!(
+        single_value(&store, "SELECT count(*) FROM observations"),
+        Value::BigInt(5)
+    );
+    assert_eq!(
+        single_value(
+            &store,
+            "SELECT value FROM observations \
+             WHERE series_code = 'FXERD01' AND period = '20240105'"
+        ),
+        Value::Double(144.70)
+    );
+    assert_eq!(
+        single_value(
+            &store,
+            "SELECT value IS NULL FROM observations \
+             WHERE series_code = 'FXERD04' AND period = '20240105'"
+        ),
+        Value::Boolean(true)
+    );
+    assert_eq!(
+        single_value(&store, "SELECT count(*) FROM series"),
+        Value::BigInt(3)
+    );
+}
+
+#[test]
+fn joins_metadata_and_data_labels() {
+    let store = DuckDbStore::open_in_memory().unwrap();
+    store
+        .load_code_response(&fetch_fm08_pages(&["FXERD01"]))
+        .unwrap();
+    assert_eq!(
+        store
+            .load_metadata(&fetch_metadata(
+                "FM08",
+                "tests/fixtures/json_success_metadata_fm08_jp.json"
+            ))
+            .unwrap(),
+        3
+    );
+    store
+        .load_metadata(&fetch_metadata(
+            "FM08",
+            "tests/fixtures/json_success_metadata_fm08_jp.json",
+        ))
+        .unwrap();
+    store
+        .load_code_response(&fetch_fm08_pages(&["FXERD01"]))
+        .unwrap();
+
+    let result = store
+        .query(
+            "SELECT s.layer2, s.unit_j, d.name_ja, count(o.period) AS points \
+             FROM series s JOIN databases d USING (db) \
+             LEFT JOIN observations o USING (db, series_code) \
+             WHERE s.series_code = 'FXERD04' GROUP BY ALL",
+        )
+        .unwrap();
+    assert_eq!(result.columns, ["layer2", "unit_j", "name_ja", "points"]);
+    let row = &result.rows[0];
+    assert_eq!(row[0], Value::UInt(2));
+    assert_eq!(row[1], Value::Text("円".to_string()));
+    assert_eq!(
+        row[2],
+        Value::Text(catalog::find_db("FM08").unwrap().name_ja.to_string())
+    );
+    assert_eq!(row[3], Value::BigInt(2));
+}
+
+#[test]
+fn stores_period_dates_for_layer_responses() {
+    let store = DuckDbStore::open_in_memory().unwrap();
+    let response = fetch_quarterly_layer();
+    let code = response.series[0].series_code.clone();
+    store.load_layer_response(&response).unwrap();
+
+    let result = store
+        .query(&format!(
+            "SELECT strftime(period_start, '%Y-%m-%d'), strftime(period_end, '%Y-%m-%d') \
+             FROM observations WHERE series_code = '{code}' AND period = '202503'"
+        ))
+        .unwrap();
+    assert_eq!(
+        result.rows[0],
+        [
+            Value::Text("2025-07-01".to_string()),
+            Value::Text("2025-09-30".to_string())
+        ]
+    );
+    assert!(store.query("SELECT * FROM missing_table").is_err());
+}
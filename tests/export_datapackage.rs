@@ -0,0 +1,164 @@
+mod common;
+
+use std::fs;
+use std::path::PathBuf;
+
+use boj_client::export::{DataPackageOptions, ExportError, write_data_package};
+use boj_client::query::Language;
+use common::{fetch_fm08_pages, fetch_metadata};
+use serde_json::Value;
+
+const METADATA_FIXTURE: &str = "tests/fixtures/json_success_metadata_fm08_jp.json";
+
+fn package_directory(name: &str) -> PathBuf {
+    let directory =
+        std::env::temp_dir().join(format!("boj-datapackage-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&directory);
+    directory
+}
+
+fn read_descriptor(path: &PathBuf) -> Value {
+    serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[test]
+fn writes_descriptor_and_csv_resource_per_frequency() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+    let metadata = fetch_metadata("FM08", METADATA_FIXTURE);
+    let directory = package_directory("daily");
+
+    let path = write_data_package(
+        &directory,
+        &response.series,
+        &metadata,
+        &DataPackageOptions::new().with_title("ドル・円 スポット"),
+    )
+    .unwrap();
+
+    assert_eq!(path, directory.join("datapackage.json"));
+    let descriptor = read_descriptor(&path);
+    assert_eq!(descriptor["profile"], "tabular-data-package");
+    assert_eq!(descriptor["name"], "boj-fm08");
+    assert_eq!(descriptor["title"], "ドル・円 スポット");
+    assert_eq!(descriptor["created"], "2026-02-19T12:30:02.114+09:00");
+
+    let resources = descriptor["resources"].as_array().unwrap();
+    assert_eq!(resources.len(), 1);
+    let resource = &resources[0];
+    assert_eq!(resource["name"], "fm08-d");
+    assert_eq!(resource["path"], "fm08-d.csv");
+    assert_eq!(resource["schema"]["missingValues"], serde_json::json!([""]));
+    assert_eq!(
+        resource["schema"]["primaryKey"],
+        serde_json::json!(["period"])
+    );
+
+    let fields = resource["schema"]["fields"].as_array().unwrap();
+    let names = fields
+        .iter()
+        .map(|field| field["name"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["period", "FXERD01", "FXERD04", "FXERD09"]);
+    assert_eq!(fields[1]["type"], "number");
+    assert_eq!(fields[1]["title"], "東京市場 ドル・円 スポット 9時時点");
+    assert_eq!(fields[1]["unit"], "円");
+    assert_eq!(
+        fields[1]["description"],
+        "分類: 外国為替市況\n収録期間: 19980101–20260218\n最終更新: 20260219"
+    );
+
+    let csv = fs::read_to_string(directory.join("fm08-d.csv")).unwrap();
+    assert_eq!(
+        csv,
+        "period,FXERD01,FXERD04,FXERD09\r\n\
+         20240104,143.15,143.69,\r\n\
+         20240105,144.62,,145.97\r\n"
+    );
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn cites_catalog_source_document_and_boj_terms() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+    let metadata = fetch_metadata("FM08", METADATA_FIXTURE);
+    let directory = package_directory("sources");
+
+    let path = write_data_package(
+        &directory,
+        &response.series,
+        &metadata,
+        &DataPackageOptions::new(),
+    )
+    .unwrap();
+
+    let descriptor = read_descriptor(&path);
+    let snapshot = boj_client::catalog::snapshot();
+    let source = &descriptor["sources"][0];
+    assert_eq!(
+        source["path"],
+        format!(
+            "https://www.stat-search.boj.or.jp/info/{}",
+            snapshot.source_document
+        )
+    );
+    assert!(
+        source["title"]
+            .as_str()
+            .unwrap()
+            .contains(snapshot.source_date)
+    );
+    assert_eq!(
+        descriptor["licenses"][0]["path"],
+        "https://www.stat-search.boj.or.jp/info/api_notice.pdf"
+    );
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn english_labels_fall_back_to_japanese_metadata() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+    let metadata = fetch_metadata("FM08", METADATA_FIXTURE);
+    let directory = package_directory("english");
+
+    let path = write_data_package(
+        &directory,
+        &response.series,
+        &metadata,
+        &DataPackageOptions::new()
+            .with_name("fx-spot")
+            .with_labels(Language::En),
+    )
+    .unwrap();
+
+    let descriptor = read_descriptor(&path);
+    assert_eq!(descriptor["name"], "fx-spot");
+    let fields = &descriptor["resources"][0]["schema"]["fields"];
+    assert_eq!(fields[0]["title"], "Period");
+    assert_eq!(fields[1]["title"], "東京市場 ドル・円 スポット 9時時点");
+    assert_eq!(
+        fields[1]["description"],
+        "Category: 外国為替市況\nCoverage: 19980101–20260218\nLast update: 20260219"
+    );
+
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn rejects_invalid_package_name_before_writing() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+    let metadata = fetch_metadata("FM08", METADATA_FIXTURE);
+    let directory = package_directory("invalid");
+
+    let error = write_data_package(
+        &directory,
+        &response.series,
+        &metadata,
+        &DataPackageOptions::new().with_name("FX Spot"),
+    )
+    .unwrap_err();
+
+    assert!(matches!(error, ExportError::InvalidName { ref name } if name == "FX Spot"));
+    assert!(!directory.exists());
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use boj_client::export::{
+    Encoding, ExportError, ExportFormat, ExportOptions, Layout, write_series,
+};
+use boj_client::model::CodeResponse;
+use boj_client::query::Language;
+use common::fetch_fm08_pages;
+use encoding_rs::SHIFT_JIS;
+
+fn export(response: &CodeResponse, options: ExportOptions) -> Vec<u8> {
+    let mut output = Vec::new();
+    write_series(&mut output, &response.series, &options).unwrap();
+    output
+}
+
+fn export_text(response: &CodeResponse, options: ExportOptions) -> String {
+    String::from_utf8(export(response, options)).unwrap()
+}
+
+#[test]
+fn writes_long_csv_and_tsv() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+
+    let csv = export_text(&response, ExportOptions::new(ExportFormat::Csv));
+    assert_eq!(
+        csv,
+        "series_code,period,value\r\n\
+         FXERD01,20240104,143.15\r\n\
+         FXERD01,20240105,144.62\r\n\
+         FXERD04,20240104,143.69\r\n\
+         FXERD04,20240105,\r\n\
+         FXERD09,20240105,145.97\r\n"
+    );
+
+    let tsv = export_text(
+        &response,
+        ExportOptions::new(ExportFormat::Tsv).with_labels(Language::Jp),
+    );
+    let lines = tsv.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "series_code\tperiod\tvalue\tname\tunit");
+    assert_eq!(
+        lines[1],
+        "FXERD01\t20240104\t143.15\t東京市場 ドル・円 スポット 9時時点\t円"
+    );
+}
+
+#[test]
+fn writes_wide_csv_with_name_and_unit_rows() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+
+    let csv = export_text(
+        &response,
+        ExportOptions::new(ExportFormat::Csv)
+            .with_layout(Layout::Wide)
+            .with_labels(Language::En),
+    );
+    assert_eq!(
+        csv,
+        "period,FXERD01,FXERD04,FXERD09\r\n\
+         name,東京市場 ドル・円 スポット 9時時点,東京市場 ドル・円 スポット 17時時点,東京市場 ドル・円 スポット 最高値\r\n\
+         unit,円,円,円\r\n\
+         20240104,143.15,143.69,\r\n\
+         20240105,144.62,,145.97\r\n"
+    );
+}
+
+#[test]
+fn writes_json_lines_in_both_layouts() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+
+    let long = export_text(
+        &response,
+        ExportOptions::new(ExportFormat::JsonLines).with_labels(Language::Jp),
+    );
+    let rows = long
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(rows.len(), 5);
+    assert_eq!(rows[0]["value"], 143.15);
+    assert_eq!(rows[0]["unit"], "円");
+    assert!(rows[3]["value"].is_null());
+
+    let wide = export_text(
+        &response,
+        ExportOptions::new(ExportFormat::JsonLines).with_layout(Layout::Wide),
+    );
+    assert_eq!(
+        wide,
+        "{\"period\":\"20240104\",\"FXERD01\":143.15,\"FXERD04\":143.69,\"FXERD09\":null}\n\
+         {\"period\":\"20240105\",\"FXERD01\":144.62,\"FXERD04\":null,\"FXERD09\":145.97}\n"
+    );
+}
+
+#[test]
+fn encodes_output_as_utf8_bom_or_shift_jis() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+    let options = ExportOptions::new(ExportFormat::Csv)
+        .with_layout(Layout::Wide)
+        .with_labels(Language::Jp);
+    let utf8 = export(&response, options);
+
+    let bom = export(&response, options.with_encoding(Encoding::Utf8Bom));
+    assert_eq!(&bom[..3], b"\xEF\xBB\xBF");
+    assert_eq!(&bom[3..], utf8.as_slice());
+
+    let shift_jis = export(&response, options.with_encoding(Encoding::ShiftJis));
+    assert_ne!(shift_jis, utf8);
+    let (decoded, _, had_errors) = SHIFT_JIS.decode(&shift_jis);
+    assert!(!had_errors);
+    assert_eq!(decoded.as_bytes(), utf8.as_slice());
+}
+
+#[test]
+fn rejects_unencodable_text_and_invalid_values_without_writing() {
+    let mut response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+    response.series[0].unit_j = Some("€".to_string());
+    let options = ExportOptions::new(ExportFormat::Csv)
+        .with_labels(Language::Jp)
+        .with_encoding(Encoding::ShiftJis);
+
+    let mut output = Vec::new();
+    let error = write_series(&mut output, &response.series, &options).unwrap_err();
+    assert!(matches!(
+        error,
+        ExportError::Unencodable {
+            character: '€', ..
+        }
+    ));
+    assert!(output.is_empty());
+
+    response.series[2].points[0].value = Some("n/a".to_string());
+    let error = write_series(&mut output, &response.series, &options).unwrap_err();
+    assert!(matches!(
+        error,
+        ExportError::InvalidValue { ref series_code, .. } if series_code == "FXERD09"
+    ));
+    assert!(output.is_empty());
+}
@@ -2,7 +2,8 @@ mod common;
 
 use boj_client::client::BojClient;
 use boj_client::error::BojError;
-use boj_client::query::{CodeQuery, Format, Language, MetadataQuery};
+use boj_client::model::LayerSelector;
+use boj_client::query::{CodeQuery, Format, Frequency, Language, LayerQuery, MetadataQuery};
 use common::{StubResponse, StubServer, fixture_bytes};
 
 #[test]
@@ -112,6 +113,33 @@ fn parses_metadata_json_payload() {
     assert_eq!(response.entries[1].series_code.as_deref(), Some("FXERD01"));
 }
 
+#[test]
+fn parses_wildcard_layer_echo_from_json() {
+    let server = StubServer::serve_once(StubResponse::with_content_type(
+        200,
+        fixture_bytes("tests/fixtures/json_success_layer_api_wildcard.json"),
+        "application/json",
+    ));
+    let client = BojClient::new()
+        .expect("default client should build")
+        .with_base_url(server.base_url().to_string());
+    let query = LayerQuery::new("MD10", Frequency::Q, vec!["*".to_string()])
+        .unwrap()
+        .with_format(Format::Json)
+        .with_lang(Language::En)
+        .with_start_position(255)
+        .unwrap();
+
+    let response = client.get_data_layer(&query).unwrap();
+    let _ = server.finish();
+
+    assert_eq!(response.parameter.layer1, Some(LayerSelector::Wildcard));
+    assert_eq!(response.parameter.layer2, None);
+    assert_eq!(response.parameter.layer, None);
+    assert_eq!(response.parameter.start_position, Some(255));
+    assert_eq!(response.series.len(), 1);
+}
+
 #[test]
 fn rejects_legacy_json_shape() {
     let server = StubServer::serve_once(StubResponse::with_content_type(
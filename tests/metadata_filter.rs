@@ -0,0 +1,103 @@
+mod common;
+
+use boj_client::model::{MetadataFilter, MetadataResponse};
+use boj_client::query::Frequency;
+use common::fetch_metadata;
+
+fn fm01() -> MetadataResponse {
+    fetch_metadata("FM01", "tests/fixtures/json_success_metadata_fm01_jp.json")
+}
+
+fn ff() -> MetadataResponse {
+    fetch_metadata("FF", "tests/fixtures/json_success_metadata_ff_jp.json")
+}
+
+#[test]
+fn default_filter_returns_every_series_row() {
+    let metadata = ff();
+
+    assert_eq!(
+        metadata.filter_codes(&MetadataFilter::new()),
+        vec![
+            "FOF_FFAS100A100",
+            "FOF_FFAS100A110",
+            "FOF_FFAS100A120",
+            "FOF_FFAF100A100",
+        ]
+    );
+}
+
+#[test]
+fn filters_by_frequency_layer_prefix_and_code_pattern() {
+    let metadata = fm01();
+    assert_eq!(
+        metadata.filter_codes(&MetadataFilter::new().with_frequency(Frequency::M)),
+        vec!["STRACLUCON"]
+    );
+    assert!(
+        metadata
+            .filter_codes(&MetadataFilter::new().with_frequency(Frequency::Q))
+            .is_empty()
+    );
+
+    let metadata = ff();
+    assert_eq!(
+        metadata.filter_codes(&MetadataFilter::new().with_layer_prefix([1, 2])),
+        vec!["FOF_FFAF100A100"]
+    );
+    assert_eq!(
+        metadata.filter_codes(
+            &MetadataFilter::new()
+                .with_layer_prefix([1, 1])
+                .with_code_pattern("fof_ffas*a11?")
+        ),
+        vec!["FOF_FFAS100A110"]
+    );
+}
+
+#[test]
+fn filters_by_coverage_and_last_update() {
+    let metadata = fm01();
+
+    let covered = MetadataFilter::new()
+        .with_coverage("198507", "20260218")
+        .unwrap();
+    assert_eq!(metadata.filter_codes(&covered), vec!["STRDCLUCON"]);
+
+    let covered = MetadataFilter::new().with_coverage("1990", "2026").unwrap();
+    assert_eq!(
+        metadata.filter_codes(&covered),
+        vec!["STRDCLUCON", "STRACLUCON"]
+    );
+
+    let recent = MetadataFilter::new()
+        .with_updated_since("20260210")
+        .unwrap();
+    assert_eq!(metadata.filter_codes(&recent), vec!["STRDCLUCON"]);
+
+    let older = MetadataFilter::new()
+        .with_updated_since("202602")
+        .unwrap()
+        .with_updated_until("20260203")
+        .unwrap();
+    assert_eq!(metadata.filter_codes(&older), vec!["STRACLUCON"]);
+
+    assert!(MetadataFilter::new().with_updated_since("2026/02").is_err());
+    assert!(MetadataFilter::new().with_coverage("2026", "2025").is_err());
+}
+
+#[test]
+fn builds_code_query_from_matching_codes() {
+    let metadata = ff();
+    let filter = MetadataFilter::new().with_code_pattern("FOF_FFAF*");
+
+    let query = metadata.code_query(&filter).unwrap();
+    let expected =
+        boj_client::query::CodeQuery::new("FF", vec!["FOF_FFAF100A100".to_string()]).unwrap();
+    assert_eq!(query, expected);
+
+    let error = metadata
+        .code_query(&MetadataFilter::new().with_code_pattern("NONE*"))
+        .unwrap_err();
+    assert!(error.to_string().contains("CODE is required"));
+}
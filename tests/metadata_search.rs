@@ -0,0 +1,184 @@
+mod common;
+
+use std::io::Cursor;
+
+use boj_client::query::Frequency;
+use boj_client::search::{SearchIndex, SearchQuery};
+use common::fetch_metadata;
+
+fn build_index() -> SearchIndex {
+    let responses = [
+        fetch_metadata("FM01", "tests/fixtures/json_success_metadata_fm01_jp.json"),
+        fetch_metadata("FM08", "tests/fixtures/json_success_metadata_api.json"),
+        fetch_metadata("FF", "tests/fixtures/json_success_metadata_ff_jp.json"),
+    ];
+    SearchIndex::from_responses(&responses)
+}
+
+fn codes(index: &SearchIndex, query: &SearchQuery) -> Vec<String> {
+    index
+        .search(query)
+        .into_iter()
+        .map(|hit| hit.series_code.to_string())
+        .collect()
+}
+
+#[test]
+fn indexes_series_rows_across_databases() {
+    let index = build_index();
+
+    assert_eq!(index.len(), 7);
+    assert_eq!(index.dbs(), vec!["FF", "FM01", "FM08"]);
+}
+
+#[test]
+fn matches_japanese_text_regardless_of_character_width() {
+    let index = build_index();
+
+    let expected = vec!["STRACLUCON".to_string(), "STRDCLUCON".to_string()];
+    assert_eq!(codes(&index, &SearchQuery::new("コール O/N")), expected);
+    assert_eq!(codes(&index, &SearchQuery::new("ｺｰﾙ ｏ／ｎ")), expected);
+    assert_eq!(
+        codes(&index, &SearchQuery::new("データ")),
+        vec!["STRDCLUCON"]
+    );
+    assert_eq!(
+        codes(&index, &SearchQuery::new("金融機関 ストック")),
+        vec!["FOF_FFAS100A100", "FOF_FFAS100A110", "FOF_FFAS100A120"]
+    );
+    assert!(codes(&index, &SearchQuery::new("コール 預金")).is_empty());
+}
+
+#[test]
+fn matches_single_character_queries_inside_words() {
+    let index = build_index();
+
+    // FF units are 億円, while FM08 has 円 on its own.
+    assert_eq!(
+        codes(&index, &SearchQuery::new("円")),
+        vec![
+            "FXERD01",
+            "FOF_FFAF100A100",
+            "FOF_FFAS100A100",
+            "FOF_FFAS100A110",
+            "FOF_FFAS100A120"
+        ]
+    );
+    assert_eq!(
+        codes(&index, &SearchQuery::new("預")),
+        vec!["FOF_FFAF100A100", "FOF_FFAS100A100", "FOF_FFAS100A120"]
+    );
+}
+
+#[test]
+fn matches_partial_codes_and_english_labels() {
+    let index = build_index();
+
+    let hits = index.search(&SearchQuery::new("fxerd"));
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0].db, "FM08");
+    assert_eq!(hits[0].series_code, "FXERD01");
+
+    let hits = index.search(&SearchQuery::new("Yen"));
+    assert_eq!(hits[0].series_code, "FXERD01");
+    assert!(hits[0].score > 0.0);
+
+    let hits = index.search(&SearchQuery::new("平均値"));
+    assert_eq!(hits.len(), 2);
+    assert_eq!(hits[0].score, hits[1].score);
+}
+
+#[test]
+fn filters_by_db_frequency_and_coverage() {
+    let index = build_index();
+
+    assert_eq!(
+        codes(&index, &SearchQuery::new("").with_db("FM08")),
+        vec!["FXERD01"]
+    );
+    assert_eq!(
+        codes(
+            &index,
+            &SearchQuery::new("コール").with_frequency(Frequency::M)
+        ),
+        vec!["STRACLUCON"]
+    );
+
+    let covered = SearchQuery::new("")
+        .with_db("FF")
+        .with_coverage("1998", "20250901")
+        .unwrap();
+    assert_eq!(index.search(&covered).len(), 4);
+
+    let not_covered = SearchQuery::new("")
+        .with_db("FF")
+        .with_coverage("1998", "202510")
+        .unwrap();
+    assert!(index.search(&not_covered).is_empty());
+
+    let limited = SearchQuery::new("").with_limit(2);
+    assert_eq!(index.search(&limited).len(), 2);
+}
+
+#[test]
+fn rejects_invalid_coverage_bounds() {
+    assert!(
+        SearchQuery::new("")
+            .with_coverage("2025-01", "2026")
+            .is_err()
+    );
+    assert!(SearchQuery::new("").with_coverage("2026", "2025").is_err());
+}
+
+#[test]
+fn add_response_replaces_existing_series() {
+    let mut index = SearchIndex::from_responses(&[fetch_metadata(
+        "FM08",
+        "tests/fixtures/json_success_metadata_api_jp.json",
+    )]);
+    assert_eq!(
+        codes(&index, &SearchQuery::new("spot rate")),
+        Vec::<String>::new()
+    );
+
+    let refreshed = fetch_metadata("FM08", "tests/fixtures/json_success_metadata_api.json");
+    index.add_response(&refreshed);
+    assert_eq!(index.len(), 1);
+    assert_eq!(
+        codes(&index, &SearchQuery::new("spot rate")),
+        vec!["FXERD01"]
+    );
+    // No postings of the replaced row are left behind.
+    assert_eq!(index, SearchIndex::from_responses(&[refreshed]));
+}
+
+#[test]
+fn round_trips_index_through_disk() {
+    let index = build_index();
+    let path = std::env::temp_dir().join(format!("boj-search-index-{}.json", std::process::id()));
+
+    index.save(&path).unwrap();
+    let restored = SearchIndex::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored, index);
+    let mut merged = restored.clone();
+    let len = merged.len();
+    merged.add_response(&fetch_metadata(
+        "FM08",
+        "tests/fixtures/json_success_metadata_api.json",
+    ));
+    assert_eq!(merged.len(), len);
+    assert_eq!(
+        codes(&restored, &SearchQuery::new("コール")),
+        codes(&index, &SearchQuery::new("コール"))
+    );
+
+    let mut bytes = Vec::new();
+    index.write_to(&mut bytes).unwrap();
+    let text = String::from_utf8(bytes)
+        .unwrap()
+        .replacen("\"version\":1", "\"version\":99", 1);
+    let error = SearchIndex::read_from(Cursor::new(text)).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use boj_client::model::MetadataTree;
+use boj_client::query::Language;
+use common::fetch_metadata;
+
+const FF_METADATA: &str = "tests/fixtures/json_success_metadata_ff_jp.json";
+
+#[test]
+fn builds_tree_from_layer_columns() {
+    let tree = MetadataTree::from_response(&fetch_metadata("FF", FF_METADATA));
+
+    assert_eq!(tree.db, "FF");
+    assert_eq!(tree.roots.len(), 1);
+    let stock = tree.node(&[1, 1]).expect("stock node");
+    assert_eq!(stock.name(Language::Jp), Some("ストック"));
+    assert_eq!(stock.series_code(), None);
+
+    let children = tree.children(&[1, 1, 1]).expect("financial institutions");
+    let codes = children
+        .iter()
+        .filter_map(|child| child.series_code())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec!["FOF_FFAS100A100", "FOF_FFAS100A110", "FOF_FFAS100A120"]
+    );
+    assert!(tree.node(&[1, 3]).is_none());
+}
+
+#[test]
+fn resolves_ancestors_and_breadcrumbs() {
+    let tree = MetadataTree::from_response(&fetch_metadata("FF", FF_METADATA));
+
+    let ancestors = tree.ancestors(&[1, 1, 1, 2]).expect("ancestors");
+    let paths = ancestors
+        .iter()
+        .map(|node| node.path.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec![vec![1], vec![1, 1], vec![1, 1, 1]]);
+
+    assert_eq!(
+        tree.breadcrumb("FOF_FFAS100A110", Language::Jp),
+        Some(vec![
+            "資金循環・四半期".to_string(),
+            "ストック".to_string(),
+            "金融機関".to_string(),
+            "資産・-現金／金融機関／ストック".to_string(),
+        ])
+    );
+    assert_eq!(
+        tree.breadcrumb("FOF_FFAF100A100", Language::Jp),
+        Some(vec![
+            "資金循環・四半期".to_string(),
+            "フロー".to_string(),
+            "1.2.1".to_string(),
+            "資産・現金・預金／金融機関／フロー".to_string(),
+        ])
+    );
+    assert_eq!(tree.breadcrumb("UNKNOWN", Language::Jp), None);
+}
+
+#[test]
+fn iterates_leaf_series_codes_under_subtree() {
+    let tree = MetadataTree::from_response(&fetch_metadata("FF", FF_METADATA));
+
+    assert_eq!(
+        tree.series_codes_under(&[1, 1]).collect::<Vec<_>>(),
+        vec!["FOF_FFAS100A100", "FOF_FFAS100A110", "FOF_FFAS100A120"]
+    );
+    assert_eq!(
+        tree.series_codes_under(&[1, 2]).collect::<Vec<_>>(),
+        vec!["FOF_FFAF100A100"]
+    );
+    assert_eq!(tree.series_codes_under(&[]).count(), 4);
+    assert_eq!(tree.series_codes_under(&[9]).count(), 0);
+}
+
+#[test]
+fn exports_tree_as_json() {
+    let tree = MetadataTree::from_response(&fetch_metadata("FF", FF_METADATA));
+
+    let value = serde_json::to_value(&tree).unwrap();
+    assert_eq!(value["db"], "FF");
+    assert_eq!(value["roots"][0]["path"], serde_json::json!([1]));
+    assert_eq!(
+        value["roots"][0]["children"][1]["children"][0]["entry"],
+        serde_json::Value::Null
+    );
+
+    let restored: MetadataTree = serde_json::from_value(value).unwrap();
+    assert_eq!(restored, tree);
+}
@@ -0,0 +1,150 @@
+#![cfg(feature = "parquet")]
+
+mod common;
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Date32Type, Float64Type, UInt32Type};
+use arrow_array::{Array, RecordBatch};
+use boj_client::parquet::{ParquetStore, metadata_schema, observation_schema};
+use common::{fetch_fm08_pages, fetch_metadata, fetch_quarterly_layer};
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+fn temp_root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("boj-parquet-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&root);
+    root
+}
+
+fn read_batch(path: &Path) -> RecordBatch {
+    let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+        .unwrap()
+        .build()
+        .unwrap();
+    let mut batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(batches.len(), 1);
+    batches.remove(0)
+}
+
+#[test]
+fn writes_typed_observations_into_db_and_frequency_partitions() {
+    let root = temp_root("observations");
+    let store = ParquetStore::new(&root);
+
+    let daily = store
+        .write_code_response(&fetch_fm08_pages(&["FXERD01"]))
+        .unwrap();
+    let quarterly = store
+        .write_layer_response(&fetch_quarterly_layer())
+        .unwrap();
+
+    assert_eq!(
+        daily,
+        vec![root.join("observations/db=FM08/frequency=D/part-00000.parquet")]
+    );
+    assert_eq!(
+        quarterly,
+        vec![root.join("observations/db=MD10/frequency=Q/part-00000.parquet")]
+    );
+
+    let batch = read_batch(&daily[0]);
+    assert_eq!(batch.schema().fields(), observation_schema().fields());
+    assert_eq!(batch.num_rows(), 5);
+    let values = batch
+        .column_by_name("value")
+        .unwrap()
+        .as_primitive::<Float64Type>();
+    assert_eq!(values.value(0), 143.15);
+    assert!(values.is_null(3));
+    let starts = batch
+        .column_by_name("period_start")
+        .unwrap()
+        .as_primitive::<Date32Type>();
+    assert_eq!(starts.value(0), 19_726);
+    let retrieved = batch
+        .column_by_name("retrieved_at")
+        .unwrap()
+        .as_string::<i32>();
+    assert_eq!(retrieved.value(0), "2026-02-19T12:38:18.251+09:00");
+
+    let batch = read_batch(&quarterly[0]);
+    let periods = batch.column_by_name("period").unwrap().as_string::<i32>();
+    assert_eq!(periods.value(0), "202503");
+    let starts = batch
+        .column_by_name("period_start")
+        .unwrap()
+        .as_primitive::<Date32Type>();
+    let ends = batch
+        .column_by_name("period_end")
+        .unwrap()
+        .as_primitive::<Date32Type>();
+    // 2025-07-01 and 2025-09-30
+    assert_eq!(starts.value(0), 20_270);
+    assert_eq!(ends.value(0), 20_361);
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn appends_later_fetches_without_rewriting_files() {
+    let root = temp_root("append");
+    let store = ParquetStore::new(&root);
+    let response = fetch_fm08_pages(&["FXERD01"]);
+
+    let first = store.write_code_response(&response).unwrap();
+    let modified = std::fs::metadata(&first[0]).unwrap().modified().unwrap();
+    let second = store.write_code_response(&response).unwrap();
+
+    assert_eq!(
+        second,
+        vec![root.join("observations/db=FM08/frequency=D/part-00001.parquet")]
+    );
+    assert_eq!(
+        std::fs::metadata(&first[0]).unwrap().modified().unwrap(),
+        modified
+    );
+    assert_eq!(read_batch(&second[0]).num_rows(), 5);
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn writes_metadata_rows_partitioned_by_db() {
+    let root = temp_root("metadata");
+    let store = ParquetStore::new(&root);
+    let metadata = fetch_metadata("FF", "tests/fixtures/json_success_metadata_ff_jp.json");
+
+    let path = store.write_metadata(&metadata).unwrap().unwrap();
+
+    assert_eq!(path, root.join("metadata/db=FF/part-00000.parquet"));
+    let batch = read_batch(&path);
+    assert_eq!(batch.schema().fields(), metadata_schema().fields());
+    assert_eq!(batch.num_rows(), metadata.entries.len());
+    let codes = batch
+        .column_by_name("series_code")
+        .unwrap()
+        .as_string::<i32>();
+    assert!(codes.is_null(0));
+    let layer2 = batch
+        .column_by_name("layer2")
+        .unwrap()
+        .as_primitive::<UInt32Type>();
+    assert_eq!(layer2.value(1), 1);
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn rejects_responses_without_usable_db() {
+    let root = temp_root("invalid");
+    let store = ParquetStore::new(&root);
+    let mut response = fetch_fm08_pages(&["FXERD01"]);
+
+    response.parameter.db = None;
+    assert!(store.write_code_response(&response).is_err());
+    response.parameter.db = Some("../FM08".to_string());
+    assert!(store.write_code_response(&response).is_err());
+    assert!(!root.exists());
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "polars")]
+
+mod common;
+
+use boj_client::query::{Format, Frequency, LayerQuery};
+use common::{StubServer, client_for, fetch_fm08_pages, json_response};
+use polars::prelude::DataFrame;
+
+fn strings(frame: &DataFrame, column: &str) -> Vec<Option<String>> {
+    let values = frame.column(column).unwrap().str().unwrap();
+    (0..values.len())
+        .map(|row| values.get(row).map(str::to_string))
+        .collect()
+}
+
+fn floats(frame: &DataFrame, column: &str) -> Vec<Option<f64>> {
+    let values = frame.column(column).unwrap().f64().unwrap();
+    (0..values.len()).map(|row| values.get(row)).collect()
+}
+
+#[test]
+fn converts_merged_pages_to_wide_frame_indexed_by_period() {
+    let frame = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"])
+        .to_polars()
+        .unwrap();
+
+    let names = frame
+        .get_column_names()
+        .into_iter()
+        .map(|name| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["period", "FXERD01", "FXERD04", "FXERD09"]);
+    assert_eq!(
+        strings(&frame, "period"),
+        vec![Some("20240104".to_string()), Some("20240105".to_string())]
+    );
+    assert_eq!(floats(&frame, "FXERD01"), vec![Some(143.15), Some(144.62)]);
+    assert_eq!(floats(&frame, "FXERD04"), vec![Some(143.69), None]);
+    assert_eq!(floats(&frame, "FXERD09"), vec![None, Some(145.97)]);
+}
+
+#[test]
+fn converts_merged_pages_to_long_and_metadata_frames() {
+    let response = fetch_fm08_pages(&["FXERD01", "FXERD04", "FXERD09"]);
+
+    let long = response.to_polars_long().unwrap();
+    assert_eq!(long.height(), 5);
+    assert_eq!(
+        floats(&long, "value"),
+        vec![Some(143.15), Some(144.62), Some(143.69), None, Some(145.97)]
+    );
+    assert_eq!(strings(&long, "series_code")[4].as_deref(), Some("FXERD09"));
+
+    let metadata = response.to_polars_metadata().unwrap();
+    assert_eq!(metadata.height(), 3);
+    assert_eq!(
+        strings(&metadata, "series_code"),
+        vec![
+            Some("FXERD01".to_string()),
+            Some("FXERD04".to_string()),
+            Some("FXERD09".to_string())
+        ]
+    );
+    assert_eq!(strings(&metadata, "unit_j")[0].as_deref(), Some("円"));
+    assert_eq!(strings(&metadata, "unit")[0], None);
+    assert_eq!(
+        strings(&metadata, "name_of_time_series_j")[1].as_deref(),
+        Some("東京市場 ドル・円 スポット 17時時点")
+    );
+}
+
+#[test]
+fn converts_layer_response_and_reports_invalid_values() {
+    let server = StubServer::serve_once(json_response(
+        "tests/fixtures/json_success_layer_api_wildcard.json",
+    ));
+    let query = LayerQuery::new("MD10", Frequency::Q, vec!["*".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+    let mut response = client_for(&server).get_data_layer(&query).unwrap();
+    let _ = server.finish();
+
+    let wide = response.to_polars().unwrap();
+    assert_eq!(wide.width(), response.series.len() + 1);
+    assert_eq!(
+        response.to_polars_metadata().unwrap().height(),
+        response.series.len()
+    );
+
+    response.series[0].points[0].value = Some("n/a".to_string());
+    let error = response.to_polars_long().unwrap_err();
+    assert!(error.to_string().contains("non-numeric value \"n/a\""));
+}
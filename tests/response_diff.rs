@@ -0,0 +1,96 @@
+mod common;
+
+use boj_client::model::{CodeResponse, DataPoint, ResponseDiff};
+use boj_client::query::{CodeQuery, Format, Frequency, LayerQuery};
+use common::{StubServer, client_for, fetch_fm08_pages, json_response};
+
+fn fetch_first_page() -> CodeResponse {
+    let server = StubServer::serve_once(json_response(
+        "tests/fixtures/json_success_code_api_page1.json",
+    ));
+    let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+    let response = client_for(&server).get_data_code(&query).unwrap();
+    let _ = server.finish();
+    response
+}
+
+#[test]
+fn reports_added_series_points_values_and_labels() {
+    let older = fetch_first_page();
+    let mut newer = fetch_fm08_pages(&["FXERD01"]);
+    newer.series[0].points[1].value = Some("144.70".to_string());
+    newer.series[0].last_update = Some("20260220".to_string());
+
+    let diff = older.diff(&newer);
+    assert_eq!(diff.added_series, ["FXERD09"]);
+    assert!(diff.removed_series.is_empty());
+    assert_eq!(diff.changed_series.len(), 2);
+
+    let fxerd01 = &diff.changed_series[0];
+    assert_eq!(fxerd01.series_code, "FXERD01");
+    assert_eq!(fxerd01.label_changes.len(), 1);
+    assert_eq!(fxerd01.label_changes[0].field, "last_update");
+    assert_eq!(fxerd01.label_changes[0].new.as_deref(), Some("20260220"));
+    assert_eq!(fxerd01.value_changes[0].survey_date, "20240105");
+    assert_eq!(fxerd01.value_changes[0].old.as_deref(), Some("144.62"));
+    assert_eq!(fxerd01.value_changes[0].new.as_deref(), Some("144.70"));
+
+    let fxerd04 = &diff.changed_series[1];
+    assert_eq!(
+        fxerd04.added_points,
+        [DataPoint {
+            survey_date: "20240105".to_string(),
+            value: None,
+        }]
+    );
+    assert!(fxerd04.value_changes.is_empty());
+}
+
+#[test]
+fn reports_removed_series_and_points() {
+    let diff = fetch_fm08_pages(&["FXERD01"]).diff(&fetch_first_page());
+    assert!(diff.added_series.is_empty());
+    assert_eq!(diff.removed_series, ["FXERD09"]);
+    assert_eq!(diff.changed_series.len(), 1);
+    assert_eq!(
+        diff.changed_series[0].removed_points[0].survey_date,
+        "20240105"
+    );
+}
+
+#[test]
+fn ignores_equal_numbers_and_identical_layer_responses() {
+    let older = fetch_first_page();
+    let mut newer = older.clone();
+    newer.series[0].points[0].value = Some("143.150".to_string());
+    assert!(older.diff(&newer).is_empty());
+
+    let server = StubServer::serve_once(json_response(
+        "tests/fixtures/json_success_layer_api_wildcard.json",
+    ));
+    let query = LayerQuery::new("MD10", Frequency::Q, vec!["*".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+    let layer = client_for(&server).get_data_layer(&query).unwrap();
+    let _ = server.finish();
+    assert_eq!(layer.diff(&layer.clone()), ResponseDiff::default());
+}
+
+#[test]
+fn serializes_to_json() {
+    let older = fetch_first_page();
+    let mut newer = older.clone();
+    newer.series[1].points[0].value = None;
+
+    let diff = older.diff(&newer);
+    let json = serde_json::to_value(&diff).unwrap();
+    let change = &json["changed_series"][0]["value_changes"][0];
+    assert_eq!(json["changed_series"][0]["series_code"], "FXERD04");
+    assert_eq!(change["old"], "143.69");
+    assert!(change["new"].is_null());
+
+    let parsed = serde_json::from_value::<ResponseDiff>(json).unwrap();
+    assert_eq!(parsed, diff);
+}
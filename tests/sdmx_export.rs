@@ -0,0 +1,177 @@
+#![cfg(feature = "sdmx")]
+
+mod common;
+
+use boj_client::catalog;
+use boj_client::model::{CodeSeries, DataPoint};
+use boj_client::query::Frequency;
+use boj_client::sdmx::{
+    DSD_ID, SdmxDataSet, SdmxError, db_codelist, frequency_codelist, write_structure,
+};
+use common::{fetch_fm08_pages, fetch_metadata, fetch_quarterly_layer};
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+fn series(code: &str, frequency: &str, points: &[(&str, Option<&str>)]) -> CodeSeries {
+    CodeSeries {
+        series_code: code.to_string(),
+        name_of_time_series_j: None,
+        name_of_time_series: None,
+        unit_j: None,
+        unit: None,
+        frequency: Some(frequency.to_string()),
+        category_j: None,
+        category: None,
+        last_update: None,
+        points: points
+            .iter()
+            .map(|(survey_date, value)| DataPoint {
+                survey_date: survey_date.to_string(),
+                value: value.map(str::to_string),
+            })
+            .collect(),
+        extras: Default::default(),
+    }
+}
+
+fn assert_roundtrips(dataset: &SdmxDataSet) {
+    let json = dataset.to_sdmx_json().unwrap();
+    assert_eq!(&SdmxDataSet::from_sdmx_json(&json).unwrap(), dataset);
+    let xml = dataset.to_sdmx_ml().unwrap();
+    assert_eq!(&SdmxDataSet::from_sdmx_ml(&xml).unwrap(), dataset);
+}
+
+#[test]
+fn roundtrips_code_response_through_json_and_ml() {
+    let mut dataset = SdmxDataSet::from_code_response(&fetch_fm08_pages(&["FXERD01"])).unwrap();
+    assert_eq!(dataset.db, "FM08");
+    assert_eq!(
+        dataset.prepared.as_deref(),
+        Some("2026-02-19T12:38:18.251+09:00")
+    );
+    assert_eq!(dataset.series.len(), 3);
+    assert_eq!(dataset.series[1].observations[1].value, None);
+
+    dataset.series[0].notes_j = Some("速報値\n<改定あり> & \"注\"".to_string());
+    assert_roundtrips(&dataset);
+}
+
+#[test]
+fn writes_sdmx_json_keys_and_periods() {
+    let dataset = SdmxDataSet::from_code_response(&fetch_fm08_pages(&["FXERD01"])).unwrap();
+    let json = serde_json::from_str::<serde_json::Value>(&dataset.to_sdmx_json().unwrap()).unwrap();
+
+    assert_eq!(json["header"]["id"], "BOJ_FM08");
+    let dimensions = &json["structure"]["dimensions"];
+    assert_eq!(dimensions["series"][0]["values"][0]["id"], "FM08");
+    assert_eq!(dimensions["series"][2]["values"][0]["name"], "DAILY");
+    let periods = &dimensions["observation"][0]["values"];
+    assert_eq!(periods[0]["id"], "2024-01-04");
+    assert_eq!(periods[1]["start"], "2024-01-05T00:00:00");
+
+    let series = &json["dataSets"][0]["series"];
+    assert_eq!(series["0:0:0"]["observations"]["0"][0], 143.15);
+    assert!(series["0:1:0"]["observations"]["1"][0].is_null());
+    assert!(series["0:2:0"]["observations"].get("0").is_none());
+}
+
+#[test]
+fn writes_structure_specific_data_with_reporting_periods() {
+    let quarterly = SdmxDataSet::from_layer_response(&fetch_quarterly_layer()).unwrap();
+    let xml = quarterly.to_sdmx_ml().unwrap();
+    assert!(xml.contains(&format!("structureID=\"{DSD_ID}\"")));
+    assert!(xml.contains("FREQ=\"Q\""));
+    assert!(xml.contains("TIME_PERIOD=\"2025-Q3\""));
+    assert_roundtrips(&quarterly);
+
+    let fiscal = series(
+        "FY01",
+        "ANNUAL(MAR)",
+        &[("2023", Some("1.5")), ("2024", None)],
+    );
+    let half = series("FH01", "SEMIANNUAL(SEP)", &[("202402", Some("-0.25"))]);
+    let dataset = SdmxDataSet::from_series("FM01", [&fiscal, &half])
+        .unwrap()
+        .with_prepared("2026-02-19T12:00:00");
+    let xml = dataset.to_sdmx_ml().unwrap();
+    assert!(xml.contains("REPORTING_YEAR_START_DAY=\"--04-01\""));
+    assert!(xml.contains("TIME_PERIOD=\"2023-A1\" OBS_VALUE=\"1.5\""));
+    assert!(xml.contains("TIME_PERIOD=\"2024-A1\" OBS_VALUE=\"NaN\""));
+    assert!(xml.contains("TIME_PERIOD=\"2024-S2\""));
+    assert_roundtrips(&dataset);
+}
+
+#[test]
+fn fills_notes_from_metadata() {
+    let daily = series("STRDCLUCON", "DAILY", &[("20260218", Some("0.727"))]);
+    let dataset = SdmxDataSet::from_series("FM01", [&daily])
+        .unwrap()
+        .with_metadata(&fetch_metadata(
+            "FM01",
+            "tests/fixtures/json_success_metadata_fm01_jp.json",
+        ));
+
+    let series = &dataset.series[0];
+    assert_eq!(series.notes_j.as_deref(), Some("ﾃﾞｰﾀは速報値"));
+    assert!(series.name_j.is_some());
+    assert_eq!(series.frequency, Frequency::D);
+    assert_roundtrips(&dataset);
+}
+
+#[test]
+fn rejects_series_without_frequency_or_numeric_values() {
+    let mut unknown = series("X", "DAILY", &[]);
+    unknown.frequency = None;
+    assert!(matches!(
+        SdmxDataSet::from_series("FM01", [&unknown]),
+        Err(SdmxError::UnknownFrequency { .. })
+    ));
+
+    let invalid = series("X", "DAILY", &[("20240104", Some("n/a"))]);
+    assert!(matches!(
+        SdmxDataSet::from_series("FM01", [&invalid]),
+        Err(SdmxError::InvalidValue { .. })
+    ));
+}
+
+#[test]
+fn writes_dsd_with_codelists_from_catalog() {
+    let mut output = Vec::new();
+    write_structure(&mut output).unwrap();
+    let xml = String::from_utf8(output).unwrap();
+
+    let mut reader = Reader::from_str(&xml);
+    let mut codelist = String::new();
+    let mut db_codes = Vec::new();
+    let mut freq_codes = Vec::new();
+    let mut dimensions = Vec::new();
+    loop {
+        match reader.read_event().unwrap() {
+            Event::Start(element) | Event::Empty(element) => {
+                let id = element
+                    .try_get_attribute("id")
+                    .unwrap()
+                    .map(|attribute| attribute.value.into_owned());
+                match (element.local_name().as_ref(), id) {
+                    ("Codelist", Some(id)) => codelist = id,
+                    ("Code", Some(id)) if codelist == "CL_BOJ_DB" => db_codes.push(id),
+                    ("Code", Some(id)) if codelist == "CL_BOJ_FREQ" => freq_codes.push(id),
+                    ("Dimension" | "TimeDimension", Some(id)) => dimensions.push(id),
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let catalog_codes = catalog::databases()
+        .iter()
+        .map(|entry| entry.code.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(db_codes, catalog_codes);
+    assert_eq!(db_codelist().codes.len(), catalog_codes.len());
+    assert_eq!(freq_codes, catalog::frequency_codes());
+    assert_eq!(frequency_codelist().codes[1].name, "ANNUAL(MAR)");
+    assert_eq!(dimensions, ["DB", "SERIES_CODE", "FREQ", "TIME_PERIOD"]);
+}
@@ -0,0 +1,175 @@
+#![cfg(feature = "store")]
+
+mod common;
+
+use boj_client::model::CodeResponse;
+use boj_client::query::{CodeQuery, Format};
+use boj_client::store::SqliteStore;
+use common::{StubServer, client_for, json_response};
+
+fn fetch_page1() -> CodeResponse {
+    let server = StubServer::serve_once(json_response(
+        "tests/fixtures/json_success_code_api_page1.json",
+    ));
+    let query = CodeQuery::new("FM08", vec!["FXERD01".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+    let response = client_for(&server).get_data_code(&query).unwrap();
+    let _ = server.finish();
+    response
+}
+
+/// Stores page 1 as fetched on 2026-02-19, then a revised FXERD01 value for
+/// 20240105 fetched on 2026-03-02.
+fn store_with_revision() -> SqliteStore {
+    let mut store = SqliteStore::open_in_memory().unwrap();
+    let mut response = fetch_page1();
+    store.upsert_code_response(&response).unwrap();
+
+    response.meta.date = Some("2026-03-02T09:00:01.000+09:00".to_string());
+    response.series[0].points[1].value = Some("144.70".to_string());
+    store.upsert_code_response(&response).unwrap();
+    store
+}
+
+#[test]
+fn keeps_per_point_history_with_fetch_dates() {
+    let store = store_with_revision();
+
+    let history = store.history("FM08", "FXERD01", "20240105").unwrap();
+    assert_eq!(history.len(), 2);
+    assert!(history[0].initial);
+    assert_eq!(history[0].retrieved_at, "2026-02-19T12:38:18.251+09:00");
+    assert_eq!(history[1].value.as_deref(), Some("144.70"));
+    assert_eq!(history[1].previous_value.as_deref(), Some("144.62"));
+    assert!(!history[1].initial);
+
+    // Unchanged values are not repeated by later fetches.
+    assert_eq!(
+        store.history("FM08", "FXERD01", "20240104").unwrap().len(),
+        1
+    );
+}
+
+#[test]
+fn answers_values_as_known_on_a_date() {
+    let store = store_with_revision();
+    let value_on = |as_of: &str| {
+        store
+            .vintage("FM08", "FXERD01", "20240105", as_of)
+            .unwrap()
+            .and_then(|revision| revision.value)
+    };
+    assert_eq!(value_on("2026-02-18"), None);
+    assert_eq!(value_on("2026-02-19").as_deref(), Some("144.62"));
+    assert_eq!(value_on("2026-03-01").as_deref(), Some("144.62"));
+    assert_eq!(value_on("2026-03-02").as_deref(), Some("144.70"));
+    assert_eq!(value_on("2026-03-02T08:59").as_deref(), Some("144.62"));
+
+    let before = store.series_as_of("FM08", "FXERD01", "2026-02-28").unwrap();
+    let values = before
+        .unwrap()
+        .points
+        .into_iter()
+        .map(|point| point.value.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["143.15", "144.62"]);
+    assert_eq!(
+        store.series("FM08", "FXERD01").unwrap().unwrap().points[1]
+            .value
+            .as_deref(),
+        Some("144.70")
+    );
+}
+
+#[test]
+fn prefers_the_latest_response_date_over_load_order() {
+    let mut store = SqliteStore::open_in_memory().unwrap();
+    let original = fetch_page1();
+    let mut revised = original.clone();
+    revised.meta.date = Some("2026-03-02T09:00:01.000+09:00".to_string());
+    revised.series[0].points[1].value = Some("144.70".to_string());
+    // A backfill loads the older response last.
+    store.upsert_code_response(&revised).unwrap();
+    store.upsert_code_response(&original).unwrap();
+
+    let value_on = |as_of: &str| {
+        store
+            .vintage("FM08", "FXERD01", "20240105", as_of)
+            .unwrap()
+            .and_then(|revision| revision.value)
+    };
+    assert_eq!(value_on("2026-02-19").as_deref(), Some("144.62"));
+    assert_eq!(value_on("2026-03-02").as_deref(), Some("144.70"));
+
+    let latest = store.series_as_of("FM08", "FXERD01", "2026-03-31").unwrap();
+    assert_eq!(latest.unwrap().points[1].value.as_deref(), Some("144.70"));
+}
+
+#[test]
+fn lists_revisions_since_a_date() {
+    let store = store_with_revision();
+
+    let revisions = store.revisions_since("FM08", "2026-03-02").unwrap();
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].series_code, "FXERD01");
+    assert_eq!(revisions[0].survey_date, "20240105");
+    assert!(
+        store
+            .revisions_since("FM08", "2026-03-03")
+            .unwrap()
+            .is_empty()
+    );
+    assert!(
+        store
+            .revisions_since("FM01", "2026-01-01")
+            .unwrap()
+            .is_empty()
+    );
+
+    let json = serde_json::to_value(&revisions[0]).unwrap();
+    assert_eq!(json["previous_value"], "144.62");
+}
+
+#[test]
+fn seeds_history_when_upgrading_a_version_1_store() {
+    let directory = std::env::temp_dir().join(format!("boj-store-v1-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let path = directory.join("mirror.sqlite");
+    let _ = std::fs::remove_file(&path);
+    {
+        let connection = rusqlite::Connection::open(&path).unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE series (db TEXT NOT NULL, series_code TEXT NOT NULL, \
+                 name_of_time_series_j TEXT, name_of_time_series TEXT, unit_j TEXT, unit TEXT, \
+                 frequency TEXT, category_j TEXT, category TEXT, layer1 INTEGER, \
+                 layer2 INTEGER, layer3 INTEGER, layer4 INTEGER, layer5 INTEGER, \
+                 start_of_the_time_series TEXT, end_of_the_time_series TEXT, last_update TEXT, \
+                 notes_j TEXT, notes TEXT, synced_last_update TEXT, \
+                 PRIMARY KEY (db, series_code)); \
+                 CREATE TABLE observations (db TEXT NOT NULL, series_code TEXT NOT NULL, \
+                 survey_date TEXT NOT NULL, value TEXT, \
+                 PRIMARY KEY (db, series_code, survey_date)); \
+                 INSERT INTO series (db, series_code) VALUES ('FM08', 'FXERD01'); \
+                 INSERT INTO observations VALUES ('FM08', 'FXERD01', '20240105', '144.62'); \
+                 PRAGMA user_version = 1;",
+            )
+            .unwrap();
+    }
+
+    let mut store = SqliteStore::open(&path).unwrap();
+    let seeded = store
+        .vintage("FM08", "FXERD01", "20240105", "2000-01-01")
+        .unwrap();
+    assert_eq!(seeded.unwrap().retrieved_at, "");
+
+    let mut response = fetch_page1();
+    response.series[0].points[1].value = Some("144.70".to_string());
+    store.upsert_code_response(&response).unwrap();
+    let revisions = store.revisions_since("FM08", "2026-02-19").unwrap();
+    assert_eq!(revisions[0].previous_value.as_deref(), Some("144.62"));
+
+    drop(store);
+    let _ = std::fs::remove_dir_all(&directory);
+}
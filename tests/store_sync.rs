@@ -0,0 +1,172 @@
+#![cfg(feature = "store")]
+
+mod common;
+
+use boj_client::query::{CodeQuery, Format};
+use boj_client::store::{SqliteStore, StoreError};
+use common::{StubResponse, StubServer, client_for, fixture_string, json_response};
+
+const METADATA_FIXTURE: &str = "tests/fixtures/json_success_metadata_fm08_jp.json";
+
+/// Returns the FM08 metadata with a newer `LAST_UPDATE` for FXERD09 only.
+fn metadata_with_fxerd09_revised() -> Vec<u8> {
+    let metadata = fixture_string(METADATA_FIXTURE);
+    let position = metadata
+        .rfind("\"LAST_UPDATE\": \"20260219\"")
+        .expect("FXERD09 is the last metadata row");
+    let mut revised = metadata;
+    revised.replace_range(
+        position..position + "\"LAST_UPDATE\": \"20260219\"".len(),
+        "\"LAST_UPDATE\": \"20260220\"",
+    );
+    revised.into_bytes()
+}
+
+fn initial_sync(store: &mut SqliteStore) {
+    let server = StubServer::serve_sequence(vec![
+        json_response(METADATA_FIXTURE),
+        json_response("tests/fixtures/json_success_code_api_page1.json"),
+        json_response("tests/fixtures/json_success_code_api_page2.json"),
+    ]);
+    let report = store.sync(&client_for(&server), "fm08").unwrap();
+    let requests = server.finish_all();
+
+    assert_eq!(report.db, "FM08");
+    assert_eq!(report.checked, 3);
+    assert_eq!(report.updated, ["FXERD01", "FXERD04", "FXERD09"]);
+    assert_eq!(report.observations, 5);
+    assert!(requests[0].target.starts_with("/api/v1/getMetadata?"));
+    assert!(
+        requests[1]
+            .target
+            .contains("code=FXERD01%2CFXERD04%2CFXERD09")
+    );
+    assert!(requests[2].target.contains("startPosition=3"));
+}
+
+#[test]
+fn sync_mirrors_metadata_and_paginated_observations() {
+    let mut store = SqliteStore::open_in_memory().unwrap();
+    initial_sync(&mut store);
+
+    let fxerd04 = store.series("FM08", "FXERD04").unwrap().unwrap();
+    assert_eq!(fxerd04.frequency.as_deref(), Some("DAILY"));
+    assert_eq!(fxerd04.unit_j.as_deref(), Some("円"));
+    assert_eq!(fxerd04.last_update.as_deref(), Some("20260219"));
+    assert_eq!(fxerd04.points.len(), 2);
+    assert_eq!(fxerd04.points[0].value.as_deref(), Some("143.69"));
+    assert_eq!(fxerd04.points[1].value, None);
+
+    assert_eq!(
+        store.series_codes("FM08").unwrap(),
+        ["FXERD01", "FXERD04", "FXERD09"]
+    );
+    let metadata = store.metadata("FM08").unwrap();
+    assert_eq!(metadata[2].layer2, Some(3));
+    assert_eq!(
+        metadata[0].start_of_the_time_series.as_deref(),
+        Some("19980101")
+    );
+    assert!(store.series("FM08", "UNKNOWN").unwrap().is_none());
+}
+
+#[test]
+fn sync_refetches_only_series_with_newer_last_update() {
+    let mut store = SqliteStore::open_in_memory().unwrap();
+    initial_sync(&mut store);
+
+    let server = StubServer::serve_once(json_response(METADATA_FIXTURE));
+    let report = store.sync(&client_for(&server), "FM08").unwrap();
+    assert_eq!(server.finish_all().len(), 1);
+    assert!(report.updated.is_empty());
+    assert_eq!(report.observations, 0);
+
+    let server = StubServer::serve_sequence(vec![
+        StubResponse::with_content_type(200, metadata_with_fxerd09_revised(), "application/json"),
+        json_response("tests/fixtures/json_success_code_api_page2.json"),
+    ]);
+    let report = store.sync(&client_for(&server), "FM08").unwrap();
+    let requests = server.finish_all();
+    assert_eq!(report.updated, ["FXERD09"]);
+    assert!(requests[1].target.ends_with("code=FXERD09"));
+    assert_eq!(
+        store
+            .series("FM08", "FXERD09")
+            .unwrap()
+            .unwrap()
+            .last_update
+            .as_deref(),
+        Some("20260220")
+    );
+}
+
+#[test]
+fn query_code_answers_offline_with_code_series() {
+    let mut store = SqliteStore::open_in_memory().unwrap();
+    initial_sync(&mut store);
+
+    let query = CodeQuery::new(
+        "FM08",
+        vec![
+            "FXERD09".to_string(),
+            "MISSING".to_string(),
+            "FXERD01".to_string(),
+        ],
+    )
+    .unwrap()
+    .with_format(Format::Json)
+    .with_start_date("202401")
+    .unwrap()
+    .with_end_date("202401")
+    .unwrap();
+    let series = store.query_code(&query).unwrap();
+    let codes = series
+        .iter()
+        .map(|series| series.series_code.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, ["FXERD09", "FXERD01"]);
+    assert_eq!(series[1].points.len(), 2);
+
+    let later = CodeQuery::new("FM08", vec!["FXERD01".to_string()])
+        .unwrap()
+        .with_start_date("202402")
+        .unwrap();
+    assert!(store.query_code(&later).unwrap()[0].points.is_empty());
+}
+
+#[test]
+fn upsert_code_response_overwrites_points_and_persists_on_disk() {
+    let directory = std::env::temp_dir().join(format!("boj-store-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let path = directory.join("mirror.sqlite");
+    let _ = std::fs::remove_file(&path);
+
+    let server = StubServer::serve_once(json_response(
+        "tests/fixtures/json_success_code_api_page2.json",
+    ));
+    let query = CodeQuery::new("FM08", vec!["FXERD04".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+    let mut response = client_for(&server).get_data_code(&query).unwrap();
+    let _ = server.finish();
+    {
+        let mut store = SqliteStore::open(&path).unwrap();
+        assert_eq!(store.upsert_code_response(&response).unwrap(), 2);
+        response.series[0].points[0].value = Some("144.01".to_string());
+        store.upsert_code_response(&response).unwrap();
+    }
+
+    let store = SqliteStore::open(&path).unwrap();
+    let fxerd04 = store.series("FM08", "FXERD04").unwrap().unwrap();
+    assert_eq!(fxerd04.points.len(), 1);
+    assert_eq!(fxerd04.points[0].value.as_deref(), Some("144.01"));
+
+    response.parameter.db = None;
+    let mut store = store;
+    assert!(matches!(
+        store.upsert_code_response(&response),
+        Err(StoreError::MissingDb)
+    ));
+    drop(store);
+    let _ = std::fs::remove_dir_all(&directory);
+}
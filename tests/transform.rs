@@ -0,0 +1,601 @@
+mod common;
+
+use boj_client::error::BojError;
+use boj_client::query::{CodeQuery, Format, Frequency};
+use boj_client::transform::{
+    Aggregation, AlignOptions, DecompositionModel, FillPolicy, Join, NumericSeries, Observation,
+    Period, ResampleOptions, align,
+};
+use common::{StubServer, client_for, json_response};
+
+fn series(frequency: Frequency, start: &str, values: &[Option<f64>]) -> NumericSeries {
+    let start = Period::parse(start, frequency).unwrap();
+    let observations = values
+        .iter()
+        .enumerate()
+        .map(|(index, &value)| Observation {
+            period: start.offset(index as i32),
+            value,
+        })
+        .collect();
+    NumericSeries::new("TEST", frequency, observations).unwrap()
+}
+
+fn values(series: &NumericSeries) -> Vec<Option<f64>> {
+    series
+        .observations
+        .iter()
+        .map(|observation| observation.value.map(|value| (value * 1e6).round() / 1e6))
+        .collect()
+}
+
+#[test]
+fn parses_quarterly_fixture_with_missing_values() {
+    let server = StubServer::serve_once(json_response("tests/fixtures/json_success_code_api.json"));
+    let query = CodeQuery::new("CO", vec!["TK99F1000601GCQ01000".to_string()])
+        .unwrap()
+        .with_format(Format::Json);
+    let response = client_for(&server).get_data_code(&query).unwrap();
+    let _ = server.finish();
+
+    let series = NumericSeries::from_series(&response.series[0]).unwrap();
+    assert_eq!(series.frequency, Frequency::Q);
+    assert_eq!(series.observations[0].period.to_string(), "202401");
+    assert_eq!(values(&series), [Some(11.0), None]);
+    assert_eq!(values(&series.diff()), [None, None]);
+    assert_eq!(series.to_points(), response.series[0].points);
+}
+
+#[test]
+fn period_changes_use_calendar_lags_and_propagate_missing_values() {
+    let monthly = series(
+        Frequency::M,
+        "202311",
+        &[Some(100.0), Some(110.0), None, Some(121.0), Some(0.0)],
+    );
+
+    assert_eq!(
+        values(&monthly.pct_change()),
+        [None, Some(10.0), None, None, Some(-100.0)]
+    );
+    assert_eq!(
+        values(&monthly.diff()),
+        [None, Some(10.0), None, None, Some(-121.0)]
+    );
+    assert_eq!(
+        values(&monthly.log_diff()),
+        [None, Some(0.09531), None, None, None]
+    );
+
+    // A gap in the periods is not bridged: 202402 has no 202401 value.
+    let gapped = NumericSeries::new(
+        "TEST",
+        Frequency::M,
+        vec![
+            Observation {
+                period: Period::parse("202312", Frequency::M).unwrap(),
+                value: Some(1.0),
+            },
+            Observation {
+                period: Period::parse("202402", Frequency::M).unwrap(),
+                value: Some(2.0),
+            },
+        ],
+    )
+    .unwrap();
+    assert_eq!(values(&gapped.pct_change()), [None, None]);
+}
+
+#[test]
+fn year_over_year_and_annualized_rates_follow_the_frequency() {
+    let quarterly = series(
+        Frequency::Q,
+        "202301",
+        &[
+            Some(100.0),
+            Some(101.0),
+            Some(102.0),
+            Some(103.0),
+            Some(104.0),
+        ],
+    );
+    let yoy = quarterly.yoy_pct_change().unwrap();
+    assert_eq!(values(&yoy), [None, None, None, None, Some(4.0)]);
+    assert_eq!(yoy.observations[4].period.to_string(), "202401");
+    assert_eq!(
+        values(&quarterly.yoy_diff().unwrap()),
+        [None, None, None, None, Some(4.0)]
+    );
+    assert_eq!(
+        values(&quarterly.annualized_rate().unwrap())[1],
+        Some(4.060401)
+    );
+
+    let fiscal_halves = series(
+        Frequency::Fh,
+        "202201",
+        &[Some(50.0), Some(60.0), Some(55.0)],
+    );
+    assert_eq!(
+        values(&fiscal_halves.yoy_pct_change().unwrap()),
+        [None, None, Some(10.0)]
+    );
+
+    let daily = series(Frequency::D, "20240105", &[Some(1.0), Some(2.0)]);
+    assert!(matches!(
+        daily.yoy_pct_change(),
+        Err(BojError::ValidationError(_))
+    ));
+    assert!(matches!(
+        daily.annualized_rate(),
+        Err(BojError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn daily_changes_compare_with_the_previous_business_day() {
+    let daily = NumericSeries::new(
+        "FXERD01",
+        Frequency::D,
+        vec![
+            Observation {
+                period: Period::parse("20240105", Frequency::D).unwrap(),
+                value: Some(144.0),
+            },
+            Observation {
+                period: Period::parse("20240109", Frequency::D).unwrap(),
+                value: Some(144.72),
+            },
+        ],
+    )
+    .unwrap();
+    assert_eq!(values(&daily.diff()), [None, Some(0.72)]);
+}
+
+#[test]
+fn rolling_statistics_require_complete_windows() {
+    let monthly = series(
+        Frequency::M,
+        "202401",
+        &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), None, Some(6.0)],
+    );
+
+    assert_eq!(
+        values(&monthly.rolling_mean(3).unwrap()),
+        [None, None, Some(2.0), Some(3.0), None, None]
+    );
+    assert_eq!(
+        values(&monthly.rolling_sum(2).unwrap()),
+        [None, Some(3.0), Some(5.0), Some(7.0), None, None]
+    );
+    assert_eq!(
+        values(&monthly.rolling_std(3).unwrap()),
+        [None, None, Some(1.0), Some(1.0), None, None]
+    );
+    assert_eq!(values(&monthly.rolling_std(1).unwrap()), [None; 6]);
+    assert!(matches!(
+        monthly.rolling_mean(0),
+        Err(BojError::ValidationError(_))
+    ));
+}
+
+#[test]
+fn rolling_windows_span_periods_except_for_daily_series() {
+    let mut monthly = series(
+        Frequency::M,
+        "202401",
+        &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)],
+    );
+    // March is absent from the series rather than missing.
+    monthly.observations.remove(2);
+    assert_eq!(
+        values(&monthly.rolling_sum(2).unwrap()),
+        [None, Some(3.0), None, Some(9.0)]
+    );
+    assert_eq!(
+        values(&monthly.rolling_mean(3).unwrap()),
+        [None, None, None, None]
+    );
+
+    // Daily windows skip the weekend between Friday and Monday.
+    let mut daily = series(
+        Frequency::D,
+        "20240105",
+        &[Some(1.0), None, None, Some(2.0)],
+    );
+    daily.observations.drain(1..3);
+    assert_eq!(values(&daily.rolling_sum(2).unwrap()), [None, Some(3.0)]);
+}
+
+#[test]
+fn rebases_to_a_period_or_the_mean_of_a_containing_period() {
+    let monthly = series(
+        Frequency::M,
+        "202303",
+        &[Some(90.0), Some(100.0), Some(110.0), None],
+    );
+
+    let rebased = monthly
+        .rebase(Period::parse("202304", Frequency::M).unwrap())
+        .unwrap();
+    assert_eq!(
+        values(&rebased),
+        [Some(90.0), Some(100.0), Some(110.0), None]
+    );
+
+    let quarterly = series(
+        Frequency::Q,
+        "202301",
+        &[Some(80.0), Some(120.0), Some(50.0)],
+    );
+    let rebased = quarterly
+        .rebase(Period::parse("202301", Frequency::Ch).unwrap())
+        .unwrap();
+    assert_eq!(values(&rebased), [Some(80.0), Some(120.0), Some(50.0)]);
+
+    // FY2023 covers 202304..202403; 202306 is missing, so the base is invalid.
+    let error = monthly
+        .rebase(Period::parse("2023", Frequency::Fy).unwrap())
+        .unwrap_err();
+    assert!(error.to_string().contains("missing values"), "{error}");
+    let error = monthly
+        .rebase(Period::parse("2020", Frequency::Cy).unwrap())
+        .unwrap_err();
+    assert!(error.to_string().contains("no observation"), "{error}");
+}
+
+#[test]
+fn rejects_mixed_frequencies_and_duplicate_periods() {
+    let month = Period::parse("202401", Frequency::M).unwrap();
+    let quarter = Period::parse("202401", Frequency::Q).unwrap();
+    let observation = |period| Observation {
+        period,
+        value: None,
+    };
+
+    assert!(NumericSeries::new("TEST", Frequency::M, vec![observation(quarter)]).is_err());
+    assert!(
+        NumericSeries::new(
+            "TEST",
+            Frequency::M,
+            vec![observation(month), observation(month)]
+        )
+        .is_err()
+    );
+    assert_eq!(
+        Period::containing(20240315, Frequency::Fh)
+            .unwrap()
+            .to_string(),
+        "202302"
+    );
+    assert_eq!(month.offset(-1).to_string(), "202312");
+    assert_eq!(month.periods_since(month.offset(-13)), Some(13));
+}
+
+fn labels(series: &NumericSeries) -> Vec<String> {
+    series
+        .observations
+        .iter()
+        .map(|observation| observation.period.to_string())
+        .collect()
+}
+
+#[test]
+fn resamples_daily_rates_to_monthly_means_with_a_coverage_threshold() {
+    // January 2024 has 19 business days; 1-3 and 8 January are holidays and
+    // the value of 10 January is missing.
+    let missing = [20240101, 20240102, 20240103, 20240108, 20240110];
+    // 1 January 2024 was a Monday; the series ends on 1 February.
+    let start = Period::parse("20240101", Frequency::D).unwrap();
+    let observations = (0..32)
+        .filter(|index| index % 7 < 5)
+        .map(|index| start.offset(index))
+        .map(|period| Observation {
+            period,
+            value: (!missing.contains(&period.first_day())).then_some(0.1),
+        })
+        .collect();
+    let daily = NumericSeries::new("FM01", Frequency::D, observations).unwrap();
+
+    let strict = daily
+        .resample(Frequency::M, ResampleOptions::new(Aggregation::Mean))
+        .unwrap();
+    assert_eq!(labels(&strict), ["202401", "202402"]);
+    assert_eq!(values(&strict), [None, None]);
+
+    let lenient = daily
+        .resample(
+            Frequency::M,
+            ResampleOptions::new(Aggregation::Mean)
+                .with_min_coverage(0.9)
+                .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(values(&lenient), [Some(0.1), None]);
+}
+
+#[test]
+fn resamples_to_fiscal_years_and_halves_starting_in_april() {
+    let monthly = series(
+        Frequency::M,
+        "202303",
+        &(1..=14)
+            .map(|month| Some(f64::from(month)))
+            .collect::<Vec<_>>(),
+    );
+
+    let fiscal_years = monthly
+        .resample(Frequency::Fy, ResampleOptions::new(Aggregation::Sum))
+        .unwrap();
+    assert_eq!(labels(&fiscal_years), ["2022", "2023", "2024"]);
+    // April 2023 to March 2024 are values 2 to 13.
+    assert_eq!(values(&fiscal_years), [None, Some(90.0), None]);
+
+    let fiscal_halves = monthly
+        .resample(Frequency::Fh, ResampleOptions::new(Aggregation::Last))
+        .unwrap();
+    assert_eq!(
+        labels(&fiscal_halves),
+        ["202202", "202301", "202302", "202401"]
+    );
+    assert_eq!(values(&fiscal_halves), [None, Some(7.0), Some(13.0), None]);
+
+    let quarterly = series(
+        Frequency::Q,
+        "202302",
+        &[Some(1.0), Some(5.0), Some(2.0), Some(4.0)],
+    );
+    let fiscal_halves = quarterly
+        .resample(Frequency::Fh, ResampleOptions::new(Aggregation::Max))
+        .unwrap();
+    assert_eq!(labels(&fiscal_halves), ["202301", "202302"]);
+    assert_eq!(values(&fiscal_halves), [Some(5.0), Some(4.0)]);
+}
+
+#[test]
+fn resample_aggregations_and_weekly_sources() {
+    let monthly = series(Frequency::M, "202401", &[Some(3.0), Some(1.0), Some(2.0)]);
+    let aggregate = |aggregation| {
+        values(
+            &monthly
+                .resample(Frequency::Q, ResampleOptions::new(aggregation))
+                .unwrap(),
+        )[0]
+    };
+    assert_eq!(aggregate(Aggregation::Mean), Some(2.0));
+    assert_eq!(aggregate(Aggregation::First), Some(3.0));
+    assert_eq!(aggregate(Aggregation::Last), Some(2.0));
+    assert_eq!(aggregate(Aggregation::Min), Some(1.0));
+    assert_eq!(aggregate(Aggregation::Max), Some(3.0));
+
+    // Fridays: four in January 2024, so three values miss the default threshold.
+    let weekly = series(
+        Frequency::W,
+        "20240105",
+        &[Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0)],
+    );
+    let monthly = weekly
+        .resample(Frequency::M, ResampleOptions::new(Aggregation::Sum))
+        .unwrap();
+    assert_eq!(labels(&monthly), ["202401", "202402"]);
+    assert_eq!(values(&monthly), [Some(10.0), None]);
+}
+
+#[test]
+fn rejects_resampling_to_periods_that_split_source_periods() {
+    let options = ResampleOptions::new(Aggregation::Mean);
+    let monthly = series(Frequency::M, "202401", &[Some(1.0)]);
+    let calendar_halves = series(Frequency::Ch, "202401", &[Some(1.0)]);
+
+    for (series, target) in [
+        (&monthly, Frequency::W),
+        (&monthly, Frequency::M),
+        (&calendar_halves, Frequency::Fy),
+    ] {
+        assert!(matches!(
+            series.resample(target, options),
+            Err(BojError::ValidationError(_))
+        ));
+    }
+    assert!(options.with_min_coverage(0.0).is_err());
+    assert!(options.with_min_coverage(1.5).is_err());
+}
+
+#[test]
+fn aligns_series_with_inner_and_outer_joins() {
+    let prices = series(
+        Frequency::M,
+        "202401",
+        &[Some(1.0), Some(2.0), None, Some(4.0)],
+    );
+    let mut rates = series(
+        Frequency::M,
+        "202402",
+        &[Some(20.0), Some(30.0), Some(40.0), Some(50.0)],
+    );
+    rates.series_code = "RATES".to_string();
+
+    let inner = align(
+        &[prices.clone(), rates.clone()],
+        AlignOptions::new(Join::Inner),
+    )
+    .unwrap();
+    assert_eq!(
+        inner
+            .periods
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["202402", "202404"]
+    );
+    assert_eq!(inner.columns[0].values, [Some(2.0), Some(4.0)]);
+    assert_eq!(
+        inner.column("RATES").unwrap().values,
+        [Some(20.0), Some(40.0)]
+    );
+
+    let outer = align(
+        &[prices.clone(), rates.clone()],
+        AlignOptions::new(Join::Outer),
+    )
+    .unwrap();
+    assert_eq!(outer.periods.len(), 5);
+    assert_eq!(
+        outer.columns[0].values,
+        [Some(1.0), Some(2.0), None, Some(4.0), None]
+    );
+    assert_eq!(
+        outer.columns[1].values,
+        [None, Some(20.0), Some(30.0), Some(40.0), Some(50.0)]
+    );
+
+    // Forward fill stops at the last value of each series.
+    let filled = align(
+        &[prices, rates],
+        AlignOptions::new(Join::Outer).with_fill(FillPolicy::ForwardFill),
+    )
+    .unwrap();
+    assert_eq!(
+        filled.columns[0].values,
+        [Some(1.0), Some(2.0), Some(2.0), Some(4.0), None]
+    );
+
+    let range = filled.common_range.unwrap();
+    assert_eq!(
+        (range.first.to_string(), range.last.to_string()),
+        ("202402".to_string(), "202404".to_string())
+    );
+    assert_eq!(range.first_constrained_by, ["RATES"]);
+    assert_eq!(range.last_constrained_by, ["TEST"]);
+}
+
+#[test]
+fn aligns_mixed_frequencies_on_their_common_lower_frequency() {
+    let mut monthly = series(
+        Frequency::M,
+        "202304",
+        &(1..=12)
+            .map(|month| Some(f64::from(month)))
+            .collect::<Vec<_>>(),
+    );
+    monthly.series_code = "MONTHLY".to_string();
+    let quarterly = series(
+        Frequency::Q,
+        "202302",
+        &[Some(1.0), Some(2.0), Some(3.0), Some(4.0)],
+    );
+    let fiscal = series(Frequency::Fy, "2023", &[Some(9.0)]);
+
+    let unconverted = align(
+        &[monthly.clone(), quarterly.clone()],
+        AlignOptions::new(Join::Inner),
+    );
+    assert!(matches!(unconverted, Err(BojError::ValidationError(_))));
+
+    let options =
+        AlignOptions::new(Join::Inner).with_conversion(ResampleOptions::new(Aggregation::Sum));
+    let table = align(&[monthly.clone(), quarterly.clone()], options).unwrap();
+    assert_eq!(table.frequency, Frequency::Q);
+    assert_eq!(
+        table.columns[0].values,
+        [Some(6.0), Some(15.0), Some(24.0), Some(33.0)]
+    );
+    assert_eq!(
+        table.columns[1].values,
+        [Some(1.0), Some(2.0), Some(3.0), Some(4.0)]
+    );
+
+    let table = align(&[monthly, quarterly, fiscal], options).unwrap();
+    assert_eq!(table.frequency, Frequency::Fy);
+    assert_eq!(table.columns[0].values, [Some(78.0)]);
+    assert_eq!(table.columns[1].values, [Some(10.0)]);
+
+    let calendar_half = series(Frequency::Ch, "202301", &[Some(1.0)]);
+    let fiscal_half = series(Frequency::Fh, "202301", &[Some(1.0)]);
+    let error = align(&[calendar_half, fiscal_half], options).unwrap_err();
+    assert!(
+        error.to_string().contains("no common lower frequency"),
+        "{error}"
+    );
+    assert!(align(&[], options).is_err());
+}
+
+#[test]
+fn decomposes_monthly_series_into_exact_components() {
+    // Linear trend plus a seasonal pattern that sums to zero over the year.
+    let pattern = [
+        -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 2.0, 1.0, 0.0, -1.0, -2.0,
+    ];
+    let monthly = series(
+        Frequency::M,
+        "202004",
+        &(0..36)
+            .map(|index: u32| Some(100.0 + f64::from(index) + pattern[(index as usize + 3) % 12]))
+            .collect::<Vec<_>>(),
+    );
+
+    let decomposition = monthly.decompose(DecompositionModel::Additive).unwrap();
+    let trend = values(&decomposition.trend);
+    assert_eq!(trend[..6], [None; 6]);
+    assert_eq!(trend[6], Some(106.0));
+    assert_eq!(trend[30..], [None; 6]);
+    // April 2020 has the April factor.
+    assert_eq!(values(&decomposition.seasonal)[0], Some(0.0));
+    assert_eq!(values(&decomposition.seasonal)[9], Some(-3.0));
+    assert!(
+        values(&decomposition.residual)
+            .iter()
+            .flatten()
+            .all(|&residual| residual == 0.0)
+    );
+    assert_eq!(
+        values(&decomposition.seasonally_adjusted),
+        (0..36)
+            .map(|index: u32| Some(100.0 + f64::from(index)))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn decomposes_quarterly_series_multiplicatively_with_missing_values() {
+    let factors = [0.9, 1.0, 1.2, 0.9];
+    let mut values_in = (0..16)
+        .map(|index| Some(200.0 * factors[index % 4]))
+        .collect::<Vec<_>>();
+    values_in[15] = None;
+    let quarterly = series(Frequency::Q, "201901", &values_in);
+
+    let decomposition = quarterly
+        .decompose(DecompositionModel::Multiplicative)
+        .unwrap();
+    assert_eq!(
+        values(&decomposition.seasonal)[..4],
+        [Some(0.9), Some(1.0), Some(1.2), Some(0.9)]
+    );
+    assert_eq!(values(&decomposition.seasonally_adjusted)[2], Some(200.0));
+    assert_eq!(values(&decomposition.seasonally_adjusted)[15], None);
+    assert_eq!(values(&decomposition.residual)[2], Some(1.0));
+    // The window of the last full trend value reaches the missing quarter.
+    assert_eq!(values(&decomposition.trend)[13], None);
+}
+
+#[test]
+fn refuses_decomposition_of_unsuitable_series() {
+    let daily = series(Frequency::D, "20240101", &[Some(1.0); 30]);
+    let fiscal = series(Frequency::Fy, "2000", &[Some(1.0); 30]);
+    let short = series(Frequency::M, "202401", &[Some(1.0); 23]);
+    let negative = series(Frequency::Q, "202001", &[Some(-1.0); 8]);
+
+    for (series, model) in [
+        (&daily, DecompositionModel::Additive),
+        (&fiscal, DecompositionModel::Additive),
+        (&short, DecompositionModel::Additive),
+        (&negative, DecompositionModel::Multiplicative),
+    ] {
+        assert!(matches!(
+            series.decompose(model),
+            Err(BojError::ValidationError(_))
+        ));
+    }
+    assert!(negative.decompose(DecompositionModel::Additive).is_ok());
+}
@@ -119,3 +119,26 @@ fn unknown_db_is_allowed_for_backward_compatibility() {
         "/api/v1/getDataCode?db=UNKNOWN_DB&code=TK99F1000601GCQ01000"
     );
 }
+
+#[test]
+fn code_query_url_and_json_match_request() {
+    let query = CodeQuery::new("fm08", vec!["FXERD01".to_string(), "FXERD04".to_string()])
+        .unwrap()
+        .with_format(Format::Json)
+        .with_start_date("202401")
+        .unwrap();
+
+    assert_eq!(
+        query.to_url("https://www.stat-search.boj.or.jp/"),
+        "https://www.stat-search.boj.or.jp/api/v1/getDataCode?format=json&db=FM08&startDate=202401&code=FXERD01%2CFXERD04"
+    );
+    assert_eq!(
+        serde_json::to_value(&query).unwrap(),
+        serde_json::json!({
+            "db": "FM08",
+            "codes": ["FXERD01", "FXERD04"],
+            "format": "Json",
+            "start_date": "202401",
+        })
+    );
+}